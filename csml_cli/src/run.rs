@@ -30,6 +30,7 @@ pub fn init_request(string: &str, metadata: Option<serde_json::Value>) -> CsmlRe
         },
         ttl_duration: None,
         step_limit: None,
+        loop_limit: None,
         low_data_mode: None,
     }
 }
@@ -53,6 +54,7 @@ pub fn init_request_flow_trigger(flow_id: &str, step_id: Option<&str>) -> CsmlRe
         metadata: json!({}),
         ttl_duration: None,
         step_limit: None,
+        loop_limit: None,
         low_data_mode: None,
     }
 }
@@ -103,6 +105,10 @@ pub fn load_info(directory_name: &str) -> Result<CsmlBot, Box<dyn Error>> {
         env: None,
         modules: None,
         multibot: None,
+        random_seed: None,
+        disable_debug: false,
+        max_duration: None,
+        auto_typing: None,
     })
 }
 
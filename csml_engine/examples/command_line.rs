@@ -36,6 +36,7 @@ fn init_request(string: &str) -> CsmlRequest {
         metadata: json!({"some": "custom-value"}),
         ttl_duration: None,
         step_limit: None,
+        loop_limit: None,
         low_data_mode: None,
     }
 }
@@ -67,6 +68,10 @@ fn init_bot() -> CsmlBot {
         env: None,
         modules: None,
         multibot: None,
+        random_seed: None,
+        disable_debug: false,
+        max_duration: None,
+        auto_typing: None,
     }
 }
 
@@ -42,6 +42,10 @@ fn init_bot() -> CsmlBot {
         env: None,
         modules: None,
         multibot: None,
+        random_seed: None,
+        disable_debug: false,
+        max_duration: None,
+        auto_typing: None,
     }
 }
 
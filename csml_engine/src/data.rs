@@ -3,7 +3,7 @@ use crate::{
     encrypt::{decrypt_data, encrypt_data},
     Client, Context,
 };
-use csml_interpreter::data::{CsmlBot, CsmlFlow, Message, Module, MultiBot};
+use csml_interpreter::data::{AutoTyping, CsmlBot, CsmlFlow, Message, Module, MultiBot};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -151,6 +151,14 @@ pub struct SerializeCsmlBot {
     pub no_interruption_delay: Option<i32>,
     pub env: Option<String>,
     pub modules: Option<Vec<Module>>,
+    #[serde(default)]
+    pub random_seed: Option<u64>,
+    #[serde(default)]
+    pub disable_debug: bool,
+    #[serde(default)]
+    pub max_duration: Option<u64>,
+    #[serde(default)]
+    pub auto_typing: Option<AutoTyping>,
 }
 
 /**
@@ -182,6 +190,10 @@ impl CsmlBotBincode {
             no_interruption_delay: None,
             env: None,
             modules: None,
+            random_seed: None,
+            disable_debug: false,
+            max_duration: None,
+            auto_typing: None,
         }
     }
 }
@@ -210,6 +222,10 @@ pub fn to_serializable_bot(bot: &CsmlBot) -> SerializeCsmlBot {
             None => None,
         },
         modules: bot.modules.to_owned(),
+        random_seed: bot.random_seed,
+        disable_debug: bot.disable_debug,
+        max_duration: bot.max_duration,
+        auto_typing: bot.auto_typing,
     }
 }
 
@@ -247,6 +263,10 @@ impl SerializeCsmlBot {
             },
             modules: self.modules.to_owned(),
             multibot: None,
+            random_seed: self.random_seed,
+            disable_debug: self.disable_debug,
+            max_duration: self.max_duration,
+            auto_typing: self.auto_typing,
         }
     }
 }
@@ -259,6 +279,14 @@ pub struct DynamoBot {
     pub default_flow: String,
     pub no_interruption_delay: Option<i32>,
     pub env: Option<String>,
+    #[serde(default)]
+    pub random_seed: Option<u64>,
+    #[serde(default)]
+    pub disable_debug: bool,
+    #[serde(default)]
+    pub max_duration: Option<u64>,
+    #[serde(default)]
+    pub auto_typing: Option<AutoTyping>,
 }
 
 /**
@@ -285,6 +313,10 @@ impl DynamoBotBincode {
             default_flow: self.default_flow,
             no_interruption_delay: None,
             env: None,
+            random_seed: None,
+            disable_debug: false,
+            max_duration: None,
+            auto_typing: None,
         }
     }
 }
@@ -303,6 +335,10 @@ pub fn to_dynamo_bot(csml_bot: &CsmlBot) -> DynamoBot {
             Some(value) => encrypt_data(value).ok(),
             None => None,
         },
+        random_seed: csml_bot.random_seed,
+        disable_debug: csml_bot.disable_debug,
+        max_duration: csml_bot.max_duration,
+        auto_typing: csml_bot.auto_typing,
     }
 }
 
@@ -332,6 +368,10 @@ impl DynamoBot {
             },
             modules: Some(modules),
             multibot: None,
+            random_seed: self.random_seed,
+            disable_debug: self.disable_debug,
+            max_duration: self.max_duration,
+            auto_typing: self.auto_typing,
         }
     }
 }
@@ -344,6 +384,7 @@ pub struct CsmlRequest {
     pub payload: serde_json::Value,
     pub metadata: serde_json::Value,
     pub step_limit: Option<usize>,
+    pub loop_limit: Option<usize>,
     pub ttl_duration: Option<serde_json::Value>,
     pub low_data_mode: Option<serde_json::Value>,
 }
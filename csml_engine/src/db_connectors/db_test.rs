@@ -22,6 +22,7 @@ mod tests {
             step: ContextStepInfo::Normal("start".to_owned()),
             flow: "Default".to_owned(),
             previous_bot: None,
+            one_of: HashMap::new(),
         }
     }
 
@@ -44,6 +45,10 @@ mod tests {
             env: None,
             modules: None,
             multibot: None,
+            random_seed: None,
+            disable_debug: false,
+            max_duration: None,
+            auto_typing: None,
         }
     }
 
@@ -255,7 +255,8 @@ mod tests {
             step_name: "step_name".to_owned(),
             flow_name: "flow_name".to_owned(),
             previous: None,
-            secure: false
+            secure: false,
+            question: None,
         };
 
         let state_hold: serde_json::Value = serde_json::json!({
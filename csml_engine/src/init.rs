@@ -165,6 +165,7 @@ pub fn init_context(
     db: &mut Database,
 ) -> Context {
     let previous_bot = get_previous_bot(&client, db);
+    let one_of = get_one_of(&client, db);
 
     let api_info = match apps_endpoint {
         Some(value) => Some(ApiInfo {
@@ -182,6 +183,7 @@ pub fn init_context(
         step: ContextStepInfo::Normal("start".to_owned()),
         flow,
         previous_bot,
+        one_of,
     }
 }
 
@@ -192,6 +194,13 @@ fn get_previous_bot(client: &Client, db: &mut Database) -> Option<PreviousBot> {
     }
 }
 
+fn get_one_of(client: &Client, db: &mut Database) -> HashMap<String, usize> {
+    match state::get_state_key(client, "one_of", "picks", db) {
+        Ok(Some(picks)) => serde_json::from_value(picks).unwrap_or_default(),
+        _ => HashMap::new(),
+    }
+}
+
 /**
  * Retrieve the current conversation, or create one if none exists.
  */
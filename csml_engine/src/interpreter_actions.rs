@@ -136,6 +136,7 @@ pub fn interpret_step(
                 flow_name,
                 previous,
                 secure,
+                question,
             }) => {
                 let hash = get_current_step_hash(&data.context, bot)?;
                 let state_hold: Value = serde_json::json!({
@@ -143,7 +144,8 @@ pub fn interpret_step(
                     "step_vars": step_vars,
                     "hash": hash,
                     "previous": previous,
-                    "secure": secure
+                    "secure": secure,
+                    "question": question
                 });
 
                 csml_logger(
@@ -179,8 +181,20 @@ pub fn interpret_step(
                     flow_name,
                     previous,
                     secure,
+                    question,
                 });
             }
+            MSG::OneOf { key, index } => {
+                data.context.one_of.insert(key, index);
+
+                set_state_items(
+                    &data.client,
+                    "one_of",
+                    vec![("picks", &serde_json::json!(data.context.one_of))],
+                    data.ttl,
+                    &mut data.db,
+                )?;
+            }
             MSG::Next {
                 flow,
                 step,
@@ -513,6 +513,7 @@ fn check_for_hold(
                 flow_name: data.context.flow.to_owned(),
                 previous: serde_json::from_value(hold["previous"].clone()).unwrap_or(None),
                 secure: secure_hold,
+                question: serde_json::from_value(hold["question"].clone()).unwrap_or(None),
             });
 
             state::delete_state_key(&data.client, "hold", "position", &mut data.db)?;
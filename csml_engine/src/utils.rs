@@ -91,6 +91,12 @@ pub fn get_event_content(content_type: &str, metadata: &Value) -> Result<String,
                 ))
             }
         }
+        location if location == "location" => match Event::get_location(metadata) {
+            Some((lat, long)) => Ok(format!("{},{}", lat, long)),
+            None => Err(EngineError::Interpreter(
+                "invalid content for event type location: expect lat and long".to_owned(),
+            )),
+        },
         regex if regex == "regex" => {
             if let Some(val) = metadata["payload"].as_str() {
                 Ok(val.to_string())
@@ -124,6 +130,7 @@ pub fn get_event_content(content_type: &str, metadata: &Value) -> Result<String,
  */
 pub fn format_event(request: &CsmlRequest) -> Result<Event, EngineError> {
     let step_limit = request.step_limit;
+    let loop_limit = request.loop_limit;
     let json_event = json!(request);
 
     let content_type = match json_event["payload"]["content_type"].as_str() {
@@ -145,6 +152,7 @@ pub fn format_event(request: &CsmlRequest) -> Result<Event, EngineError> {
         ttl_duration: json_event["ttl_duration"].as_i64(),
         low_data_mode: json_event["low_data_mode"].as_bool(),
         step_limit,
+        loop_limit,
         secure: json_event["payload"]["secure"].as_bool().unwrap_or(false),
     })
 }
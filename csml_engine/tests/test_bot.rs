@@ -89,6 +89,10 @@ fn init_bot(bot_name: &str) -> Result<CsmlBot, std::io::Error> {
         })),
         modules: None,
         multibot: None,
+        random_seed: None,
+        disable_debug: false,
+        max_duration: None,
+        auto_typing: None,
     };
 
     Ok(bot)
@@ -110,6 +114,7 @@ fn init_request(string: &str, bot_id: String, channel_id: String) -> CsmlRequest
         metadata: json!({"some": "custom-value"}),
         ttl_duration: None,
         step_limit: None,
+        loop_limit: None,
         low_data_mode: None,
     }
 }
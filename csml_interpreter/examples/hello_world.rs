@@ -46,6 +46,7 @@ fn main() {
         ttl_duration: None,
         low_data_mode: None,
         step_limit: None,
+        loop_limit: None,
         secure: false,
     };
 
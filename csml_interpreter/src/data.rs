@@ -23,13 +23,13 @@ pub mod warnings;
 pub use ast::Interval;
 pub use client::Client;
 pub use context::{ApiInfo, Context, PreviousBot};
-pub use csml_bot::{CsmlBot, Module, MultiBot};
+pub use csml_bot::{AutoTyping, CsmlBot, Module, MultiBot};
 pub use csml_flow::CsmlFlow;
 pub use csml_result::CsmlResult;
 pub use data::Data;
 pub use event::Event;
 pub use fn_args_type::ArgsType;
-pub use hold::{Hold, IndexInfo};
+pub use hold::{Hold, IndexInfo, QuestionHold};
 pub use literal::Literal;
 pub use memories::{Memory, MemoryType};
 pub use message::Message;
@@ -40,3 +40,12 @@ pub use msg::MSG;
 
 // limit of steps in a single execution
 pub static STEP_LIMIT: usize = 100;
+
+// limit of iterations of a single foreach or while loop
+pub static LOOP_LIMIT: usize = 5_000;
+
+// limit of nested function calls, stops infinite recursions
+pub static FN_DEPTH_LIMIT: usize = 50;
+
+// longest Wait() or Typing() in milliseconds unless the bot sets max_duration
+pub static MAX_DURATION: u64 = 30_000;
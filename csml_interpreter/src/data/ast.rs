@@ -314,6 +314,14 @@ pub enum IfStatement {
     ElseStmt(Block, Interval),
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchArm {
+    pub patterns: Option<Vec<Expr>>, // None for the '_' arm
+    pub block: Block,
+    pub last_action_index: usize,
+    pub interval: Interval,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Expr {
     Scope {
@@ -323,6 +331,7 @@ pub enum Expr {
     },
     ForEachExpr(Identifier, Option<Identifier>, Box<Expr>, Block, Interval),
     WhileExpr(Box<Expr>, Block, Interval),
+    MatchExpr(Box<Expr>, Vec<MatchArm>, Interval),
     ComplexLiteral(Vec<Expr>, Interval),
     MapExpr {
         object: HashMap<String, Expr>,
@@ -373,6 +382,7 @@ pub enum Infix {
 
     And,
     Or,
+    Xor,
 }
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
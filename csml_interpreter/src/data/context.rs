@@ -67,6 +67,8 @@ pub struct Context {
     pub step: ContextStepInfo,
     pub flow: String,
     pub previous_bot: Option<PreviousBot>,
+    // index of the last value picked by each OneOf(.., no_repeat = true)
+    pub one_of: HashMap<String, usize>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -135,6 +137,7 @@ impl Context {
             step: ContextStepInfo::Normal(step.to_owned()),
             flow: flow.to_owned(),
             previous_bot,
+            one_of: HashMap::new(),
         }
     }
 }
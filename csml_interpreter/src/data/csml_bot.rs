@@ -22,6 +22,20 @@ pub struct CsmlBot {
     pub bot_ast: Option<String>,
     pub no_interruption_delay: Option<i32>,
     pub env: Option<serde_json::Value>,
+    /// seeds the random generator on each run to make Random(), RandomInt()
+    /// and the other random methods reproducible
+    #[serde(default)]
+    pub random_seed: Option<u64>,
+    /// drop the messages of debug and Debug() so production bots don't
+    /// leak their internals
+    #[serde(default)]
+    pub disable_debug: bool,
+    /// longest Wait() or Typing() in milliseconds, 30 000 by default
+    #[serde(default)]
+    pub max_duration: Option<u64>,
+    /// how Typing("auto") turns the length of the next text into a duration
+    #[serde(default)]
+    pub auto_typing: Option<AutoTyping>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +55,28 @@ pub struct MultiBot {
     pub version_id: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoTyping {
+    pub chars_per_second: u64,
+    /// durations in milliseconds
+    pub min: u64,
+    pub max: u64,
+    /// used when no text follows the Typing
+    pub default: u64,
+}
+
+impl Default for AutoTyping {
+    fn default() -> Self {
+        Self {
+            chars_per_second: 25,
+            min: 500,
+            max: 5000,
+            default: 1500,
+        }
+    }
+}
+
 fn default_version() -> String {
     "latest".to_string()
 }
@@ -77,6 +113,10 @@ impl CsmlBot {
             bot_ast,
             no_interruption_delay,
             env,
+            random_seed: None,
+            disable_debug: false,
+            max_duration: None,
+            auto_typing: None,
         }
     }
 
@@ -1,6 +1,6 @@
 use crate::data::context::Context;
 use crate::data::Event;
-use crate::data::{ast::*, Literal};
+use crate::data::{ast::*, AutoTyping, Literal, Message, QuestionHold};
 
 use crate::data::context::ContextStepInfo;
 
@@ -32,11 +32,24 @@ pub struct Data<'a> {
 
     pub step_count: &'a mut usize,
     pub step_limit: usize,
+    // most iterations a single foreach or while loop can run
+    pub loop_limit: usize,
 
     pub step_vars: HashMap<String, Literal>,
+    // number of nested function calls
+    pub fn_depth: usize,
     pub previous_info: Option<PreviousInfo>,
     pub custom_component: &'a serde_json::Map<String, serde_json::Value>,
     pub native_component: &'a serde_json::Map<String, serde_json::Value>,
+    // false when the bot disables debug messages
+    pub debug: bool,
+    // longest Wait() or Typing() allowed by the bot, in milliseconds
+    pub max_duration: u64,
+    pub auto_typing: AutoTyping,
+    // Typing("auto") waiting for the next message to get its duration
+    pub typing: Option<Message>,
+    // last Question with accepted answers sent in this step, saved by hold
+    pub question: Option<QuestionHold>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -73,10 +86,14 @@ impl<'a> Data<'a> {
         loop_index: usize,
         step_count: &'a mut usize,
         step_limit: usize,
+        loop_limit: usize,
         step_vars: HashMap<String, Literal>,
         previous_info: Option<PreviousInfo>,
         custom_component: &'a serde_json::Map<String, serde_json::Value>,
         native_component: &'a serde_json::Map<String, serde_json::Value>,
+        debug: bool,
+        max_duration: u64,
+        auto_typing: AutoTyping,
     ) -> Self {
         let constants = flow.constants.clone();
 
@@ -93,10 +110,17 @@ impl<'a> Data<'a> {
             loop_index,
             step_count,
             step_limit,
+            loop_limit,
             step_vars,
+            fn_depth: 0,
             previous_info,
             custom_component,
             native_component,
+            debug,
+            max_duration,
+            auto_typing,
+            typing: None,
+            question: None,
         }
     }
 
@@ -144,6 +168,7 @@ pub fn init_child_context(data: &Data) -> Context {
         step: data.context.step.clone(),
         flow: data.context.flow.clone(),
         previous_bot: data.context.previous_bot.clone(),
+        one_of: data.context.one_of.clone(),
     }
 }
 
@@ -152,7 +177,7 @@ pub fn init_child_scope<'a>(
     context: &'a mut Context,
     step_count: &'a mut usize,
 ) -> Data<'a> {
-    Data::new(
+    let mut new_scope_data = Data::new(
         &data.flows,
         &data.extern_flows,
         &data.flow,
@@ -164,9 +189,16 @@ pub fn init_child_scope<'a>(
         data.loop_index,
         step_count,
         data.step_limit,
+        data.loop_limit,
         HashMap::new(),
         data.previous_info.clone(),
         &data.custom_component,
         &data.native_component,
-    )
+        data.debug,
+        data.max_duration,
+        data.auto_typing,
+    );
+    new_scope_data.fn_depth = data.fn_depth + 1;
+
+    new_scope_data
 }
@@ -10,6 +10,7 @@ pub struct Event {
     pub ttl_duration: Option<i64>,
     pub low_data_mode: Option<bool>,
     pub step_limit: Option<usize>,
+    pub loop_limit: Option<usize>,
     pub secure: bool,
 }
 
@@ -26,6 +27,7 @@ impl Default for Event {
             ttl_duration: None,
             low_data_mode: None,
             step_limit: None,
+            loop_limit: None,
             secure: false,
         }
     }
@@ -44,7 +46,47 @@ impl Event {
             ttl_duration: None,
             low_data_mode: None,
             step_limit: None,
+            loop_limit: None,
             secure: false,
         }
     }
+
+    /// channels send coordinates as lat/long, lat/lng or latitude/longitude,
+    /// as numbers or strings
+    pub fn get_location(content: &serde_json::Value) -> Option<(f64, f64)> {
+        let get_coordinate = |keys: &[&str]| {
+            keys.iter()
+                .find_map(|key| match &content[key] {
+                    serde_json::Value::Number(number) => number.as_f64(),
+                    serde_json::Value::String(string) => string.trim().parse::<f64>().ok(),
+                    _ => None,
+                })
+                .filter(|coordinate| coordinate.is_finite())
+        };
+
+        match (
+            get_coordinate(&["lat", "latitude"]),
+            get_coordinate(&["long", "lng", "longitude"]),
+        ) {
+            (Some(lat), Some(long)) => Some((lat, long)),
+            _ => None,
+        }
+    }
+
+    /// content of the event as seen from the flow, location events always
+    /// expose their coordinates as `lat` and `long` floats
+    pub fn get_content(&self) -> serde_json::Value {
+        let mut content = self.content.to_owned();
+
+        if let ("location", Some((lat, long)), Some(map)) = (
+            self.content_type.as_str(),
+            Event::get_location(&self.content),
+            content.as_object_mut(),
+        ) {
+            map.insert("lat".to_owned(), serde_json::json!(lat));
+            map.insert("long".to_owned(), serde_json::json!(long));
+        }
+
+        content
+    }
 }
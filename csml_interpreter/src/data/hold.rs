@@ -1,4 +1,4 @@
-use crate::data::{Data, Literal};
+use crate::data::{Data, Event, Literal};
 use serde::{Deserialize, Serialize};
 
 use super::data::PreviousInfo;
@@ -13,6 +13,17 @@ pub struct IndexInfo {
     pub loop_index: Vec<usize>,
 }
 
+/// a Question with accepted answers waiting for the next event, it is saved
+/// with the hold so the question can be asked again on a wrong answer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuestionHold {
+    pub message: serde_json::Value,
+    pub accepts: Vec<String>,
+    pub retries: u64,
+    pub attempts: u64,
+    pub fallback: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Hold {
     pub index: IndexInfo,
@@ -21,12 +32,44 @@ pub struct Hold {
     pub flow_name: String,
     pub previous: Option<PreviousInfo>,
     pub secure: bool,
+    pub question: Option<Box<QuestionHold>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // STATIC FUNCTIONS
 ////////////////////////////////////////////////////////////////////////////////
 
+impl QuestionHold {
+    /// only questions emitted with a list of accepted answers are validated
+    pub fn from_message(content: &serde_json::Value) -> Option<Self> {
+        let accepts = content["accepts"]
+            .as_array()?
+            .iter()
+            .map(|accept| match accept {
+                serde_json::Value::String(accept) => accept.to_owned(),
+                accept => accept.to_string(),
+            })
+            .collect();
+
+        Some(Self {
+            message: content.to_owned(),
+            accepts,
+            retries: content["retries"].as_u64().unwrap_or(0),
+            attempts: 0,
+            fallback: content["fallback"].as_str().map(|step| step.to_owned()),
+        })
+    }
+
+    /// answers are compared without case and surrounding whitespace
+    pub fn is_accepted(&self, event: &Event) -> bool {
+        let answer = event.content_value.trim().to_lowercase();
+
+        self.accepts
+            .iter()
+            .any(|accept| accept.trim().to_lowercase() == answer)
+    }
+}
+
 impl Hold {
     pub fn new(
         index: IndexInfo,
@@ -43,9 +86,15 @@ impl Hold {
             flow_name,
             previous,
             secure,
+            question: None,
         }
     }
 
+    pub fn with_question(mut self, question: Option<QuestionHold>) -> Self {
+        self.question = question.map(Box::new);
+        self
+    }
+
     pub fn default() -> Self {
         Self {
             index: IndexInfo {
@@ -57,6 +106,7 @@ impl Hold {
             flow_name: "".to_owned(),
             previous: None,
             secure: false,
+            question: None,
         }
     }
 }
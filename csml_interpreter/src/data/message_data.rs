@@ -44,7 +44,8 @@ impl Add<MessageData> for MessageData {
                 _ => None,
             },
             messages: [&self.messages[..], &other.messages[..]].concat(),
-            hold: self.hold,
+            // a hold reached in a nested scope or a later step is kept
+            hold: self.hold.or(other.hold),
             exit_condition: match (&self.exit_condition, &other.exit_condition) {
                 (Some(exit_condition), None) => Some(exit_condition.to_owned()),
                 (None, Some(exit_condition)) => Some(exit_condition.to_owned()),
@@ -21,6 +21,10 @@ pub enum MSG {
         log_lvl: LogLvl,
     },
     Hold(Hold),
+    OneOf {
+        key: String,
+        index: usize,
+    },
     Next {
         flow: Option<String>,
         step: Option<ContextStepInfo>,
@@ -10,6 +10,7 @@ pub mod string;
 pub mod tools;
 pub mod tools_crypto;
 pub mod tools_jwt;
+pub mod tools_random;
 pub mod tools_smtp;
 pub mod tools_time;
 
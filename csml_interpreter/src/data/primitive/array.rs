@@ -4,8 +4,9 @@ use crate::data::{
     literal,
     literal::ContentType,
     primitive::{
-        Primitive, PrimitiveBoolean, PrimitiveClosure, PrimitiveInt, PrimitiveNull,
-        PrimitiveString, PrimitiveType, Right,
+        tools::{get_length, get_slice_range},
+        tools_random, Primitive, PrimitiveBoolean, PrimitiveClosure, PrimitiveFloat, PrimitiveInt,
+        PrimitiveNull, PrimitiveObject, PrimitiveString, PrimitiveType, Right,
     },
     tokens::TYPES,
     ArgsType, Interval, Literal, MemoryType, Message, MessageData, MSG,
@@ -51,19 +52,39 @@ const FUNCTIONS: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_map! {
     "is_empty" => (PrimitiveArray::is_empty as PrimitiveMethod, Right::Read),
     "insert_at" => (PrimitiveArray::insert_at as PrimitiveMethod, Right::Write),
     "index_of" => (PrimitiveArray::index_of as PrimitiveMethod, Right::Read),
+    "contains" => (PrimitiveArray::contains as PrimitiveMethod, Right::Read),
     "join" => (PrimitiveArray::join as PrimitiveMethod, Right::Read),
     "length" => (PrimitiveArray::length as PrimitiveMethod, Right::Read),
     "one_of" => (PrimitiveArray::one_of as PrimitiveMethod, Right::Read),
     "push" => (PrimitiveArray::push as PrimitiveMethod, Right::Write),
     "pop" => (PrimitiveArray::pop as PrimitiveMethod, Right::Write),
+    "shift" => (PrimitiveArray::shift as PrimitiveMethod, Right::Write),
+    "unshift" => (PrimitiveArray::unshift as PrimitiveMethod, Right::Write),
     "remove_at" => (PrimitiveArray::remove_at as PrimitiveMethod, Right::Write),
     "slice" => (PrimitiveArray::slice as PrimitiveMethod, Right::Read),
     "shuffle" => (PrimitiveArray::shuffle as PrimitiveMethod, Right::Write),
     "map" => (PrimitiveArray::map as PrimitiveMethod, Right::Read),
+    "pluck" => (PrimitiveArray::pluck as PrimitiveMethod, Right::Read),
     "filter" => (PrimitiveArray::filter as PrimitiveMethod, Right::Read),
+    "reject" => (PrimitiveArray::reject as PrimitiveMethod, Right::Read),
     "reduce" => (PrimitiveArray::reduce as PrimitiveMethod, Right::Read),
+    "sort" => (PrimitiveArray::sort as PrimitiveMethod, Right::Read),
+    "sort_desc" => (PrimitiveArray::sort_desc as PrimitiveMethod, Right::Read),
+    "sort_by" => (PrimitiveArray::sort_by as PrimitiveMethod, Right::Read),
+    "sort_by_desc" => (PrimitiveArray::sort_by_desc as PrimitiveMethod, Right::Read),
+    "sum" => (PrimitiveArray::sum as PrimitiveMethod, Right::Read),
+    "min" => (PrimitiveArray::min as PrimitiveMethod, Right::Read),
+    "max" => (PrimitiveArray::max as PrimitiveMethod, Right::Read),
+    "mean" => (PrimitiveArray::mean as PrimitiveMethod, Right::Read),
+    "unique" => (PrimitiveArray::unique as PrimitiveMethod, Right::Read),
+    "chunk" => (PrimitiveArray::chunk as PrimitiveMethod, Right::Read),
+    "zip" => (PrimitiveArray::zip as PrimitiveMethod, Right::Read),
+    "from_entries" => (PrimitiveArray::from_entries as PrimitiveMethod, Right::Read),
+    "clone" => (PrimitiveArray::clone as PrimitiveMethod, Right::Read),
     "reverse" => (PrimitiveArray::reverse as PrimitiveMethod, Right::Read),
+    "reverse_in_place" => (PrimitiveArray::reverse_in_place as PrimitiveMethod, Right::Write),
     "append" => (PrimitiveArray::append as PrimitiveMethod, Right::Read),
+    "concat" => (PrimitiveArray::concat as PrimitiveMethod, Right::Read),
     "flatten" => (PrimitiveArray::flatten as PrimitiveMethod, Right::Read),
 };
 
@@ -78,25 +99,122 @@ pub struct PrimitiveArray {
 
 fn check_index(
     index: i64,
-    length: i64,
+    length: usize,
+    include_end: bool,
     flow_name: &str,
     interval: Interval,
-) -> Result<(), ErrorInfo> {
-    if index.is_negative() {
+) -> Result<usize, ErrorInfo> {
+    // negative indexes count from the end of the array
+    let resolved = if index.is_negative() {
+        index + length as i64
+    } else {
+        index
+    };
+
+    let upper_bound = if include_end {
+        length as i64
+    } else {
+        length as i64 - 1
+    };
+
+    if resolved.is_negative() || resolved > upper_bound {
         return Err(gen_error_info(
             Position::new(interval, flow_name),
-            ERROR_ARRAY_NEGATIVE.to_owned(),
+            format!(
+                "{} (index: {}, length: {})",
+                ERROR_ARRAY_INDEX_OUT_OF_RANGE, index, length
+            ),
         ));
     }
 
-    if index > length {
-        return Err(gen_error_info(
-            Position::new(interval, flow_name),
-            ERROR_ARRAY_INDEX.to_owned(),
-        ));
+    Ok(resolved as usize)
+}
+
+// index_of() and contains() must agree, both compare with Primitive::is_eq
+fn position_of(array: &[Literal], value: &Literal) -> Option<usize> {
+    array.iter().position(|literal| literal == value)
+}
+
+fn is_number_type(primitive_type: &PrimitiveType) -> bool {
+    matches!(
+        primitive_type,
+        PrimitiveType::PrimitiveInt | PrimitiveType::PrimitiveFloat
+    )
+}
+
+fn get_number(literal: &Literal) -> Option<f64> {
+    if let Some(int) = literal.primitive.as_any().downcast_ref::<PrimitiveInt>() {
+        return Some(int.value as f64);
+    }
+
+    literal
+        .primitive
+        .as_any()
+        .downcast_ref::<PrimitiveFloat>()
+        .map(|float| float.value)
+}
+
+fn is_nan(literal: &Literal) -> bool {
+    match literal.primitive.as_any().downcast_ref::<PrimitiveFloat>() {
+        Some(float) => float.value.is_nan(),
+        None => false,
+    }
+}
+
+// ints and floats are compared numerically and strings lexicographically,
+// any other pair of types can not be sorted
+fn compare_sortable(lhs: &Literal, rhs: &Literal) -> Option<Ordering> {
+    let (lhs_type, rhs_type) = (lhs.primitive.get_type(), rhs.primitive.get_type());
+
+    match (&lhs_type, &rhs_type) {
+        (PrimitiveType::PrimitiveString, PrimitiveType::PrimitiveString) => {
+            Some(lhs.primitive.to_string().cmp(&rhs.primitive.to_string()))
+        }
+        (PrimitiveType::PrimitiveInt, PrimitiveType::PrimitiveInt) => {
+            lhs.primitive.is_cmp(&*rhs.primitive)
+        }
+        _ if is_number_type(&lhs_type) && is_number_type(&rhs_type) => {
+            get_number(lhs)?.partial_cmp(&get_number(rhs)?)
+        }
+        _ => None,
+    }
+}
+
+fn compare_literals(lhs: &Literal, rhs: &Literal) -> Option<Ordering> {
+    compare_sortable(lhs, rhs).or_else(|| lhs.primitive.is_cmp(&*rhs.primitive))
+}
+
+// NaN floats are always placed at the end, whatever the sort direction
+fn sort_ordering(lhs: &Literal, rhs: &Literal, descending: bool) -> Ordering {
+    match (is_nan(lhs), is_nan(rhs)) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        _ => {
+            let ordering = compare_literals(lhs, rhs).unwrap_or(Ordering::Equal);
+
+            match descending {
+                true => ordering.reverse(),
+                false => ordering,
+            }
+        }
     }
+}
 
-    Ok(())
+enum NumericValues {
+    Int(Vec<i64>),
+    Float(Vec<f64>),
+}
+
+fn flatten_literals(values: &[Literal], depth: Option<usize>, new_array: &mut Vec<Literal>) {
+    for literal in values.iter() {
+        match literal.primitive.as_any().downcast_ref::<PrimitiveArray>() {
+            Some(array) if depth != Some(0) => {
+                flatten_literals(&array.value, depth.map(|depth| depth - 1), new_array)
+            }
+            _ => new_array.push(literal.to_owned()),
+        }
+    }
 }
 
 impl PrimitiveArray {
@@ -288,7 +406,8 @@ impl PrimitiveArray {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "find(value: primitive) => array";
+        let usage =
+            "find(value: primitive) => array | find(key: string, value: primitive) => object";
 
         if array.value.len() + args.len() == usize::MAX {
             return Err(gen_error_info(
@@ -297,6 +416,10 @@ impl PrimitiveArray {
             ));
         }
 
+        if args.len() == 2 {
+            return PrimitiveArray::find_by_key(array, args, interval, data, usage);
+        }
+
         let value = match args.get("arg0") {
             Some(res) => res,
             _ => {
@@ -390,14 +513,15 @@ impl PrimitiveArray {
             }
         };
 
-        check_index(
+        let index = check_index(
             *index,
-            array.value.len() as i64,
+            array.value.len(),
+            true,
             &data.context.flow,
             interval,
         )?;
 
-        array.value.insert(*index as usize, value.clone());
+        array.value.insert(index, value.clone());
 
         Ok(PrimitiveNull::get_literal(interval))
     }
@@ -430,13 +554,36 @@ impl PrimitiveArray {
             }
         };
 
-        for (index, literal) in array.value.iter().enumerate() {
-            if literal == value {
-                return Ok(PrimitiveInt::get_literal(index as i64, interval));
-            }
+        match position_of(&array.value, value) {
+            Some(index) => Ok(PrimitiveInt::get_literal(index as i64, interval)),
+            None => Ok(PrimitiveInt::get_literal(-1, interval)),
         }
+    }
+
+    fn contains(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "contains(value: primitive) => boolean";
 
-        Ok(PrimitiveInt::get_literal(-1, interval))
+        let value = match args.get("arg0") {
+            Some(res) if args.len() == 1 => res,
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        let result = position_of(&array.value, value).is_some();
+
+        Ok(PrimitiveBoolean::get_literal(result, interval))
     }
 
     fn join(
@@ -506,7 +653,8 @@ impl PrimitiveArray {
             ));
         }
 
-        let result = array.value.len();
+        let result = get_length(&*array)
+            .map_err(|err| gen_error_info(Position::new(interval, &data.context.flow), err))?;
 
         Ok(PrimitiveInt::get_literal(result as i64, interval))
     }
@@ -529,10 +677,9 @@ impl PrimitiveArray {
             ));
         }
 
-        if let Some(res) = array
-            .value
-            .get(rand::thread_rng().gen_range(0..array.value.len()))
-        {
+        if let Some(res) = array.value.get(tools_random::with_rng(|rng| {
+            rng.gen_range(0..array.value.len())
+        })) {
             return Ok(res.to_owned());
         }
 
@@ -606,6 +753,67 @@ impl PrimitiveArray {
         }
     }
 
+    fn shift(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "shift() => primitive";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        if array.value.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                ERROR_ARRAY_SHIFT.to_owned(),
+            ));
+        }
+
+        Ok(array.value.remove(0))
+    }
+
+    fn unshift(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "unshift(value: primitive) => null";
+
+        let value = match args.get("arg0") {
+            Some(res) if args.len() == 1 => res,
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        if array.value.len() + args.len() == usize::MAX {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("{} {}", ERROR_ARRAY_UNSHIFT_OVERFLOW, usize::MAX),
+            ));
+        }
+
+        array.value.insert(0, value.to_owned());
+
+        Ok(PrimitiveNull::get_literal(interval))
+    }
+
     fn remove_at(
         array: &mut PrimitiveArray,
         args: &HashMap<String, Literal>,
@@ -641,14 +849,15 @@ impl PrimitiveArray {
             }
         };
 
-        check_index(
+        let index = check_index(
             *index,
-            array.value.len() as i64,
+            array.value.len(),
+            false,
             &data.context.flow,
             interval,
         )?;
 
-        Ok(array.value.remove(*index as usize))
+        Ok(array.value.remove(index))
     }
 
     fn shuffle(
@@ -671,7 +880,7 @@ impl PrimitiveArray {
 
         let mut vector = array.value.to_owned();
 
-        vector.shuffle(&mut rand::thread_rng());
+        tools_random::with_rng(|rng| vector.shuffle(rng));
 
         Ok(PrimitiveArray::get_literal(&vector, interval))
     }
@@ -688,110 +897,46 @@ impl PrimitiveArray {
         let usage = "slice(start: Integer, end: Optional<Integer>) => [Literal]";
         let len = array.value.len();
 
-        match args.len() {
-            1 => match args.get("arg0") {
-                Some(literal) => {
-                    let mut int_start = Literal::get_value::<i64>(
-                        &literal.primitive,
-                        &data.context.flow,
-                        literal.interval,
-                        ERROR_SLICE_ARG_INT.to_owned(),
-                    )?
-                    .to_owned();
-
-                    if int_start < 0 {
-                        int_start = len as i64 + int_start;
-                    }
-
-                    let start = match int_start {
-                        value if value >= 0 && (value as usize) < len => value as usize,
-                        _ => {
-                            return Err(gen_error_info(
-                                Position::new(interval, &data.context.flow),
-                                ERROR_SLICE_ARG_LEN.to_owned(),
-                            ))
-                        }
-                    };
-
-                    let value = array.value[start..]
-                        .iter()
-                        .cloned()
-                        .collect::<Vec<Literal>>();
+        if args.is_empty() || args.len() > 2 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
 
-                    Ok(PrimitiveArray::get_literal(&value, interval))
-                }
-                _ => Err(gen_error_info(
+        let int_start = match args.get("arg0") {
+            Some(literal) => *Literal::get_value::<i64>(
+                &literal.primitive,
+                &data.context.flow,
+                literal.interval,
+                ERROR_SLICE_ARG_INT.to_owned(),
+            )?,
+            None => {
+                return Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
                     ERROR_SLICE_ARG_INT.to_owned(),
-                )),
-            },
-            2 => match (args.get("arg0"), args.get("arg1")) {
-                (Some(literal_start), Some(literal_end)) => {
-                    let mut int_start = Literal::get_value::<i64>(
-                        &literal_start.primitive,
-                        &data.context.flow,
-                        literal_start.interval,
-                        ERROR_SLICE_ARG_INT.to_owned(),
-                    )?
-                    .to_owned();
-                    let mut int_end = Literal::get_value::<i64>(
-                        &literal_end.primitive,
-                        &data.context.flow,
-                        literal_end.interval,
-                        ERROR_SLICE_ARG_INT.to_owned(),
-                    )?
-                    .to_owned();
-
-                    if int_start < 0 {
-                        int_start = len as i64 + int_start;
-                    }
+                ))
+            }
+        };
 
-                    if int_end.is_negative() {
-                        int_end = len as i64 + int_end;
-                    }
-                    if int_end < int_start {
-                        return Err(gen_error_info(
-                            Position::new(interval, &data.context.flow),
-                            ERROR_SLICE_ARG2.to_owned(),
-                        ));
-                    }
+        let int_end = match args.get("arg1") {
+            Some(literal) => *Literal::get_value::<i64>(
+                &literal.primitive,
+                &data.context.flow,
+                literal.interval,
+                ERROR_SLICE_ARG_INT.to_owned(),
+            )?,
+            None => len as i64,
+        };
 
-                    let (start, end) = match (int_start, int_end) {
-                        (start, end)
-                            if int_start >= 0
-                                && end >= 0
-                                && (start as usize) < len
-                                && (end as usize) <= len =>
-                        {
-                            (start as usize, end as usize)
-                        }
-                        _ => {
-                            return Err(gen_error_info(
-                                Position::new(interval, &data.context.flow),
-                                ERROR_SLICE_ARG_LEN.to_owned(),
-                            ))
-                        }
-                    };
-                    let value = array.value[start..end]
-                        .iter()
-                        .cloned()
-                        .collect::<Vec<Literal>>();
+        // out of range bounds are clamped and start > end gives an empty array
+        let (start, end) = get_slice_range(int_start, int_end, len);
+        let value = array.value[start..end].to_vec();
 
-                    Ok(PrimitiveArray::get_literal(&value, interval))
-                }
-                _ => Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_SLICE_ARG_INT.to_owned(),
-                )),
-            },
-            _ => Err(gen_error_info(
-                Position::new(interval, &data.context.flow),
-                format!("usage: {}", usage),
-            )),
-        }
+        Ok(PrimitiveArray::get_literal(&value, interval))
     }
 
-    fn reverse(
+    fn sort(
         array: &mut PrimitiveArray,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -800,7 +945,7 @@ impl PrimitiveArray {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "reverse() => [Literal]";
+        let usage = "sort() => [Literal]";
 
         if !args.is_empty() {
             return Err(gen_error_info(
@@ -809,13 +954,12 @@ impl PrimitiveArray {
             ));
         }
 
-        let mut reversed_list = array.value.clone();
-        reversed_list.reverse();
+        let sorted = PrimitiveArray::get_sorted(array, false, interval, data, "sort")?;
 
-        Ok(PrimitiveArray::get_literal(&reversed_list, interval))
+        Ok(PrimitiveArray::get_literal(&sorted, interval))
     }
 
-    fn append(
+    fn sort_desc(
         array: &mut PrimitiveArray,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -824,42 +968,53 @@ impl PrimitiveArray {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "append(other_array: [Literal]) => [Literal]";
+        let usage = "sort_desc() => [Literal]";
 
-        if args.len() != 1 {
+        if !args.is_empty() {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let mut other_array: Vec<Literal> = match args.get("arg0") {
-            Some(res) if res.content_type == "array" => {
-                let value = Literal::get_value::<Vec<Literal>>(
-                    &res.primitive,
-                    &data.context.flow,
-                    interval,
-                    ERROR_ARRAY_INSERT_AT.to_owned(),
-                )?;
+        let sorted = PrimitiveArray::get_sorted(array, true, interval, data, "sort_desc")?;
 
-                (*value).clone().to_vec()
-            }
-            _ => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_ARRAY_INSERT_AT.to_owned(),
-                ));
-            }
-        };
+        Ok(PrimitiveArray::get_literal(&sorted, interval))
+    }
 
-        let mut new_array = array.value.clone();
+    fn sort_by(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "sort_by(key: string) => [object]";
 
-        new_array.append(&mut other_array);
+        let sorted = PrimitiveArray::get_sorted_by_key(array, args, false, interval, data, usage)?;
 
-        Ok(PrimitiveArray::get_literal(&new_array, interval))
+        Ok(PrimitiveArray::get_literal(&sorted, interval))
     }
 
-    fn flatten(
+    fn sort_by_desc(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "sort_by_desc(key: string) => [object]";
+
+        let sorted = PrimitiveArray::get_sorted_by_key(array, args, true, interval, data, usage)?;
+
+        Ok(PrimitiveArray::get_literal(&sorted, interval))
+    }
+
+    fn sum(
         array: &mut PrimitiveArray,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -868,75 +1023,607 @@ impl PrimitiveArray {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "flatten() => [Literal]";
+        let usage = "sum() => int | float, 0 for an empty array";
 
-        if args.len() != 0 {
+        if !args.is_empty() {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let mut new_array = vec![];
-
-        for lit in array.value.iter() {
-            if lit.content_type == "array" {
-                let value = Literal::get_value::<Vec<Literal>>(
-                    &lit.primitive,
-                    &data.context.flow,
-                    interval,
-                    ERROR_ARRAY_INSERT_AT.to_owned(),
-                )?;
-                for elem in value.iter() {
-                    new_array.push(elem.to_owned())
+        match PrimitiveArray::get_numeric_values(array, "sum", interval, data)? {
+            NumericValues::Int(values) => {
+                match values
+                    .iter()
+                    .try_fold(0i64, |acc, value| acc.checked_add(*value))
+                {
+                    Some(sum) => Ok(PrimitiveInt::get_literal(sum, interval)),
+                    None => Err(gen_error_info(
+                        Position::new(interval, &data.context.flow),
+                        format!("[sum] {}", ERROR_ARRAY_SUM_OVERFLOW),
+                    )),
                 }
-            } else {
-                new_array.push(lit.to_owned())
+            }
+            NumericValues::Float(values) => {
+                Ok(PrimitiveFloat::get_literal(values.iter().sum(), interval))
             }
         }
-
-        Ok(PrimitiveArray::get_literal(&new_array, interval))
     }
-}
 
-impl PrimitiveArray {
-    fn map(
+    fn min(
         array: &mut PrimitiveArray,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
         interval: Interval,
         data: &mut Data,
-        msg_data: &mut MessageData,
-        sender: &Option<mpsc::Sender<MSG>>,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "map(fn) expect one argument of type [Closure]";
+        let usage = "min() => int | float";
 
-        match args.get("arg0") {
-            Some(lit) => {
-                let closure: &PrimitiveClosure = Literal::get_value::<PrimitiveClosure>(
-                    &lit.primitive,
-                    &data.context.flow,
-                    interval,
-                    format!("usage: {}", usage),
-                )?;
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
 
-                let mut vec = vec![];
-                let mut context = init_child_context(&data);
-                let mut step_count = data.step_count.clone();
-                let mut new_scope_data = init_child_scope(data, &mut context, &mut step_count);
+        if array.value.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("[min] {}", ERROR_ARRAY_AGGREGATE_EMPTY),
+            ));
+        }
 
-                if let Some(memories) = closure.enclosed_variables.clone() {
-                    insert_memories_in_scope_memory(
-                        &mut new_scope_data,
-                        memories,
-                        msg_data,
-                        sender,
-                    );
-                }
+        match PrimitiveArray::get_numeric_values(array, "min", interval, data)? {
+            NumericValues::Int(values) => {
+                let value = values.iter().min().copied().unwrap_or_default();
 
-                for (index, value) in array.value.iter().enumerate() {
-                    let mut map = HashMap::new();
-                    map.insert("arg0".to_owned(), value.to_owned());
+                Ok(PrimitiveInt::get_literal(value, interval))
+            }
+            NumericValues::Float(values) => {
+                let value = values.iter().copied().fold(f64::NAN, f64::min);
+
+                Ok(PrimitiveFloat::get_literal(value, interval))
+            }
+        }
+    }
+
+    fn max(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "max() => int | float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        if array.value.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("[max] {}", ERROR_ARRAY_AGGREGATE_EMPTY),
+            ));
+        }
+
+        match PrimitiveArray::get_numeric_values(array, "max", interval, data)? {
+            NumericValues::Int(values) => {
+                let value = values.iter().max().copied().unwrap_or_default();
+
+                Ok(PrimitiveInt::get_literal(value, interval))
+            }
+            NumericValues::Float(values) => {
+                let value = values.iter().copied().fold(f64::NAN, f64::max);
+
+                Ok(PrimitiveFloat::get_literal(value, interval))
+            }
+        }
+    }
+
+    fn mean(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "mean() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        if array.value.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("[mean] {}", ERROR_ARRAY_AGGREGATE_EMPTY),
+            ));
+        }
+
+        let sum: f64 = match PrimitiveArray::get_numeric_values(array, "mean", interval, data)? {
+            NumericValues::Int(values) => values.iter().map(|value| *value as f64).sum(),
+            NumericValues::Float(values) => values.iter().sum(),
+        };
+
+        Ok(PrimitiveFloat::get_literal(
+            sum / array.value.len() as f64,
+            interval,
+        ))
+    }
+
+    fn chunk(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "chunk(size: Integer) => [[Literal]]";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let size = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveInt => {
+                *Literal::get_value::<i64>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_ARRAY_CHUNK.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_ARRAY_CHUNK.to_owned(),
+                ));
+            }
+        };
+
+        if size <= 0 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                ERROR_ARRAY_CHUNK.to_owned(),
+            ));
+        }
+
+        let chunks = array
+            .value
+            .chunks(size as usize)
+            .map(|chunk| PrimitiveArray::get_literal(chunk, interval))
+            .collect::<Vec<Literal>>();
+
+        Ok(PrimitiveArray::get_literal(&chunks, interval))
+    }
+
+    fn zip(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "zip(other: array) => [[Literal, Literal]], the result has the length of the shorter array";
+
+        let other = match args.get("arg0") {
+            Some(res)
+                if args.len() == 1 && res.primitive.get_type() == PrimitiveType::PrimitiveArray =>
+            {
+                Literal::get_value::<Vec<Literal>>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    format!("usage: {}", usage),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        let pairs = array
+            .value
+            .iter()
+            .zip(other.iter())
+            .map(|(first, second)| {
+                PrimitiveArray::get_literal(&[first.to_owned(), second.to_owned()], interval)
+            })
+            .collect::<Vec<Literal>>();
+
+        Ok(PrimitiveArray::get_literal(&pairs, interval))
+    }
+
+    fn from_entries(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "from_entries() => object, from an array of [key, value] pairs";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let mut object = HashMap::new();
+
+        // on duplicate keys the last pair wins
+        for entry in array.value.iter() {
+            let pair = match entry.primitive.as_any().downcast_ref::<PrimitiveArray>() {
+                Some(pair) if pair.value.len() == 2 => &pair.value,
+                _ => {
+                    return Err(gen_error_info(
+                        Position::new(interval, &data.context.flow),
+                        format!(
+                            "{} (found: {})",
+                            ERROR_ARRAY_FROM_ENTRIES,
+                            entry.primitive.to_string()
+                        ),
+                    ));
+                }
+            };
+
+            let key = match pair[0].primitive.get_type() {
+                PrimitiveType::PrimitiveString => pair[0].primitive.to_string(),
+                _ => {
+                    return Err(gen_error_info(
+                        Position::new(interval, &data.context.flow),
+                        format!(
+                            "{} (found key of type: {})",
+                            ERROR_ARRAY_FROM_ENTRIES,
+                            pair[0].primitive.get_type().to_string()
+                        ),
+                    ));
+                }
+            };
+
+            object.insert(key, pair[1].to_owned());
+        }
+
+        Ok(PrimitiveObject::get_literal(&object, interval))
+    }
+
+    fn clone(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "clone() => array";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        // cloning a Literal copies every nested primitive, nothing is shared with the source
+        Ok(PrimitiveArray::get_literal(&array.value, interval))
+    }
+
+    fn unique(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "unique() => [Literal]";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        // values are bucketed by their json representation and then compared
+        // with is_eq, so 1 and "1" are never considered equal
+        let mut buckets: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut unique_list: Vec<Literal> = Vec::new();
+
+        for literal in array.value.iter() {
+            let bucket = buckets
+                .entry(literal.primitive.to_json().to_string())
+                .or_default();
+
+            if !bucket.iter().any(|index| &unique_list[*index] == literal) {
+                bucket.push(unique_list.len());
+                unique_list.push(literal.to_owned());
+            }
+        }
+
+        Ok(PrimitiveArray::get_literal(&unique_list, interval))
+    }
+
+    fn reverse(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "reverse() => [Literal]";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let mut reversed_list = array.value.clone();
+        reversed_list.reverse();
+
+        Ok(PrimitiveArray::get_literal(&reversed_list, interval))
+    }
+
+    fn reverse_in_place(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "reverse_in_place() => null";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        array.value.reverse();
+
+        Ok(PrimitiveNull::get_literal(interval))
+    }
+
+    fn append(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "append(other_array: [Literal]) => [Literal]";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let mut other_array: Vec<Literal> = match args.get("arg0") {
+            Some(res) if res.content_type == "array" => {
+                let value = Literal::get_value::<Vec<Literal>>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_ARRAY_INSERT_AT.to_owned(),
+                )?;
+
+                (*value).clone().to_vec()
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_ARRAY_INSERT_AT.to_owned(),
+                ));
+            }
+        };
+
+        let mut new_array = array.value.clone();
+
+        new_array.append(&mut other_array);
+
+        Ok(PrimitiveArray::get_literal(&new_array, interval))
+    }
+
+    fn concat(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "concat(other: [Literal] | Literal) => [Literal], a value that is not an array is appended as a single element";
+
+        let other = match args.get("arg0") {
+            Some(res) if args.len() == 1 => res,
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        let mut new_array = array.value.clone();
+
+        match other.primitive.get_type() {
+            PrimitiveType::PrimitiveArray => {
+                let value = Literal::get_value::<Vec<Literal>>(
+                    &other.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_ARRAY_CONCAT.to_owned(),
+                )?;
+
+                new_array.extend(value.iter().cloned());
+            }
+            PrimitiveType::PrimitiveClosure => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!(
+                        "{}, got {}",
+                        ERROR_ARRAY_CONCAT,
+                        other.primitive.get_type().to_string()
+                    ),
+                ));
+            }
+            _ => new_array.push(other.to_owned()),
+        }
+
+        Ok(PrimitiveArray::get_literal(&new_array, interval))
+    }
+
+    fn flatten(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "flatten(depth: Optional<Integer | \"all\">) => [Literal], depth defaults to 1 and -1 or \"all\" flattens every level";
+
+        // None means that every nested level is flattened
+        let depth = match args.get("arg0") {
+            None if args.is_empty() => Some(1),
+            Some(res) if args.len() == 1 => match res.primitive.get_type() {
+                PrimitiveType::PrimitiveInt => {
+                    let depth = Literal::get_value::<i64>(
+                        &res.primitive,
+                        &data.context.flow,
+                        interval,
+                        ERROR_ARRAY_FLATTEN.to_owned(),
+                    )?;
+
+                    match *depth {
+                        -1 => None,
+                        depth if depth >= 0 => Some(depth as usize),
+                        _ => {
+                            return Err(gen_error_info(
+                                Position::new(interval, &data.context.flow),
+                                ERROR_ARRAY_FLATTEN.to_owned(),
+                            ));
+                        }
+                    }
+                }
+                PrimitiveType::PrimitiveString if res.primitive.to_string() == "all" => None,
+                _ => {
+                    return Err(gen_error_info(
+                        Position::new(interval, &data.context.flow),
+                        ERROR_ARRAY_FLATTEN.to_owned(),
+                    ));
+                }
+            },
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        let mut new_array = vec![];
+
+        flatten_literals(&array.value, depth, &mut new_array);
+
+        Ok(PrimitiveArray::get_literal(&new_array, interval))
+    }
+}
+
+impl PrimitiveArray {
+    fn map(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        msg_data: &mut MessageData,
+        sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "map(fn) expect one argument of type [Closure] or map(key: string) on an array of objects";
+
+        if let Some(lit) = args.get("arg0") {
+            if lit.primitive.get_type() == PrimitiveType::PrimitiveString {
+                return PrimitiveArray::pluck(
+                    array,
+                    args,
+                    additional_info,
+                    interval,
+                    data,
+                    msg_data,
+                    sender,
+                );
+            }
+        }
+
+        match args.get("arg0") {
+            Some(lit) => {
+                let closure: &PrimitiveClosure = Literal::get_value::<PrimitiveClosure>(
+                    &lit.primitive,
+                    &data.context.flow,
+                    interval,
+                    format!("usage: {}", usage),
+                )?;
+
+                let mut vec = vec![];
+                let mut context = init_child_context(&data);
+                let mut step_count = data.step_count.clone();
+                let mut new_scope_data = init_child_scope(data, &mut context, &mut step_count);
+
+                if let Some(memories) = closure.enclosed_variables.clone() {
+                    insert_memories_in_scope_memory(
+                        &mut new_scope_data,
+                        memories,
+                        msg_data,
+                        sender,
+                    );
+                }
+
+                for (index, value) in array.value.iter().enumerate() {
+                    let mut map = HashMap::new();
+                    map.insert("arg0".to_owned(), value.to_owned());
                     if closure.args.len() >= 2 {
                         map.insert(
                             "arg1".to_owned(),
@@ -983,7 +1670,11 @@ impl PrimitiveArray {
         msg_data: &mut MessageData,
         sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "filter(fn) expect one argument of type [Closure]";
+        let usage = "filter(fn) expect one argument of type [Closure], or filter(key: string, operator: Optional<string>, value) on an array of objects";
+
+        if args.len() > 1 {
+            return PrimitiveArray::filter_by_key(array, args, false, interval, data, usage);
+        }
 
         match args.get("arg0") {
             Some(lit) => {
@@ -1053,6 +1744,67 @@ impl PrimitiveArray {
         }
     }
 
+    fn pluck(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "pluck(key: string) => array, key can be a path like \"price.amount\"";
+
+        let key = match args.get("arg0") {
+            Some(res)
+                if args.len() == 1
+                    && res.primitive.get_type() == PrimitiveType::PrimitiveString =>
+            {
+                res.primitive.to_string()
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        // elements that are not objects or lack the key give null
+        let values = array
+            .value
+            .iter()
+            .map(|literal| {
+                key.split('.')
+                    .try_fold(literal, |literal, key| {
+                        literal
+                            .primitive
+                            .as_any()
+                            .downcast_ref::<PrimitiveObject>()
+                            .and_then(|object| object.value.get(key))
+                    })
+                    .cloned()
+                    .unwrap_or_else(|| PrimitiveNull::get_literal(interval))
+            })
+            .collect::<Vec<Literal>>();
+
+        Ok(PrimitiveArray::get_literal(&values, interval))
+    }
+
+    fn reject(
+        array: &mut PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "reject(key: string, operator: Optional<string>, value) => array";
+
+        PrimitiveArray::filter_by_key(array, args, true, interval, data, usage)
+    }
+
     fn reduce(
         array: &mut PrimitiveArray,
         args: &HashMap<String, Literal>,
@@ -1122,6 +1874,299 @@ impl PrimitiveArray {
     }
 }
 
+impl PrimitiveArray {
+    // every element must be a number, the values are promoted to float as
+    // soon as one of them is a float
+    fn get_numeric_values(
+        array: &PrimitiveArray,
+        method: &str,
+        interval: Interval,
+        data: &Data,
+    ) -> Result<NumericValues, ErrorInfo> {
+        let mut ints = Vec::with_capacity(array.value.len());
+        let mut floats = Vec::with_capacity(array.value.len());
+        let mut has_float = false;
+
+        for (index, literal) in array.value.iter().enumerate() {
+            let primitive = literal.primitive.as_any();
+
+            if let Some(int) = primitive.downcast_ref::<PrimitiveInt>() {
+                ints.push(int.value);
+                floats.push(int.value as f64);
+            } else if let Some(float) = primitive.downcast_ref::<PrimitiveFloat>() {
+                floats.push(float.value);
+                has_float = true;
+            } else {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!(
+                        "[{}] {} (element at index {} is of type {})",
+                        method,
+                        ERROR_ARRAY_NUMERIC,
+                        index,
+                        literal.primitive.get_type().to_string()
+                    ),
+                ));
+            }
+        }
+
+        match has_float {
+            true => Ok(NumericValues::Float(floats)),
+            false => Ok(NumericValues::Int(ints)),
+        }
+    }
+
+    fn get_sorted(
+        array: &PrimitiveArray,
+        descending: bool,
+        interval: Interval,
+        data: &Data,
+        method: &str,
+    ) -> Result<Vec<Literal>, ErrorInfo> {
+        if let Some(first) = array.value.first() {
+            for (index, literal) in array.value.iter().enumerate().skip(1) {
+                let (first_type, literal_type) =
+                    (first.primitive.get_type(), literal.primitive.get_type());
+
+                let sortable = match (&first_type, &literal_type) {
+                    (PrimitiveType::PrimitiveString, PrimitiveType::PrimitiveString) => true,
+                    _ => is_number_type(&first_type) && is_number_type(&literal_type),
+                };
+
+                if !sortable {
+                    return Err(gen_error_info(
+                        Position::new(interval, &data.context.flow),
+                        format!(
+                            "[{}] {}: {} at index 0 and {} at index {}",
+                            method,
+                            ERROR_ARRAY_SORT,
+                            first_type.to_string(),
+                            literal_type.to_string(),
+                            index
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let mut sorted = array.value.clone();
+        // sort_by is stable, equal elements keep their original order
+        sorted.sort_by(|lhs, rhs| sort_ordering(lhs, rhs, descending));
+
+        Ok(sorted)
+    }
+
+    fn get_sorted_by_key(
+        array: &PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        descending: bool,
+        interval: Interval,
+        data: &Data,
+        usage: &str,
+    ) -> Result<Vec<Literal>, ErrorInfo> {
+        let key = match args.get("arg0") {
+            Some(res)
+                if args.len() == 1
+                    && res.primitive.get_type() == PrimitiveType::PrimitiveString =>
+            {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    format!("usage: {}", usage),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        let mut keyed = Vec::with_capacity(array.value.len());
+
+        for (index, literal) in array.value.iter().enumerate() {
+            let value = literal
+                .primitive
+                .as_any()
+                .downcast_ref::<PrimitiveObject>()
+                .and_then(|object| object.value.get(key));
+
+            match value {
+                Some(value) => keyed.push((value, literal)),
+                None => {
+                    return Err(gen_error_info(
+                        Position::new(interval, &data.context.flow),
+                        format!(
+                            "{} '{}' (element at index {})",
+                            ERROR_ARRAY_SORT_BY_KEY, key, index
+                        ),
+                    ));
+                }
+            }
+        }
+
+        // every value must be comparable, NaN floats are placed at the end
+        if let Some((reference, _)) = keyed.iter().find(|(value, _)| !is_nan(value)) {
+            for (index, (value, _)) in keyed.iter().enumerate() {
+                let comparable = is_nan(value) || compare_literals(reference, value).is_some();
+
+                if !comparable {
+                    return Err(gen_error_info(
+                        Position::new(interval, &data.context.flow),
+                        format!(
+                            "{} '{}' (element at index {} is of type {})",
+                            ERROR_ARRAY_SORT_BY_CMP,
+                            key,
+                            index,
+                            value.primitive.get_type().to_string()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        // sort_by is stable, elements with equal values keep their original order
+        keyed.sort_by(|(lhs, _), (rhs, _)| sort_ordering(lhs, rhs, descending));
+
+        Ok(keyed
+            .into_iter()
+            .map(|(_, literal)| literal.to_owned())
+            .collect())
+    }
+
+    // an element matches if it is an object whose 'key' field satisfies the
+    // comparison, filter() keeps the matching elements and reject() the others
+    fn filter_by_key(
+        array: &PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        reject: bool,
+        interval: Interval,
+        data: &Data,
+        usage: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let (operator, value) = match (args.len(), args.get("arg1"), args.get("arg2")) {
+            (2, Some(value), None) => ("==".to_owned(), value),
+            (3, Some(operator), Some(value))
+                if operator.primitive.get_type() == PrimitiveType::PrimitiveString =>
+            {
+                (operator.primitive.to_string(), value)
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        let key = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                res.primitive.to_string()
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        let compare: fn(&Literal, &Literal) -> bool = match operator.as_str() {
+            "==" => |lhs, rhs| lhs == rhs,
+            "!=" => |lhs, rhs| lhs != rhs,
+            ">" => |lhs, rhs| compare_literals(lhs, rhs) == Some(Ordering::Greater),
+            "<" => |lhs, rhs| compare_literals(lhs, rhs) == Some(Ordering::Less),
+            ">=" => |lhs, rhs| {
+                matches!(
+                    compare_literals(lhs, rhs),
+                    Some(Ordering::Greater | Ordering::Equal)
+                )
+            },
+            "<=" => |lhs, rhs| {
+                matches!(
+                    compare_literals(lhs, rhs),
+                    Some(Ordering::Less | Ordering::Equal)
+                )
+            },
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("'{}' {}", operator, ERROR_ARRAY_FILTER_OPERATOR),
+                ));
+            }
+        };
+
+        let filtered = array
+            .value
+            .iter()
+            .filter(|literal| {
+                let matched = match literal.primitive.as_any().downcast_ref::<PrimitiveObject>() {
+                    Some(object) => match object.value.get(&key) {
+                        Some(field) => compare(field, value),
+                        None => false,
+                    },
+                    None => false,
+                };
+
+                matched != reject
+            })
+            .cloned()
+            .collect::<Vec<Literal>>();
+
+        Ok(PrimitiveArray::get_literal(&filtered, interval))
+    }
+
+    fn find_by_key(
+        array: &PrimitiveArray,
+        args: &HashMap<String, Literal>,
+        interval: Interval,
+        data: &Data,
+        usage: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let key = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_ARRAY_FIND.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        let value = match args.get("arg1") {
+            Some(res) => res,
+            None => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        // elements that are not objects or lack the key are skipped
+        let found = array.value.iter().find(|literal| {
+            match literal.primitive.as_any().downcast_ref::<PrimitiveObject>() {
+                Some(object) => object.value.get(key) == Some(value),
+                None => false,
+            }
+        });
+
+        match found {
+            Some(literal) => Ok(literal.to_owned()),
+            None => Ok(PrimitiveNull::get_literal(interval)),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // PUBLIC FUNCTIONS
 ////////////////////////////////////////////////////////////////////////////////
@@ -1269,7 +2314,8 @@ impl Primitive for PrimitiveArray {
     }
 
     fn as_bool(&self) -> bool {
-        true
+        // an empty array is falsy, consistent with is_empty() and length()
+        !self.value.is_empty()
     }
 
     fn get_value(&self) -> &dyn std::any::Any {
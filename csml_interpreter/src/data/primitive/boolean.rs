@@ -1,5 +1,6 @@
 use crate::data::error_info::ErrorInfo;
 use crate::data::position::Position;
+use crate::data::primitive::int::PrimitiveInt;
 use crate::data::primitive::object::PrimitiveObject;
 use crate::data::primitive::string::PrimitiveString;
 use crate::data::primitive::Right;
@@ -32,6 +33,10 @@ const FUNCTIONS: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_map! {
     "is_error" => (PrimitiveBoolean::is_error as PrimitiveMethod, Right::Read),
     "get_info" => (PrimitiveBoolean::get_info as PrimitiveMethod, Right::Read),
     "to_string" => (PrimitiveBoolean::to_string as PrimitiveMethod, Right::Read),
+    "to_int" => (PrimitiveBoolean::to_int as PrimitiveMethod, Right::Read),
+    "not" => (PrimitiveBoolean::not as PrimitiveMethod, Right::Read),
+    "xor" => (PrimitiveBoolean::xor as PrimitiveMethod, Right::Read),
+    "nand" => (PrimitiveBoolean::nand as PrimitiveMethod, Right::Read),
 };
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
@@ -163,6 +168,100 @@ impl PrimitiveBoolean {
 
         Ok(PrimitiveString::get_literal(&boolean.to_string(), interval))
     }
+
+    fn to_int(
+        boolean: &mut PrimitiveBoolean,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_int() => int";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        Ok(PrimitiveInt::get_literal(boolean.value as i64, interval))
+    }
+
+    fn not(
+        boolean: &mut PrimitiveBoolean,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "not() => boolean";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        Ok(PrimitiveBoolean::get_literal(!boolean.value, interval))
+    }
+
+    fn xor(
+        boolean: &mut PrimitiveBoolean,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "xor(other: boolean) => boolean";
+
+        let other = match args.get("arg0") {
+            Some(other) if args.len() == 1 => {
+                other.primitive.as_any().downcast_ref::<PrimitiveBoolean>()
+            }
+            _ => None,
+        };
+
+        match other {
+            Some(other) => Ok(PrimitiveBoolean::get_literal(
+                boolean.value ^ other.value,
+                interval,
+            )),
+            None => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            )),
+        }
+    }
+
+    fn nand(
+        boolean: &mut PrimitiveBoolean,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "nand(other: boolean) => boolean";
+
+        let other = match args.get("arg0") {
+            Some(other) if args.len() == 1 => {
+                other.primitive.as_any().downcast_ref::<PrimitiveBoolean>()
+            }
+            _ => None,
+        };
+
+        match other {
+            Some(other) => Ok(PrimitiveBoolean::get_literal(
+                !(boolean.value && other.value),
+                interval,
+            )),
+            None => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            )),
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -1,4 +1,7 @@
-use crate::data::primitive::tools::check_division_by_zero_f64;
+use crate::data::primitive::tools::{
+    check_division_by_zero_f64, clamp_number, format_fixed, get_logarithm, get_number, max_number,
+    min_number, Integer,
+};
 use crate::data::{
     ast::Interval,
     error_info::ErrorInfo,
@@ -7,13 +10,14 @@ use crate::data::{
     message::Message,
     position::Position,
     primitive::{
-        Primitive, PrimitiveBoolean, PrimitiveInt, PrimitiveObject, PrimitiveString, PrimitiveType,
-        Right,
+        tools_random, Primitive, PrimitiveBoolean, PrimitiveInt, PrimitiveObject, PrimitiveString,
+        PrimitiveType, Right,
     },
     Data, Literal, MemoryType, MessageData, MSG,
 };
 use crate::error_format::*;
 use phf::phf_map;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::{collections::HashMap, sync::mpsc};
@@ -30,6 +34,9 @@ type PrimitiveMethod = fn(
     interval: Interval,
 ) -> Result<Literal, ErrorInfo>;
 
+// upper bound for to_fixed and round_to, more decimals than that are only noise
+const MAX_DECIMALS: i64 = 100;
+
 const FUNCTIONS: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_map! {
     "is_number" => (PrimitiveFloat::is_number as PrimitiveMethod, Right::Read),
     "is_int" => (PrimitiveFloat::is_int as PrimitiveMethod, Right::Read),
@@ -40,6 +47,8 @@ const FUNCTIONS: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_map! {
     "to_string" => (PrimitiveFloat::to_string as PrimitiveMethod, Right::Read),
 
     "precision" => (PrimitiveFloat::precision as PrimitiveMethod, Right::Read),
+    "to_fixed" => (PrimitiveFloat::to_fixed as PrimitiveMethod, Right::Read),
+    "round_to" => (PrimitiveFloat::round_to as PrimitiveMethod, Right::Read),
     "abs" => (PrimitiveFloat::abs as PrimitiveMethod, Right::Read),
     "cos" => (PrimitiveFloat::cos as PrimitiveMethod, Right::Read),
     "ceil" => (PrimitiveFloat::ceil as PrimitiveMethod, Right::Read),
@@ -49,8 +58,25 @@ const FUNCTIONS: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_map! {
     "sin" => (PrimitiveFloat::sin as PrimitiveMethod, Right::Read),
     "sqrt" => (PrimitiveFloat::sqrt as PrimitiveMethod, Right::Read),
     "tan" => (PrimitiveFloat::tan as PrimitiveMethod, Right::Read),
+    "asin" => (PrimitiveFloat::asin as PrimitiveMethod, Right::Read),
+    "acos" => (PrimitiveFloat::acos as PrimitiveMethod, Right::Read),
+    "atan2" => (PrimitiveFloat::atan2 as PrimitiveMethod, Right::Read),
+    "to_radians" => (PrimitiveFloat::to_radians as PrimitiveMethod, Right::Read),
+    "to_degrees" => (PrimitiveFloat::to_degrees as PrimitiveMethod, Right::Read),
+    "sign" => (PrimitiveFloat::sign as PrimitiveMethod, Right::Read),
     "to_int" => (PrimitiveFloat::to_int as PrimitiveMethod, Right::Read),
     "to_float" => (PrimitiveFloat::to_float as PrimitiveMethod, Right::Read),
+    "min" => (PrimitiveFloat::min as PrimitiveMethod, Right::Read),
+    "max" => (PrimitiveFloat::max as PrimitiveMethod, Right::Read),
+    "clamp" => (PrimitiveFloat::clamp as PrimitiveMethod, Right::Read),
+    "ln" => (PrimitiveFloat::ln as PrimitiveMethod, Right::Read),
+    "log" => (PrimitiveFloat::log as PrimitiveMethod, Right::Read),
+    "log10" => (PrimitiveFloat::log10 as PrimitiveMethod, Right::Read),
+    "log2" => (PrimitiveFloat::log2 as PrimitiveMethod, Right::Read),
+    "exp" => (PrimitiveFloat::exp as PrimitiveMethod, Right::Read),
+    "rand_between" => (PrimitiveFloat::rand_between as PrimitiveMethod, Right::Read),
+    "is_even" => (PrimitiveFloat::is_even as PrimitiveMethod, Right::Read),
+    "is_odd" => (PrimitiveFloat::is_odd as PrimitiveMethod, Right::Read),
 };
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct PrimitiveFloat {
@@ -280,6 +306,74 @@ impl PrimitiveFloat {
         Ok(PrimitiveFloat::get_literal(result, interval))
     }
 
+    fn to_fixed(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_fixed(decimals: int) => string";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let decimals = match args.get("arg0").and_then(get_number) {
+            Some(Integer::Int(decimals)) if (0..=MAX_DECIMALS).contains(&decimals) => {
+                decimals as usize
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("[to_fixed] {}", ERROR_FLOAT_DECIMALS),
+                ));
+            }
+        };
+
+        let result = format_fixed(float.value, decimals);
+
+        Ok(PrimitiveString::get_literal(&result, interval))
+    }
+
+    fn round_to(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "round_to(decimals: int) => float";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let decimals = match args.get("arg0").and_then(get_number) {
+            Some(Integer::Int(decimals)) if (0..=MAX_DECIMALS).contains(&decimals) => {
+                decimals as usize
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("[round_to] {}", ERROR_FLOAT_DECIMALS),
+                ));
+            }
+        };
+
+        let result = format_fixed(float.value, decimals)
+            .parse::<f64>()
+            .unwrap_or(float.value);
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
+
     fn floor(
         float: &mut PrimitiveFloat,
         args: &HashMap<String, Literal>,
@@ -449,6 +543,167 @@ impl PrimitiveFloat {
         Ok(PrimitiveFloat::get_literal(result, interval))
     }
 
+    fn to_radians(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_radians() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let result = float.value.to_radians();
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
+
+    fn to_degrees(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_degrees() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let result = float.value.to_degrees();
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
+
+    fn sign(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "sign() => int";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        // f64::signum gives 1 for 0.0, zero (and NaN) must give 0 here
+        let result = match float.value.partial_cmp(&0.0) {
+            Some(Ordering::Greater) => 1,
+            Some(Ordering::Less) => -1,
+            _ => 0,
+        };
+
+        Ok(PrimitiveInt::get_literal(result, interval))
+    }
+
+    fn asin(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "asin() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        if !(-1.0..=1.0).contains(&float.value) {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!(
+                    "[asin] {} (value: {})",
+                    ERROR_NUMBER_TRIG_DOMAIN, float.value
+                ),
+            ));
+        }
+
+        let result = float.value.asin();
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
+
+    fn acos(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "acos() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        if !(-1.0..=1.0).contains(&float.value) {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!(
+                    "[acos] {} (value: {})",
+                    ERROR_NUMBER_TRIG_DOMAIN, float.value
+                ),
+            ));
+        }
+
+        let result = float.value.acos();
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
+
+    fn atan2(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "atan2(x: number) => float";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let x = match args.get("arg0").and_then(get_number) {
+            Some(x) => x.as_f64(),
+            None => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        let result = float.value.atan2(x);
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
+
     fn to_int(
         float: &mut PrimitiveFloat,
         args: &HashMap<String, Literal>,
@@ -486,6 +741,274 @@ impl PrimitiveFloat {
 
         Ok(PrimitiveFloat::get_literal(float.value, interval))
     }
+
+    fn min(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "min(other: number) => number";
+
+        match args.get("arg0").and_then(get_number) {
+            Some(other) if args.len() == 1 => {
+                Ok(min_number(Integer::Float(float.value), other).get_literal(interval))
+            }
+            _ => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            )),
+        }
+    }
+
+    fn max(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "max(other: number) => number";
+
+        match args.get("arg0").and_then(get_number) {
+            Some(other) if args.len() == 1 => {
+                Ok(max_number(Integer::Float(float.value), other).get_literal(interval))
+            }
+            _ => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            )),
+        }
+    }
+
+    fn clamp(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "clamp(low: number, high: number) => number";
+
+        let (low, high) = match (
+            args.get("arg0").and_then(get_number),
+            args.get("arg1").and_then(get_number),
+        ) {
+            (Some(low), Some(high)) if args.len() == 2 => (low, high),
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        match clamp_number(Integer::Float(float.value), low, high) {
+            Ok(result) => Ok(result.get_literal(interval)),
+            Err(err) => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                err,
+            )),
+        }
+    }
+
+    fn ln(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "ln() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        get_logarithm(
+            float.value,
+            std::f64::consts::E,
+            &data.context.flow,
+            interval,
+        )
+    }
+
+    fn log(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "log(base: number) => float";
+
+        let base = match args.get("arg0").and_then(get_number) {
+            Some(base) if args.len() == 1 => base.as_f64(),
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        get_logarithm(float.value, base, &data.context.flow, interval)
+    }
+
+    fn log10(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "log10() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        get_logarithm(float.value, 10.0, &data.context.flow, interval)
+    }
+
+    fn log2(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "log2() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        get_logarithm(float.value, 2.0, &data.context.flow, interval)
+    }
+
+    fn exp(
+        float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "exp() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let result = (float.value).exp();
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
+
+    fn rand_between(
+        _float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage =
+            "rand_between(min: number, max: number) => float, min included and max excluded";
+
+        let (min, max) = match (
+            args.get("arg0").and_then(get_number),
+            args.get("arg1").and_then(get_number),
+        ) {
+            (Some(min), Some(max)) if args.len() == 2 => (min.as_f64(), max.as_f64()),
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        if !min.is_finite() || !max.is_finite() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!(
+                    "{} (min: {}, max: {})",
+                    ERROR_NUMBER_RAND_BETWEEN_FINITE, min, max
+                ),
+            ));
+        }
+
+        if min > max {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("{} (min: {}, max: {})", ERROR_NUMBER_RAND_BETWEEN, min, max),
+            ));
+        }
+
+        let result = match min == max {
+            true => min,
+            false => tools_random::with_rng(|rng| rng.gen_range(min..max)),
+        };
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
+
+    fn is_even(
+        _float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "is_even() => boolean";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        Err(gen_error_info(
+            Position::new(interval, &data.context.flow),
+            format!("[is_even] {}", ERROR_FLOAT_PARITY),
+        ))
+    }
+
+    fn is_odd(
+        _float: &mut PrimitiveFloat,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "is_odd() => boolean";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        Err(gen_error_info(
+            Position::new(interval, &data.context.flow),
+            format!("[is_odd] {}", ERROR_FLOAT_PARITY),
+        ))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -673,7 +1196,8 @@ impl Primitive for PrimitiveFloat {
     }
 
     fn as_bool(&self) -> bool {
-        self.value.is_normal()
+        // same rule as ints: only zero is falsy, NaN is never truthy
+        self.value != 0.0 && !self.value.is_nan()
     }
 
     fn get_value(&self) -> &dyn std::any::Any {
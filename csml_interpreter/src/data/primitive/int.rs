@@ -4,15 +4,20 @@ use crate::data::primitive::boolean::PrimitiveBoolean;
 use crate::data::primitive::float::PrimitiveFloat;
 use crate::data::primitive::object::PrimitiveObject;
 use crate::data::primitive::string::PrimitiveString;
-use crate::data::primitive::tools::check_division_by_zero_i64;
-use crate::data::primitive::Right;
+use crate::data::primitive::tools::{
+    check_division_by_zero_i64, clamp_number, format_radix, get_integer, get_logarithm, get_number,
+    max_number, min_number, Integer,
+};
+use crate::data::primitive::{tools_random, Right};
 use crate::data::primitive::{Primitive, PrimitiveType};
 use crate::data::{ast::Interval, message::Message, Data, Literal, MemoryType, MessageData, MSG};
 use crate::data::{literal, literal::ContentType};
 use crate::error_format::*;
 use phf::phf_map;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::{collections::HashMap, sync::mpsc};
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -46,8 +51,32 @@ const FUNCTIONS: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_map! {
     "sin" => (PrimitiveInt::sin as PrimitiveMethod, Right::Read),
     "sqrt" => (PrimitiveInt::sqrt as PrimitiveMethod, Right::Read),
     "tan" => (PrimitiveInt::tan as PrimitiveMethod, Right::Read),
+    "to_radians" => (PrimitiveInt::to_radians as PrimitiveMethod, Right::Read),
+    "to_degrees" => (PrimitiveInt::to_degrees as PrimitiveMethod, Right::Read),
     "to_int" => (PrimitiveInt::to_int as PrimitiveMethod, Right::Read),
     "to_float" => (PrimitiveInt::to_float as PrimitiveMethod, Right::Read),
+    "min" => (PrimitiveInt::min as PrimitiveMethod, Right::Read),
+    "max" => (PrimitiveInt::max as PrimitiveMethod, Right::Read),
+    "clamp" => (PrimitiveInt::clamp as PrimitiveMethod, Right::Read),
+    "ln" => (PrimitiveInt::ln as PrimitiveMethod, Right::Read),
+    "log" => (PrimitiveInt::log as PrimitiveMethod, Right::Read),
+    "log10" => (PrimitiveInt::log10 as PrimitiveMethod, Right::Read),
+    "log2" => (PrimitiveInt::log2 as PrimitiveMethod, Right::Read),
+    "exp" => (PrimitiveInt::exp as PrimitiveMethod, Right::Read),
+    "rand_between" => (PrimitiveInt::rand_between as PrimitiveMethod, Right::Read),
+    "to_hex" => (PrimitiveInt::to_hex as PrimitiveMethod, Right::Read),
+    "to_binary" => (PrimitiveInt::to_binary as PrimitiveMethod, Right::Read),
+    "to_base" => (PrimitiveInt::to_base as PrimitiveMethod, Right::Read),
+    "is_even" => (PrimitiveInt::is_even as PrimitiveMethod, Right::Read),
+    "is_odd" => (PrimitiveInt::is_odd as PrimitiveMethod, Right::Read),
+    "bitand" => (PrimitiveInt::bitand as PrimitiveMethod, Right::Read),
+    "bitor" => (PrimitiveInt::bitor as PrimitiveMethod, Right::Read),
+    "bitxor" => (PrimitiveInt::bitxor as PrimitiveMethod, Right::Read),
+    "bitnot" => (PrimitiveInt::bitnot as PrimitiveMethod, Right::Read),
+    "shl" => (PrimitiveInt::shl as PrimitiveMethod, Right::Read),
+    "shr" => (PrimitiveInt::shr as PrimitiveMethod, Right::Read),
+    "sign" => (PrimitiveInt::sign as PrimitiveMethod, Right::Read),
+    "to_boolean" => (PrimitiveInt::to_boolean as PrimitiveMethod, Right::Read),
 };
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct PrimitiveInt {
@@ -197,12 +226,13 @@ impl PrimitiveInt {
             ));
         }
 
-        let float = int.value as f64;
-
-        let result = float.abs();
-        let result = result as i64;
-
-        Ok(PrimitiveInt::get_literal(result, interval))
+        match int.value.checked_abs() {
+            Some(result) => Ok(PrimitiveInt::get_literal(result, interval)),
+            None => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("{} {}.abs()", OVERFLOWING_OPERATION, int.value),
+            )),
+        }
     }
 
     fn cos(
@@ -212,7 +242,7 @@ impl PrimitiveInt {
         data: &mut Data,
         interval: Interval,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "cos() => number";
+        let usage = "cos() => float";
 
         if !args.is_empty() {
             return Err(gen_error_info(
@@ -221,14 +251,9 @@ impl PrimitiveInt {
             ));
         }
 
-        let float = int.value as f64;
+        let result = (int.value as f64).cos();
 
-        let result = float.cos();
-
-        match result == (result as i64) as f64 {
-            true => Ok(PrimitiveInt::get_literal(result as i64, interval)),
-            false => Ok(PrimitiveFloat::get_literal(result, interval)),
-        }
+        Ok(PrimitiveFloat::get_literal(result, interval))
     }
 
     fn ceil(
@@ -247,12 +272,7 @@ impl PrimitiveInt {
             ));
         }
 
-        let float = int.value as f64;
-
-        let result = float.ceil();
-        let result = result as i64;
-
-        Ok(PrimitiveInt::get_literal(result, interval))
+        Ok(PrimitiveInt::get_literal(int.value, interval))
     }
 
     fn precision(
@@ -281,12 +301,7 @@ impl PrimitiveInt {
             ));
         }
 
-        let float = int.value as f64;
-
-        let result = float.floor();
-        let result = result as i64;
-
-        Ok(PrimitiveInt::get_literal(result, interval))
+        Ok(PrimitiveInt::get_literal(int.value, interval))
     }
 
     fn pow(
@@ -305,25 +320,7 @@ impl PrimitiveInt {
             ));
         }
 
-        let float = int.value as f64;
-
         let exponent = match args.get("arg0") {
-            Some(exponent) if exponent.primitive.get_type() == PrimitiveType::PrimitiveInt => {
-                *Literal::get_value::<i64>(
-                    &exponent.primitive,
-                    &data.context.flow,
-                    interval,
-                    ERROR_NUMBER_POW.to_owned(),
-                )? as f64
-            }
-            Some(exponent) if exponent.primitive.get_type() == PrimitiveType::PrimitiveFloat => {
-                *Literal::get_value::<f64>(
-                    &exponent.primitive,
-                    &data.context.flow,
-                    interval,
-                    ERROR_NUMBER_POW.to_owned(),
-                )?
-            }
             Some(exponent) if exponent.primitive.get_type() == PrimitiveType::PrimitiveString => {
                 let exponent = Literal::get_value::<String>(
                     &exponent.primitive,
@@ -332,40 +329,370 @@ impl PrimitiveInt {
                     ERROR_NUMBER_POW.to_owned(),
                 )?;
 
-                match exponent.parse::<f64>() {
-                    Ok(res) => res,
-                    Err(_) => {
-                        return Err(gen_error_info(
-                            Position::new(interval, &data.context.flow),
-                            ERROR_NUMBER_POW.to_owned(),
-                        ));
-                    }
+                get_integer(exponent.trim()).ok()
+            }
+            Some(exponent) => get_number(exponent),
+            None => None,
+        };
+
+        match exponent {
+            // exact int power, going through f64 loses precision above 2^53
+            Some(Integer::Int(exponent)) if exponent >= 0 => {
+                // |x| <= 1 never overflows, only the parity of the exponent matters
+                let reduced = match int.value.unsigned_abs() <= 1 && exponent > 2 {
+                    true => 2 - exponent % 2,
+                    false => exponent,
+                };
+
+                match u32::try_from(reduced)
+                    .ok()
+                    .and_then(|exp| int.value.checked_pow(exp))
+                {
+                    Some(result) => Ok(PrimitiveInt::get_literal(result, interval)),
+                    None => Err(gen_error_info(
+                        Position::new(interval, &data.context.flow),
+                        format!("{} {}.pow({})", OVERFLOWING_OPERATION, int.value, exponent),
+                    )),
                 }
             }
+            // negative or fractional exponents always give a float
+            Some(exponent) => {
+                let result = (int.value as f64).powf(exponent.as_f64());
+
+                Ok(PrimitiveFloat::get_literal(result, interval))
+            }
+            None => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                ERROR_NUMBER_POW.to_owned(),
+            )),
+        }
+    }
+
+    fn round(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "round() => int";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        Ok(PrimitiveInt::get_literal(int.value, interval))
+    }
+
+    fn sin(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "sin() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let result = (int.value as f64).sin();
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
+
+    fn sqrt(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "sqrt() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let result = (int.value as f64).sqrt();
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
+
+    fn tan(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "tan() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let result = (int.value as f64).tan();
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
+
+    fn to_radians(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_radians() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let result = (int.value as f64).to_radians();
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
+
+    fn to_degrees(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_degrees() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let result = (int.value as f64).to_degrees();
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
+
+    fn to_int(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_int() => int";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        Ok(PrimitiveInt::get_literal(int.value, interval))
+    }
+
+    fn to_float(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_float() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        Ok(PrimitiveFloat::get_literal(int.value as f64, interval))
+    }
+
+    fn min(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "min(other: number) => number";
+
+        match args.get("arg0").and_then(get_number) {
+            Some(other) if args.len() == 1 => {
+                Ok(min_number(Integer::Int(int.value), other).get_literal(interval))
+            }
+            _ => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            )),
+        }
+    }
+
+    fn max(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "max(other: number) => number";
+
+        match args.get("arg0").and_then(get_number) {
+            Some(other) if args.len() == 1 => {
+                Ok(max_number(Integer::Int(int.value), other).get_literal(interval))
+            }
+            _ => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            )),
+        }
+    }
+
+    fn clamp(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "clamp(low: number, high: number) => number";
+
+        let (low, high) = match (
+            args.get("arg0").and_then(get_number),
+            args.get("arg1").and_then(get_number),
+        ) {
+            (Some(low), Some(high)) if args.len() == 2 => (low, high),
             _ => {
                 return Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
-                    ERROR_NUMBER_POW.to_owned(),
+                    format!("usage: {}", usage),
                 ));
             }
         };
 
-        let result = float.powf(exponent);
+        match clamp_number(Integer::Int(int.value), low, high) {
+            Ok(result) => Ok(result.get_literal(interval)),
+            Err(err) => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                err,
+            )),
+        }
+    }
 
-        match result == (result as i64) as f64 {
-            true => Ok(PrimitiveInt::get_literal(result as i64, interval)),
-            false => Ok(PrimitiveFloat::get_literal(result, interval)),
+    fn ln(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "ln() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
         }
+
+        get_logarithm(
+            int.value as f64,
+            std::f64::consts::E,
+            &data.context.flow,
+            interval,
+        )
     }
 
-    fn round(
+    fn log(
         int: &mut PrimitiveInt,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
         data: &mut Data,
         interval: Interval,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "round() => int";
+        let usage = "log(base: number) => float";
+
+        let base = match args.get("arg0").and_then(get_number) {
+            Some(base) if args.len() == 1 => base.as_f64(),
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        get_logarithm(int.value as f64, base, &data.context.flow, interval)
+    }
+
+    fn log10(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "log10() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        get_logarithm(int.value as f64, 10.0, &data.context.flow, interval)
+    }
+
+    fn log2(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "log2() => float";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        get_logarithm(int.value as f64, 2.0, &data.context.flow, interval)
+    }
+
+    fn exp(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "exp() => float";
 
         if !args.is_empty() {
             return Err(gen_error_info(
@@ -374,22 +701,53 @@ impl PrimitiveInt {
             ));
         }
 
-        let float = int.value as f64;
+        let result = (int.value as f64).exp();
+
+        Ok(PrimitiveFloat::get_literal(result, interval))
+    }
 
-        let result = float.round();
-        let result = result as i64;
+    fn rand_between(
+        _int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "rand_between(min: int, max: int) => int, min and max included";
+
+        let (min, max) = match (
+            args.get("arg0").and_then(get_number),
+            args.get("arg1").and_then(get_number),
+        ) {
+            (Some(Integer::Int(min)), Some(Integer::Int(max))) if args.len() == 2 => (min, max),
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        if min > max {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("{} (min: {}, max: {})", ERROR_NUMBER_RAND_BETWEEN, min, max),
+            ));
+        }
+
+        let result = tools_random::with_rng(|rng| rng.gen_range(min..=max));
 
         Ok(PrimitiveInt::get_literal(result, interval))
     }
 
-    fn sin(
+    fn to_hex(
         int: &mut PrimitiveInt,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
         data: &mut Data,
         interval: Interval,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "sin() => number";
+        let usage = "to_hex() => string";
 
         if !args.is_empty() {
             return Err(gen_error_info(
@@ -398,24 +756,90 @@ impl PrimitiveInt {
             ));
         }
 
-        let float = int.value as f64;
+        let result = format_radix(int.value, 16);
 
-        let result = float.sin();
+        Ok(PrimitiveString::get_literal(&result, interval))
+    }
 
-        match result == (result as i64) as f64 {
-            true => Ok(PrimitiveInt::get_literal(result as i64, interval)),
-            false => Ok(PrimitiveFloat::get_literal(result, interval)),
+    fn to_binary(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_binary() => string";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
         }
+
+        let result = format_radix(int.value, 2);
+
+        Ok(PrimitiveString::get_literal(&result, interval))
     }
 
-    fn sqrt(
+    fn to_base(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_base(radix: int) => string";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let radix = match args.get("arg0").and_then(get_number) {
+            Some(Integer::Int(radix)) if (2..=36).contains(&radix) => radix as u32,
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_INT_TO_BASE.to_owned(),
+                ));
+            }
+        };
+
+        let result = format_radix(int.value, radix);
+
+        Ok(PrimitiveString::get_literal(&result, interval))
+    }
+
+    fn is_even(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "is_even() => boolean";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        Ok(PrimitiveBoolean::get_literal(int.value % 2 == 0, interval))
+    }
+
+    fn is_odd(
         int: &mut PrimitiveInt,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
         data: &mut Data,
         interval: Interval,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "round() => number";
+        let usage = "is_odd() => boolean";
 
         if !args.is_empty() {
             return Err(gen_error_info(
@@ -424,24 +848,80 @@ impl PrimitiveInt {
             ));
         }
 
-        let float = int.value as f64;
+        Ok(PrimitiveBoolean::get_literal(int.value % 2 != 0, interval))
+    }
 
-        let result = float.sqrt();
+    fn bitand(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "bitand(other: int) => int";
 
-        match result == (result as i64) as f64 {
-            true => Ok(PrimitiveInt::get_literal(result as i64, interval)),
-            false => Ok(PrimitiveFloat::get_literal(result, interval)),
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
         }
+
+        let other = get_int_arg(args, "bitand", ERROR_INT_BITWISE, data, interval)?;
+
+        Ok(PrimitiveInt::get_literal(int.value & other, interval))
     }
 
-    fn tan(
+    fn bitor(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "bitor(other: int) => int";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let other = get_int_arg(args, "bitor", ERROR_INT_BITWISE, data, interval)?;
+
+        Ok(PrimitiveInt::get_literal(int.value | other, interval))
+    }
+
+    fn bitxor(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "bitxor(other: int) => int";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let other = get_int_arg(args, "bitxor", ERROR_INT_BITWISE, data, interval)?;
+
+        Ok(PrimitiveInt::get_literal(int.value ^ other, interval))
+    }
+
+    fn bitnot(
         int: &mut PrimitiveInt,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
         data: &mut Data,
         interval: Interval,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "tan() => number";
+        let usage = "bitnot() => int";
 
         if !args.is_empty() {
             return Err(gen_error_info(
@@ -450,24 +930,79 @@ impl PrimitiveInt {
             ));
         }
 
-        let float = int.value as f64;
+        Ok(PrimitiveInt::get_literal(!int.value, interval))
+    }
 
-        let result = float.tan();
+    fn shl(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "shl(bits: int) => int";
 
-        match result == (result as i64) as f64 {
-            true => Ok(PrimitiveInt::get_literal(result as i64, interval)),
-            false => Ok(PrimitiveFloat::get_literal(result, interval)),
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let bits = get_int_arg(args, "shl", ERROR_INT_SHIFT, data, interval)?;
+
+        // bits shifted past the sign bit are dropped, like any bit operation
+        match u32::try_from(bits)
+            .ok()
+            .and_then(|bits| int.value.checked_shl(bits))
+        {
+            Some(result) => Ok(PrimitiveInt::get_literal(result, interval)),
+            None => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("[shl] {} (bits: {})", ERROR_INT_SHIFT, bits),
+            )),
         }
     }
 
-    fn to_int(
+    fn shr(
         int: &mut PrimitiveInt,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
         data: &mut Data,
         interval: Interval,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "to_int() => int";
+        let usage = "shr(bits: int) => int";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let bits = get_int_arg(args, "shr", ERROR_INT_SHIFT, data, interval)?;
+
+        // arithmetic shift, negative values keep their sign: -8.shr(1) is -4
+        match u32::try_from(bits)
+            .ok()
+            .and_then(|bits| int.value.checked_shr(bits))
+        {
+            Some(result) => Ok(PrimitiveInt::get_literal(result, interval)),
+            None => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("[shr] {} (bits: {})", ERROR_INT_SHIFT, bits),
+            )),
+        }
+    }
+
+    fn sign(
+        int: &mut PrimitiveInt,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "sign() => int";
 
         if !args.is_empty() {
             return Err(gen_error_info(
@@ -476,17 +1011,17 @@ impl PrimitiveInt {
             ));
         }
 
-        Ok(PrimitiveInt::get_literal(int.value, interval))
+        Ok(PrimitiveInt::get_literal(int.value.signum(), interval))
     }
 
-    fn to_float(
+    fn to_boolean(
         int: &mut PrimitiveInt,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
         data: &mut Data,
         interval: Interval,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "to_float() => float";
+        let usage = "to_boolean() => boolean";
 
         if !args.is_empty() {
             return Err(gen_error_info(
@@ -495,7 +1030,7 @@ impl PrimitiveInt {
             ));
         }
 
-        Ok(PrimitiveFloat::get_literal(int.value as f64, interval))
+        Ok(PrimitiveBoolean::get_literal(int.value != 0, interval))
     }
 }
 
@@ -544,105 +1079,105 @@ impl Primitive for PrimitiveInt {
     }
 
     fn do_add(&self, other: &dyn Primitive) -> Result<Box<dyn Primitive>, String> {
-        let mut error_msg = ERROR_ILLEGAL_OPERATION;
-
         if let Some(other) = other.as_any().downcast_ref::<Self>() {
-            if let Some(value) = self.value.checked_add(other.value) {
-                return Ok(Box::new(PrimitiveInt::new(value)));
-            }
-
-            error_msg = OVERFLOWING_OPERATION;
+            return match self.value.checked_add(other.value) {
+                Some(value) => Ok(Box::new(PrimitiveInt::new(value))),
+                None => Err(format!(
+                    "{} {} + {}",
+                    OVERFLOWING_OPERATION, self.value, other.value
+                )),
+            };
         }
 
         Err(format!(
             "{} {:?} + {:?}",
-            error_msg,
+            ERROR_ILLEGAL_OPERATION,
             self.get_type(),
             other.get_type()
         ))
     }
 
     fn do_sub(&self, other: &dyn Primitive) -> Result<Box<dyn Primitive>, String> {
-        let mut error_msg = ERROR_ILLEGAL_OPERATION;
-
         if let Some(other) = other.as_any().downcast_ref::<Self>() {
-            if let Some(value) = self.value.checked_sub(other.value) {
-                return Ok(Box::new(PrimitiveInt::new(value)));
-            }
-
-            error_msg = OVERFLOWING_OPERATION
+            return match self.value.checked_sub(other.value) {
+                Some(value) => Ok(Box::new(PrimitiveInt::new(value))),
+                None => Err(format!(
+                    "{} {} - {}",
+                    OVERFLOWING_OPERATION, self.value, other.value
+                )),
+            };
         }
 
         Err(format!(
             "{} {:?} - {:?}",
-            error_msg,
+            ERROR_ILLEGAL_OPERATION,
             self.get_type(),
             other.get_type()
         ))
     }
 
     fn do_div(&self, other: &dyn Primitive) -> Result<Box<dyn Primitive>, String> {
-        let mut error_msg = ERROR_ILLEGAL_OPERATION;
-
         if let Some(other) = other.as_any().downcast_ref::<Self>() {
             check_division_by_zero_i64(self.value, other.value)?;
 
-            if self.value % other.value != 0 {
-                if self.value.checked_div(other.value).is_some() {
+            return match (
+                self.value.checked_div(other.value),
+                self.value.checked_rem(other.value),
+            ) {
+                (Some(value), Some(0)) => Ok(Box::new(PrimitiveInt::new(value))),
+                (Some(_), Some(_)) => {
                     let value = self.value as f64 / other.value as f64;
 
-                    return Ok(Box::new(PrimitiveFloat::new(value)));
-                }
-            } else {
-                if let Some(value) = self.value.checked_div(other.value) {
-                    return Ok(Box::new(PrimitiveInt::new(value)));
+                    Ok(Box::new(PrimitiveFloat::new(value)))
                 }
-
-                error_msg = OVERFLOWING_OPERATION;
-            }
+                _ => Err(format!(
+                    "{} {} / {}",
+                    OVERFLOWING_OPERATION, self.value, other.value
+                )),
+            };
         }
 
         Err(format!(
             "{} {:?} / {:?}",
-            error_msg,
+            ERROR_ILLEGAL_OPERATION,
             self.get_type(),
             other.get_type()
         ))
     }
 
     fn do_mul(&self, other: &dyn Primitive) -> Result<Box<dyn Primitive>, String> {
-        let mut error_msg = ERROR_ILLEGAL_OPERATION;
-
         if let Some(other) = other.as_any().downcast_ref::<Self>() {
-            if let Some(value) = self.value.checked_mul(other.value) {
-                return Ok(Box::new(PrimitiveInt::new(value)));
-            }
-
-            error_msg = OVERFLOWING_OPERATION;
+            return match self.value.checked_mul(other.value) {
+                Some(value) => Ok(Box::new(PrimitiveInt::new(value))),
+                None => Err(format!(
+                    "{} {} * {}",
+                    OVERFLOWING_OPERATION, self.value, other.value
+                )),
+            };
         }
 
         Err(format!(
             "{} {:?} * {:?}",
-            error_msg,
+            ERROR_ILLEGAL_OPERATION,
             self.get_type(),
             other.get_type()
         ))
     }
 
     fn do_rem(&self, other: &dyn Primitive) -> Result<Box<dyn Primitive>, String> {
-        let mut error_msg = ERROR_ILLEGAL_OPERATION;
-
         if let Some(other) = other.as_any().downcast_ref::<Self>() {
-            if let Some(value) = self.value.checked_rem(other.value) {
-                return Ok(Box::new(PrimitiveInt::new(value)));
-            }
-
-            error_msg = OVERFLOWING_OPERATION;
+            return match self.value.checked_rem(other.value) {
+                Some(value) => Ok(Box::new(PrimitiveInt::new(value))),
+                None => Err(format!(
+                    "{} {} % {}",
+                    OVERFLOWING_OPERATION, self.value, other.value
+                )),
+            };
         }
 
         Err(format!(
             "{} {:?} % {:?}",
-            error_msg,
+            ERROR_ILLEGAL_OPERATION,
             self.get_type(),
             other.get_type()
         ))
@@ -677,7 +1212,7 @@ impl Primitive for PrimitiveInt {
     }
 
     fn as_bool(&self) -> bool {
-        self.value.is_positive()
+        self.value != 0
     }
 
     fn get_value(&self) -> &dyn std::any::Any {
@@ -757,3 +1292,23 @@ impl Primitive for PrimitiveInt {
         ))
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// PRIVATE FUNCTION
+////////////////////////////////////////////////////////////////////////////////
+
+fn get_int_arg(
+    args: &HashMap<String, Literal>,
+    name: &str,
+    error: &str,
+    data: &Data,
+    interval: Interval,
+) -> Result<i64, ErrorInfo> {
+    match args.get("arg0").and_then(get_number) {
+        Some(Integer::Int(value)) => Ok(value),
+        _ => Err(gen_error_info(
+            Position::new(interval, &data.context.flow),
+            format!("[{}] {}", name, error),
+        )),
+    }
+}
@@ -7,8 +7,8 @@ use crate::data::{
     literal::ContentType,
     message::Message,
     primitive::{
-        tools_crypto, tools_jwt, tools_smtp, tools_time, Data, MessageData, Primitive,
-        PrimitiveArray, PrimitiveBoolean, PrimitiveInt, PrimitiveNull, PrimitiveString,
+        tools::get_length, tools_crypto, tools_jwt, tools_smtp, tools_time, Data, MessageData,
+        Primitive, PrimitiveArray, PrimitiveBoolean, PrimitiveInt, PrimitiveNull, PrimitiveString,
         PrimitiveType, Right, MSG,
     },
     tokens::TYPES,
@@ -16,7 +16,8 @@ use crate::data::{
 };
 use crate::error_format::*;
 use crate::interpreter::{
-    builtins::http_builtin::http_request, json_to_rust::json_to_literal,
+    builtins::http_builtin::{get_timeout_duration, http_fetch, http_request},
+    json_to_rust::json_to_literal,
     variable_handler::match_literals::match_obj,
 };
 use std::cmp::Ordering;
@@ -43,7 +44,9 @@ const FUNCTIONS_HTTP: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_map
     "put" => (PrimitiveObject::put as PrimitiveMethod, Right::Read),
     "delete" => (PrimitiveObject::delete as PrimitiveMethod, Right::Read),
     "patch" => (PrimitiveObject::patch as PrimitiveMethod, Right::Read),
+    "timeout" => (PrimitiveObject::timeout as PrimitiveMethod, Right::Read),
     "send" => (PrimitiveObject::send as PrimitiveMethod, Right::Read),
+    "fetch" => (PrimitiveObject::fetch as PrimitiveMethod, Right::Read),
 };
 
 const FUNCTIONS_SMTP: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_map! {
@@ -75,11 +78,16 @@ const FUNCTIONS_CRYPTO: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_m
     "create_hmac" => (PrimitiveObject::create_hmac as PrimitiveMethod, Right::Read),
     "create_hash" => (PrimitiveObject::create_hash as PrimitiveMethod, Right::Read),
     "digest" => (PrimitiveObject::digest as PrimitiveMethod, Right::Read),
+    "hmac_sha256" => (PrimitiveObject::hmac_sha256 as PrimitiveMethod, Right::Read),
+    "verify_hmac" => (PrimitiveObject::verify_hmac as PrimitiveMethod, Right::Read),
+    "sha256" => (PrimitiveObject::crypto_sha256 as PrimitiveMethod, Right::Read),
+    "md5" => (PrimitiveObject::crypto_md5 as PrimitiveMethod, Right::Read),
 };
 
 const FUNCTIONS_BASE64: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_map! {
     "encode" => (PrimitiveObject::base64_encode as PrimitiveMethod, Right::Read),
     "decode" => (PrimitiveObject::base64_decode as PrimitiveMethod, Right::Read),
+    "decode_json" => (PrimitiveObject::base64_decode_json as PrimitiveMethod, Right::Read),
 };
 
 const FUNCTIONS_HEX: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_map! {
@@ -108,11 +116,23 @@ const FUNCTIONS_READ: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_map
     "to_string" => (PrimitiveObject::to_string as PrimitiveMethod, Right::Read),
 
     "contains" => (PrimitiveObject::contains as PrimitiveMethod, Right::Read),
+    "has_key" => (PrimitiveObject::contains as PrimitiveMethod, Right::Read),
+    "has_path" => (PrimitiveObject::has_path as PrimitiveMethod, Right::Read),
     "is_empty" => (PrimitiveObject::is_empty as PrimitiveMethod, Right::Read),
     "length" => (PrimitiveObject::length as PrimitiveMethod, Right::Read),
+    "size" => (PrimitiveObject::length as PrimitiveMethod, Right::Read),
     "keys" => (PrimitiveObject::keys as PrimitiveMethod, Right::Read),
     "values" => (PrimitiveObject::values as PrimitiveMethod, Right::Read),
+    "entries" => (PrimitiveObject::entries as PrimitiveMethod, Right::Read),
+    "to_array" => (PrimitiveObject::to_array as PrimitiveMethod, Right::Read),
+    "clone" => (PrimitiveObject::clone as PrimitiveMethod, Right::Read),
+    "flatten_keys" => (PrimitiveObject::flatten_keys as PrimitiveMethod, Right::Read),
+    "unflatten" => (PrimitiveObject::unflatten as PrimitiveMethod, Right::Read),
+    "query" => (PrimitiveObject::query_path as PrimitiveMethod, Right::Read),
     "get" => (PrimitiveObject::get_generics as PrimitiveMethod, Right::Read),
+    "get_or" => (PrimitiveObject::get_or as PrimitiveMethod, Right::Read),
+    "merge" => (PrimitiveObject::merge as PrimitiveMethod, Right::Read),
+    "deep_merge" => (PrimitiveObject::deep_merge as PrimitiveMethod, Right::Read),
 
 };
 
@@ -121,6 +141,7 @@ const FUNCTIONS_WRITE: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_ma
     "insert" => (PrimitiveObject::insert as PrimitiveMethod, Right::Write),
     "assign" => (PrimitiveObject::assign as PrimitiveMethod, Right::Write),
     "remove" => (PrimitiveObject::remove as PrimitiveMethod, Right::Write),
+    "set" => (PrimitiveObject::set_path as PrimitiveMethod, Right::Write),
 };
 
 type PrimitiveMethod = fn(
@@ -137,6 +158,12 @@ pub struct PrimitiveObject {
     pub value: HashMap<String, Literal>,
 }
 
+enum QuerySegment {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // METHOD FUNCTIONS
 ////////////////////////////////////////////////////////////////////////////////
@@ -461,6 +488,41 @@ impl PrimitiveObject {
         Ok(result)
     }
 
+    fn timeout(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "timeout(seconds: number) => http object";
+
+        let literal = match args.get("arg0") {
+            Some(literal) if args.len() == 1 => literal,
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        get_timeout_duration(literal, &data.context.flow)?;
+
+        let mut object = object.to_owned();
+
+        object
+            .value
+            .insert("timeout".to_owned(), literal.to_owned());
+
+        let mut result = PrimitiveObject::get_literal(&object.value, interval);
+
+        result.set_content_type("http");
+
+        Ok(result)
+    }
+
     fn send(
         object: &mut PrimitiveObject,
         args: &HashMap<String, Literal>,
@@ -478,39 +540,37 @@ impl PrimitiveObject {
             ));
         }
 
-        if let Some(literal) = object.value.get("method") {
-            let method = match Literal::get_value::<String>(
-                &literal.primitive,
-                &data.context.flow,
-                interval,
-                ERROR_HTTP_UNKNOWN_METHOD.to_string(),
-            ) {
-                Ok(delete) if delete == "delete" => "delete",
-                Ok(put) if put == "put" => "put",
-                Ok(patch) if patch == "patch" => "patch",
-                Ok(post) if post == "post" => "post",
-                Ok(get) if get == "get" => "get",
-                _ => {
-                    return Err(gen_error_info(
-                        Position::new(interval, &data.context.flow),
-                        ERROR_HTTP_UNKNOWN_METHOD.to_string(),
-                    ))
-                }
-            };
+        let method = get_http_method(object, &data.context.flow, interval)?;
 
-            let (value, response_info) =
-                http_request(&object.value, method, &data.context.flow, interval, false)?;
-            let mut literal = json_to_literal(&value, interval, &data.context.flow)?;
-            // add additional information about the http request response: status and headers
-            literal.add_info_block(response_info);
+        let (value, response_info) =
+            http_request(&object.value, method, &data.context.flow, interval, false)?;
+        let mut literal = json_to_literal(&value, interval, &data.context.flow)?;
+        // add additional information about the http request response: status and headers
+        literal.add_info_block(response_info);
+
+        Ok(literal)
+    }
+
+    fn fetch(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "fetch() => object {status, headers, body, error?}";
 
-            return Ok(literal);
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
         }
 
-        Err(gen_error_info(
-            Position::new(interval, &data.context.flow),
-            ERROR_HTTP_SEND.to_owned(),
-        ))
+        let method = get_http_method(object, &data.context.flow, interval)?;
+
+        http_fetch(&object.value, method, &data.context.flow, interval)
     }
 }
 
@@ -1267,20 +1327,38 @@ impl PrimitiveObject {
     ) -> Result<Literal, ErrorInfo> {
         let mut headers = jsonwebtoken::Header::default();
 
-        match args.get("arg0") {
+        // the legacy sign(algo, secret) order is detected by its first
+        // argument, otherwise sign(secret, algo) with HS256 by default
+        let (secret, algo) = match (args.get("arg0"), args.get("arg1")) {
+            (Some(algo), Some(secret))
+                if tools_jwt::get_algorithm(algo, &data.context.flow, interval).is_ok() =>
+            {
+                (secret, Some(algo))
+            }
+            (Some(secret), algo) => (secret, algo),
+            (None, _) => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_JWT_SIGN_SECRET.to_string(),
+                ))
+            }
+        };
+
+        match algo {
             Some(algo) if algo.primitive.get_type() == PrimitiveType::PrimitiveString => {
                 headers.alg = tools_jwt::get_algorithm(algo, &data.context.flow, interval)?;
             }
-            _ => {
+            Some(_) => {
                 return Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
                     ERROR_JWT_SIGN_ALGO.to_string(),
                 ))
             }
+            None => {}
         }
 
         let claims = match object.value.get("jwt") {
-            Some(literal) => literal.primitive.to_json(),
+            Some(literal) => tools_jwt::get_claims(literal),
             None => {
                 return Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
@@ -1289,10 +1367,10 @@ impl PrimitiveObject {
             }
         };
 
-        let key = match args.get("arg1") {
-            Some(key) if key.primitive.get_type() == PrimitiveType::PrimitiveString => {
+        let key = match secret.primitive.get_type() {
+            PrimitiveType::PrimitiveString => {
                 let key = Literal::get_value::<String>(
-                    &key.primitive,
+                    &secret.primitive,
                     &data.context.flow,
                     interval,
                     ERROR_JWT_SIGN_SECRET.to_string(),
@@ -1303,7 +1381,7 @@ impl PrimitiveObject {
             _ => {
                 return Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
-                    ERROR_JWT_ALGO.to_string(),
+                    ERROR_JWT_SIGN_SECRET.to_string(),
                 ))
             }
         };
@@ -1419,6 +1497,27 @@ impl PrimitiveObject {
             }
         };
 
+        // verify(secret): the algorithm comes from the token header and
+        // rejected tokens are reported in the returned object
+        if args.len() == 1 {
+            return match args.get("arg0") {
+                Some(secret) if secret.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                    let secret = Literal::get_value::<String>(
+                        &secret.primitive,
+                        &data.context.flow,
+                        interval,
+                        ERROR_JWT_VERIFY_SECRET.to_owned(),
+                    )?;
+
+                    tools_jwt::verify_token(token, secret, &data.context.flow, interval)
+                }
+                _ => Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_JWT_VERIFY_SECRET.to_string(),
+                )),
+            };
+        }
+
         match args.get("arg0") {
             Some(lit) => {
                 tools_jwt::get_validation(lit, &data.context.flow, interval, &mut validation)?
@@ -1697,20 +1796,119 @@ impl PrimitiveObject {
 
         Ok(PrimitiveString::get_literal(&value, interval))
     }
+
+    fn hmac_sha256(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "hmac_sha256(secret: string) => string";
+        let flow_name = &data.context.flow;
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, flow_name),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let value = get_crypto_value(object, flow_name, interval)?;
+        let secret = get_string_arg(args, "arg0", usage, flow_name, interval)?;
+
+        let hash = tools_crypto::hmac(
+            "sha256",
+            secret.as_bytes(),
+            value.as_bytes(),
+            flow_name,
+            interval,
+        )?;
+
+        Ok(PrimitiveString::get_literal(&hex::encode(hash), interval))
+    }
+
+    fn verify_hmac(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "verify_hmac(signature: string, secret: string) => boolean";
+        let flow_name = &data.context.flow;
+
+        if args.len() != 2 {
+            return Err(gen_error_info(
+                Position::new(interval, flow_name),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let value = get_crypto_value(object, flow_name, interval)?;
+        let signature = get_string_arg(args, "arg0", usage, flow_name, interval)?;
+        let secret = get_string_arg(args, "arg1", usage, flow_name, interval)?;
+
+        let is_valid = tools_crypto::verify_hmac(
+            "sha256",
+            secret.as_bytes(),
+            value.as_bytes(),
+            signature,
+            flow_name,
+            interval,
+        )?;
+
+        Ok(PrimitiveBoolean::get_literal(is_valid, interval))
+    }
+
+    fn crypto_sha256(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        crypto_hash(object, args, "sha256", &data.context.flow, interval)
+    }
+
+    fn crypto_md5(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        crypto_hash(object, args, "md5", &data.context.flow, interval)
+    }
 }
 
 impl PrimitiveObject {
     fn base64_encode(
         object: &mut PrimitiveObject,
-        _args: &HashMap<String, Literal>,
+        args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
         data: &mut Data,
         interval: Interval,
         _content_type: &str,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "Base64(\"...\").encode() => String";
+        let usage = "Base64(value: string || object).encode() => String";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
 
+        // objects are serialized to JSON before being encoded
         let string = match object.value.get("string") {
+            Some(lit) if lit.primitive.get_type() == PrimitiveType::PrimitiveObject => {
+                lit.primitive.to_json().to_string()
+            }
             Some(lit) => lit.primitive.to_string(),
             _ => {
                 return Err(gen_error_info(
@@ -1727,7 +1925,7 @@ impl PrimitiveObject {
 
     fn base64_decode(
         object: &mut PrimitiveObject,
-        _args: &HashMap<String, Literal>,
+        args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
         data: &mut Data,
         interval: Interval,
@@ -1735,27 +1933,43 @@ impl PrimitiveObject {
     ) -> Result<Literal, ErrorInfo> {
         let usage = "Base64(\"...\").decode() => String";
 
-        let string = match object.value.get("string") {
-            Some(lit) => lit.primitive.to_string(),
-            _ => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    format!("usage: {}", usage),
-                ))
-            }
-        };
+        let result = decode_base64(object, args, usage, &data.context.flow, interval)?;
 
-        let result = match base64::decode(string.as_bytes()) {
-            Ok(buf) => format!("{}", String::from_utf8_lossy(&buf)),
-            Err(_) => {
-                return Err(gen_error_info(
+        Ok(PrimitiveString::get_literal(&result, interval))
+    }
+
+    fn base64_decode_json(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "Base64(\"...\").decode_json() => Object";
+
+        let result = decode_base64(object, args, usage, &data.context.flow, interval)?;
+
+        match serde_json::from_str::<serde_json::Value>(&result) {
+            Ok(value) => json_to_literal(&value, interval, &data.context.flow),
+            Err(e) => {
+                let position = result
+                    .split('\n')
+                    .take(e.line().saturating_sub(1))
+                    .map(|line| line.len() + 1)
+                    .sum::<usize>()
+                    + e.column().saturating_sub(1);
+
+                Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
-                    format!("Base64 invalid value: {}, can't be decode", string),
+                    format!(
+                        "{} (at: '{}')",
+                        ERROR_BASE64_JSON,
+                        get_failing_prefix(&result, position)
+                    ),
                 ))
             }
-        };
-
-        Ok(PrimitiveString::get_literal(&result, interval))
+        }
     }
 }
 
@@ -2196,6 +2410,45 @@ impl PrimitiveObject {
         Ok(PrimitiveBoolean::get_literal(result, interval))
     }
 
+    fn has_path(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "has_path(path: string) => boolean";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let path = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_OBJECT_HAS_PATH.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_OBJECT_HAS_PATH.to_owned(),
+                ));
+            }
+        };
+
+        let result = object.get_path(path).is_some();
+
+        Ok(PrimitiveBoolean::get_literal(result, interval))
+    }
+
     fn is_empty(
         object: &mut PrimitiveObject,
         args: &HashMap<String, Literal>,
@@ -2235,7 +2488,8 @@ impl PrimitiveObject {
             ));
         }
 
-        let result = object.value.len();
+        let result = get_length(&*object)
+            .map_err(|err| gen_error_info(Position::new(interval, &data.context.flow), err))?;
 
         Ok(PrimitiveInt::get_literal(result as i64, interval))
     }
@@ -2257,11 +2511,11 @@ impl PrimitiveObject {
             ));
         }
 
-        let mut result = Vec::new();
-
-        for key in object.value.keys() {
-            result.push(PrimitiveString::get_literal(key, interval));
-        }
+        let result = object
+            .get_sorted_entries()
+            .into_iter()
+            .map(|(key, _)| PrimitiveString::get_literal(key, interval))
+            .collect::<Vec<Literal>>();
 
         Ok(PrimitiveArray::get_literal(&result, interval))
     }
@@ -2283,16 +2537,16 @@ impl PrimitiveObject {
             ));
         }
 
-        let mut result = Vec::new();
-
-        for value in object.value.values() {
-            result.push(value.to_owned());
-        }
+        let result = object
+            .get_sorted_entries()
+            .into_iter()
+            .map(|(_, value)| value.to_owned())
+            .collect::<Vec<Literal>>();
 
         Ok(PrimitiveArray::get_literal(&result, interval))
     }
 
-    fn get_generics(
+    fn entries(
         object: &mut PrimitiveObject,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -2300,41 +2554,35 @@ impl PrimitiveObject {
         interval: Interval,
         _content_type: &str,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "get(key: string) => primitive";
+        let usage = "entries() => array";
 
-        if args.len() != 1 {
+        if !args.is_empty() {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let key = match args.get("arg0") {
-            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
-                Literal::get_value::<String>(
-                    &res.primitive,
-                    &data.context.flow,
-                    interval,
-                    ERROR_OBJECT_GET_GENERICS.to_owned(),
-                )?
-            }
-            _ => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_OBJECT_GET_GENERICS.to_owned(),
-                ));
-            }
-        };
+        let result = object
+            .get_sorted_entries()
+            .into_iter()
+            .map(|(key, value)| {
+                let mut entry = HashMap::new();
 
-        match object.value.get(key) {
-            Some(res) => Ok(res.to_owned()),
-            None => Ok(PrimitiveNull::get_literal(interval)),
-        }
+                entry.insert(
+                    "key".to_owned(),
+                    PrimitiveString::get_literal(key, interval),
+                );
+                entry.insert("value".to_owned(), value.to_owned());
+
+                PrimitiveObject::get_literal(&entry, interval)
+            })
+            .collect::<Vec<Literal>>();
+
+        Ok(PrimitiveArray::get_literal(&result, interval))
     }
-}
 
-impl PrimitiveObject {
-    fn clear_values(
+    fn merge(
         object: &mut PrimitiveObject,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -2342,12 +2590,316 @@ impl PrimitiveObject {
         interval: Interval,
         _content_type: &str,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "clear_values() => null";
+        let usage = "merge(obj: Object) => object";
 
-        if !args.is_empty() {
-            return Err(gen_error_info(
-                Position::new(interval, &data.context.flow),
-                format!("usage: {}", usage),
+        let other = get_object_arg(args, usage, data, interval)?;
+
+        let mut result = object.value.clone();
+        merge_objects(&mut result, other, false);
+
+        Ok(PrimitiveObject::get_literal(&result, interval))
+    }
+
+    fn deep_merge(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "deep_merge(obj: Object) => object";
+
+        let other = get_object_arg(args, usage, data, interval)?;
+
+        let mut result = object.value.clone();
+        merge_objects(&mut result, other, true);
+
+        Ok(PrimitiveObject::get_literal(&result, interval))
+    }
+
+    fn to_array(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_array() => [[string, primitive]]";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let result = object
+            .get_sorted_entries()
+            .into_iter()
+            .map(|(key, value)| {
+                PrimitiveArray::get_literal(
+                    &[
+                        PrimitiveString::get_literal(key, interval),
+                        value.to_owned(),
+                    ],
+                    interval,
+                )
+            })
+            .collect::<Vec<Literal>>();
+
+        Ok(PrimitiveArray::get_literal(&result, interval))
+    }
+
+    fn clone(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "clone() => object";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        // cloning a Literal copies every nested primitive, nothing is shared with the source
+        Ok(PrimitiveObject::get_literal(&object.value, interval))
+    }
+
+    fn flatten_keys(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "flatten_keys(keep_arrays: boolean = false) => object";
+
+        let keep_arrays = match args.get("arg0") {
+            None if args.is_empty() => false,
+            Some(res)
+                if args.len() == 1
+                    && res.primitive.get_type() == PrimitiveType::PrimitiveBoolean =>
+            {
+                res.primitive.as_bool()
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        let mut result = HashMap::new();
+
+        for (key, value) in object.get_sorted_entries() {
+            flatten_literal(
+                key.to_owned(),
+                value,
+                keep_arrays,
+                &mut result,
+                &data.context.flow,
+                interval,
+            )?;
+        }
+
+        Ok(PrimitiveObject::get_literal(&result, interval))
+    }
+
+    fn unflatten(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "unflatten() => object";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let mut result = HashMap::new();
+
+        for (path, value) in object.get_sorted_entries() {
+            unflatten_literal(path, value, &mut result, &data.context.flow, interval)?;
+        }
+
+        Ok(PrimitiveObject::get_literal(&result, interval))
+    }
+
+    fn query_path(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage =
+            "query(path: string) => primitive, or an array of primitives for paths with a wildcard";
+
+        let path = match args.get("arg0") {
+            Some(res)
+                if args.len() == 1
+                    && res.primitive.get_type() == PrimitiveType::PrimitiveString =>
+            {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    format!("usage: {}", usage),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        let segments = match parse_query(path) {
+            Ok(segments) => segments,
+            Err(position) => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!(
+                        "{} (position: {} in '{}')",
+                        ERROR_OBJECT_QUERY, position, path
+                    ),
+                ));
+            }
+        };
+
+        let root = PrimitiveObject::get_literal(&object.value, interval);
+
+        let values = segments.iter().fold(vec![&root], |values, segment| {
+            query_literals(values, segment)
+        });
+
+        let is_wildcard = segments
+            .iter()
+            .any(|segment| matches!(segment, QuerySegment::Wildcard));
+
+        match values.first() {
+            _ if is_wildcard => {
+                let values = values.into_iter().cloned().collect::<Vec<Literal>>();
+
+                Ok(PrimitiveArray::get_literal(&values, interval))
+            }
+            Some(value) => Ok((*value).to_owned()),
+            None => Ok(PrimitiveNull::get_literal(interval)),
+        }
+    }
+
+    fn get_generics(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "get(key: string | path: string) => primitive";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let key = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_OBJECT_GET_GENERICS.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_OBJECT_GET_GENERICS.to_owned(),
+                ));
+            }
+        };
+
+        match object.get_path(key) {
+            Some(res) => Ok(res.to_owned()),
+            None => Ok(PrimitiveNull::get_literal(interval)),
+        }
+    }
+
+    fn get_or(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "get_or(key: string, default: primitive) => primitive";
+
+        let (key, default) = match (args.get("arg0"), args.get("arg1")) {
+            (Some(key), Some(default))
+                if args.len() == 2
+                    && key.primitive.get_type() == PrimitiveType::PrimitiveString =>
+            {
+                let key = Literal::get_value::<String>(
+                    &key.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_OBJECT_GET_OR.to_owned(),
+                )?;
+
+                (key, default)
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        match object.get_path(key) {
+            Some(res) => Ok(res.to_owned()),
+            None => Ok(default.to_owned()),
+        }
+    }
+}
+
+impl PrimitiveObject {
+    fn clear_values(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "clear_values() => null";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
             ));
         }
 
@@ -2456,6 +3008,62 @@ impl PrimitiveObject {
         Ok(PrimitiveNull::get_literal(interval))
     }
 
+    fn set_path(
+        object: &mut PrimitiveObject,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        data: &mut Data,
+        interval: Interval,
+        _content_type: &str,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "set(path: string, value: primitive) => null";
+
+        let (path, value) = match (args.get("arg0"), args.get("arg1")) {
+            (Some(path), Some(value))
+                if args.len() == 2
+                    && path.primitive.get_type() == PrimitiveType::PrimitiveString =>
+            {
+                let path = Literal::get_value::<String>(
+                    &path.primitive,
+                    &data.context.flow,
+                    interval,
+                    format!("usage: {}", usage),
+                )?;
+
+                (path, value)
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        let keys = path.split('.').collect::<Vec<&str>>();
+        let (last, parents) = match keys.split_last() {
+            Some(keys) => keys,
+            None => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("usage: {}", usage),
+                ));
+            }
+        };
+
+        let current = get_nested_object_mut(
+            &mut object.value,
+            parents,
+            ERROR_OBJECT_SET_PATH,
+            &data.context.flow,
+            interval,
+        )?;
+
+        current.insert(last.to_string(), value.to_owned());
+
+        Ok(PrimitiveNull::get_literal(interval))
+    }
+
     fn remove(
         object: &mut PrimitiveObject,
         args: &HashMap<String, Literal>,
@@ -2464,7 +3072,7 @@ impl PrimitiveObject {
         interval: Interval,
         _content_type: &str,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "remove(key: string) => primitive";
+        let usage = "remove(key: string | path: string) => primitive";
 
         if args.len() != 1 {
             return Err(gen_error_info(
@@ -2490,7 +3098,33 @@ impl PrimitiveObject {
             }
         };
 
-        match object.value.remove(key) {
+        if let Some(value) = object.value.remove(key) {
+            return Ok(value);
+        }
+
+        // a key that does not exist as a whole is read as a path to a nested key
+        let mut path: Vec<&str> = key.split('.').collect();
+        let last = match path.pop() {
+            Some(last) if !path.is_empty() => last,
+            _ => return Ok(PrimitiveNull::get_literal(interval)),
+        };
+
+        let mut current = &mut object.value;
+        for key in path {
+            current = match current.get_mut(key) {
+                Some(value) if value.primitive.get_type() == PrimitiveType::PrimitiveObject => {
+                    Literal::get_mut_value::<HashMap<String, Literal>>(
+                        &mut value.primitive,
+                        &data.context.flow,
+                        interval,
+                        ERROR_OBJECT_REMOVE.to_owned(),
+                    )?
+                }
+                _ => return Ok(PrimitiveNull::get_literal(interval)),
+            };
+        }
+
+        match current.remove(last) {
             Some(value) => Ok(value),
             None => Ok(PrimitiveNull::get_literal(interval)),
         }
@@ -2501,6 +3135,416 @@ impl PrimitiveObject {
 // PRIVATE FUNCTION
 ////////////////////////////////////////////////////////////////////////////////
 
+fn get_object_arg<'a>(
+    args: &'a HashMap<String, Literal>,
+    usage: &str,
+    data: &Data,
+    interval: Interval,
+) -> Result<&'a HashMap<String, Literal>, ErrorInfo> {
+    match args.get("arg0") {
+        Some(res)
+            if args.len() == 1 && res.primitive.get_type() == PrimitiveType::PrimitiveObject =>
+        {
+            Literal::get_value::<HashMap<String, Literal>>(
+                &res.primitive,
+                &data.context.flow,
+                interval,
+                format!("usage: {}", usage),
+            )
+        }
+        _ => Err(gen_error_info(
+            Position::new(interval, &data.context.flow),
+            format!("usage: {}", usage),
+        )),
+    }
+}
+
+fn flatten_literal(
+    path: String,
+    literal: &Literal,
+    keep_arrays: bool,
+    result: &mut HashMap<String, Literal>,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<(), ErrorInfo> {
+    if let Some(object) = literal.primitive.as_any().downcast_ref::<PrimitiveObject>() {
+        if !object.value.is_empty() {
+            for (key, value) in object.get_sorted_entries() {
+                let path = format!("{}.{}", path, key);
+                flatten_literal(path, value, keep_arrays, result, flow_name, interval)?;
+            }
+
+            return Ok(());
+        }
+    }
+
+    if let Some(array) = literal.primitive.as_any().downcast_ref::<PrimitiveArray>() {
+        if !keep_arrays && !array.value.is_empty() {
+            for (index, value) in array.value.iter().enumerate() {
+                let path = format!("{}.{}", path, index);
+                flatten_literal(path, value, keep_arrays, result, flow_name, interval)?;
+            }
+
+            return Ok(());
+        }
+    }
+
+    // a key containing a dot can end up on the same path as a nested key
+    if result.contains_key(&path) {
+        return Err(gen_error_info(
+            Position::new(interval, flow_name),
+            format!("{} (path: {})", ERROR_OBJECT_FLATTEN_KEYS_COLLISION, path),
+        ));
+    }
+
+    result.insert(path, literal.to_owned());
+
+    Ok(())
+}
+
+// walk the given keys from object, creating the missing intermediate objects
+fn get_nested_object_mut<'a>(
+    object: &'a mut HashMap<String, Literal>,
+    keys: &[&str],
+    error: &str,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<&'a mut HashMap<String, Literal>, ErrorInfo> {
+    let mut current = object;
+
+    for (index, key) in keys.iter().enumerate() {
+        let value = current
+            .entry(key.to_string())
+            .or_insert_with(|| PrimitiveObject::get_literal(&HashMap::new(), interval));
+
+        if value.primitive.get_type() != PrimitiveType::PrimitiveObject {
+            return Err(gen_error_info(
+                Position::new(interval, flow_name),
+                format!("{} (path: {})", error, keys[..=index].join(".")),
+            ));
+        }
+
+        current = Literal::get_mut_value::<HashMap<String, Literal>>(
+            &mut value.primitive,
+            flow_name,
+            interval,
+            ERROR_UNREACHABLE.to_owned(),
+        )?;
+    }
+
+    Ok(current)
+}
+
+fn unflatten_literal(
+    path: &str,
+    literal: &Literal,
+    result: &mut HashMap<String, Literal>,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<(), ErrorInfo> {
+    let keys = path.split('.').collect::<Vec<&str>>();
+    let (last, parents) = match keys.split_last() {
+        Some(keys) => keys,
+        None => return Ok(()),
+    };
+
+    let current = get_nested_object_mut(
+        result,
+        parents,
+        ERROR_OBJECT_UNFLATTEN_COLLISION,
+        flow_name,
+        interval,
+    )?;
+
+    if current.contains_key(*last) {
+        return Err(gen_error_info(
+            Position::new(interval, flow_name),
+            format!("{} (path: {})", ERROR_OBJECT_UNFLATTEN_COLLISION, path),
+        ));
+    }
+
+    current.insert(last.to_string(), literal.to_owned());
+
+    Ok(())
+}
+
+// parse the supported subset of JSONPath: $.key, $.*, $[0] and $[*]
+// on failure the position of the unexpected character is returned
+fn parse_query(path: &str) -> Result<Vec<QuerySegment>, usize> {
+    let chars = path.chars().collect::<Vec<char>>();
+    let mut segments = vec![];
+
+    if chars.first() != Some(&'$') {
+        return Err(0);
+    }
+
+    let mut index = 1;
+    while index < chars.len() {
+        match chars[index] {
+            '.' if chars.get(index + 1) == Some(&'*') => {
+                segments.push(QuerySegment::Wildcard);
+                index += 2;
+            }
+            '.' => {
+                let start = index + 1;
+                let end = chars[start..]
+                    .iter()
+                    .position(|c| *c == '.' || *c == '[')
+                    .map_or(chars.len(), |position| start + position);
+
+                if start == end {
+                    return Err(start);
+                }
+                if let Some(position) = chars[start..end]
+                    .iter()
+                    .position(|c| !(c.is_alphanumeric() || *c == '_' || *c == '-'))
+                {
+                    return Err(start + position);
+                }
+
+                segments.push(QuerySegment::Key(chars[start..end].iter().collect()));
+                index = end;
+            }
+            '[' => {
+                let start = index + 1;
+                let end = match chars[start..].iter().position(|c| *c == ']') {
+                    Some(position) => start + position,
+                    None => return Err(chars.len()),
+                };
+                let content = chars[start..end].iter().collect::<String>();
+
+                match content.as_str() {
+                    "*" => segments.push(QuerySegment::Wildcard),
+                    _ => match content.parse::<usize>() {
+                        Ok(value) => segments.push(QuerySegment::Index(value)),
+                        Err(_) => return Err(start),
+                    },
+                }
+                index = end + 1;
+            }
+            _ => return Err(index),
+        }
+    }
+
+    Ok(segments)
+}
+
+fn query_literals<'a>(values: Vec<&'a Literal>, segment: &QuerySegment) -> Vec<&'a Literal> {
+    values
+        .into_iter()
+        .flat_map(|value| {
+            let object = value.primitive.as_any().downcast_ref::<PrimitiveObject>();
+            let array = value.primitive.as_any().downcast_ref::<PrimitiveArray>();
+
+            match (segment, object, array) {
+                (QuerySegment::Key(key), Some(object), _) => {
+                    object.value.get(key).into_iter().collect()
+                }
+                (QuerySegment::Index(index), _, Some(array)) => {
+                    array.value.get(*index).into_iter().collect()
+                }
+                (QuerySegment::Wildcard, Some(object), _) => object
+                    .get_sorted_entries()
+                    .into_iter()
+                    .map(|(_, value)| value)
+                    .collect(),
+                (QuerySegment::Wildcard, _, Some(array)) => array.value.iter().collect(),
+                _ => vec![],
+            }
+        })
+        .collect()
+}
+
+// keys of src win over keys of dst; in deep mode two objects found at the same
+// key are merged recursively, any other pair (arrays, object vs scalar) is
+// replaced by the value of src
+fn merge_objects(dst: &mut HashMap<String, Literal>, src: &HashMap<String, Literal>, deep: bool) {
+    for (key, value) in src.iter() {
+        let nested = match (dst.get(key), deep) {
+            (Some(current), true) => match (
+                current.primitive.as_any().downcast_ref::<PrimitiveObject>(),
+                value.primitive.as_any().downcast_ref::<PrimitiveObject>(),
+            ) {
+                (Some(current), Some(other)) => {
+                    let mut merged = current.value.clone();
+                    merge_objects(&mut merged, &other.value, deep);
+
+                    Some(merged)
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let literal = match nested {
+            Some(merged) => Literal {
+                primitive: Box::new(PrimitiveObject::new(&merged)),
+                ..value.to_owned()
+            },
+            None => value.to_owned(),
+        };
+
+        dst.insert(key.to_owned(), literal);
+    }
+}
+
+fn get_crypto_value<'a>(
+    object: &'a PrimitiveObject,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<&'a String, ErrorInfo> {
+    match object.value.get("value") {
+        Some(literal) => Literal::get_value::<String>(
+            &literal.primitive,
+            flow_name,
+            literal.interval,
+            ERROR_HASH.to_owned(),
+        ),
+        None => Err(gen_error_info(
+            Position::new(interval, flow_name),
+            ERROR_HASH.to_owned(),
+        )),
+    }
+}
+
+fn get_string_arg<'a>(
+    args: &'a HashMap<String, Literal>,
+    key: &str,
+    usage: &str,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<&'a String, ErrorInfo> {
+    match args.get(key) {
+        Some(literal) => Literal::get_value::<String>(
+            &literal.primitive,
+            flow_name,
+            literal.interval,
+            format!("usage: {}", usage),
+        ),
+        None => Err(gen_error_info(
+            Position::new(interval, flow_name),
+            format!("usage: {}", usage),
+        )),
+    }
+}
+
+fn crypto_hash(
+    object: &PrimitiveObject,
+    args: &HashMap<String, Literal>,
+    algo: &str,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<Literal, ErrorInfo> {
+    if !args.is_empty() {
+        return Err(gen_error_info(
+            Position::new(interval, flow_name),
+            format!("usage: {}() => string", algo),
+        ));
+    }
+
+    let value = get_crypto_value(object, flow_name, interval)?;
+    let digest = tools_crypto::hash_hex(algo, value.as_bytes(), flow_name, interval)?;
+
+    Ok(PrimitiveString::get_literal(&digest, interval))
+}
+
+fn get_http_method(
+    object: &PrimitiveObject,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<&'static str, ErrorInfo> {
+    let literal = match object.value.get("method") {
+        Some(literal) => literal,
+        None => {
+            return Err(gen_error_info(
+                Position::new(interval, flow_name),
+                ERROR_HTTP_SEND.to_owned(),
+            ))
+        }
+    };
+
+    match Literal::get_value::<String>(
+        &literal.primitive,
+        flow_name,
+        interval,
+        ERROR_HTTP_UNKNOWN_METHOD.to_string(),
+    ) {
+        Ok(delete) if delete == "delete" => Ok("delete"),
+        Ok(put) if put == "put" => Ok("put"),
+        Ok(patch) if patch == "patch" => Ok("patch"),
+        Ok(post) if post == "post" => Ok("post"),
+        Ok(get) if get == "get" => Ok("get"),
+        _ => Err(gen_error_info(
+            Position::new(interval, flow_name),
+            ERROR_HTTP_UNKNOWN_METHOD.to_string(),
+        )),
+    }
+}
+
+/// the input up to the position of an error, long inputs keep the last
+/// characters before the error
+fn get_failing_prefix(input: &str, position: usize) -> String {
+    let prefix: Vec<char> = input
+        .char_indices()
+        .take_while(|(index, _)| *index <= position)
+        .map(|(_, c)| c)
+        .collect();
+
+    match prefix.len() {
+        len if len > 32 => format!("...{}", prefix[len - 32..].iter().collect::<String>()),
+        _ => prefix.iter().collect(),
+    }
+}
+
+/// CSML strings are UTF-8: binary data can be encoded from a string but bytes
+/// that do not form valid UTF-8 once decoded are reported instead of being
+/// silently replaced
+fn decode_base64(
+    object: &PrimitiveObject,
+    args: &HashMap<String, Literal>,
+    usage: &str,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<String, ErrorInfo> {
+    let string = match object.value.get("string") {
+        Some(lit) if args.is_empty() => lit.primitive.to_string(),
+        _ => {
+            return Err(gen_error_info(
+                Position::new(interval, flow_name),
+                format!("usage: {}", usage),
+            ))
+        }
+    };
+
+    let buf = base64::decode(string.as_bytes()).map_err(|e| {
+        let position = match e {
+            base64::DecodeError::InvalidByte(offset, _)
+            | base64::DecodeError::InvalidLastSymbol(offset, _) => offset,
+            base64::DecodeError::InvalidLength => string.len(),
+        };
+
+        gen_error_info(
+            Position::new(interval, flow_name),
+            format!(
+                "{} (at: '{}')",
+                ERROR_STRING_BASE64_INVALID,
+                get_failing_prefix(&string, position)
+            ),
+        )
+    })?;
+
+    String::from_utf8(buf).map_err(|e| {
+        gen_error_info(
+            Position::new(interval, flow_name),
+            format!(
+                "{} (byte: {})",
+                ERROR_STRING_BASE64_UTF8,
+                e.utf8_error().valid_up_to()
+            ),
+        )
+    })
+}
+
 fn insert_to_object(
     src: &HashMap<String, Literal>,
     dst: &mut PrimitiveObject,
@@ -2525,6 +3569,38 @@ fn insert_to_object(
         .or_insert_with(|| literal.to_owned());
 }
 
+impl PrimitiveObject {
+    // keys(), values() and entries() share the same ordering
+    fn get_sorted_entries(&self) -> Vec<(&String, &Literal)> {
+        let mut entries = self.value.iter().collect::<Vec<(&String, &Literal)>>();
+        entries.sort_unstable_by_key(|(key, _)| *key);
+
+        entries
+    }
+
+    // a key that does not exist as a whole is read as a dotted path to a nested key
+    fn get_path(&self, path: &str) -> Option<&Literal> {
+        if let Some(value) = self.value.get(path) {
+            return Some(value);
+        }
+
+        let mut current = self;
+        let mut keys = path.split('.').peekable();
+
+        while let Some(key) = keys.next() {
+            let value = current.value.get(key)?;
+
+            if keys.peek().is_none() {
+                return Some(value);
+            }
+
+            current = value.primitive.as_any().downcast_ref::<PrimitiveObject>()?;
+        }
+
+        None
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // PUBLIC FUNCTIONS
 ////////////////////////////////////////////////////////////////////////////////
@@ -2696,7 +3772,7 @@ impl Primitive for PrimitiveObject {
     }
 
     fn as_bool(&self) -> bool {
-        true
+        !self.value.is_empty()
     }
 
     fn get_value(&self) -> &dyn std::any::Any {
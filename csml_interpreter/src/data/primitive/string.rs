@@ -7,6 +7,7 @@ use crate::data::primitive::int::PrimitiveInt;
 use crate::data::primitive::null::PrimitiveNull;
 use crate::data::primitive::object::PrimitiveObject;
 use crate::data::primitive::tools::*;
+use crate::data::primitive::tools_crypto;
 use crate::data::primitive::Right;
 use crate::data::primitive::{Primitive, PrimitiveType};
 use crate::data::{ast::Interval, message::Message, Data, Literal, MemoryType, MessageData, MSG};
@@ -19,6 +20,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::{collections::HashMap, sync::mpsc};
+use unicode_segmentation::UnicodeSegmentation;
 use url::form_urlencoded;
 use url::form_urlencoded::Parse;
 use url::Url;
@@ -51,35 +53,69 @@ const FUNCTIONS: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_map! {
     "decode_uri" => (PrimitiveString::decode_uri as PrimitiveMethod, Right::Read),
     "encode_uri_component" => (PrimitiveString::encode_uri_component as PrimitiveMethod, Right::Read),
     "decode_uri_component" => (PrimitiveString::decode_uri_component as PrimitiveMethod, Right::Read),
+    "format" => (PrimitiveString::format as PrimitiveMethod, Right::Read),
+    "levenshtein" => (PrimitiveString::levenshtein as PrimitiveMethod, Right::Read),
+    "similarity" => (PrimitiveString::similarity as PrimitiveMethod, Right::Read),
+    "hash" => (PrimitiveString::hash as PrimitiveMethod, Right::Read),
+    "sha256" => (PrimitiveString::sha256 as PrimitiveMethod, Right::Read),
+    "sha1" => (PrimitiveString::sha1 as PrimitiveMethod, Right::Read),
+    "md5" => (PrimitiveString::md5 as PrimitiveMethod, Right::Read),
+    "url_encode" => (PrimitiveString::url_encode as PrimitiveMethod, Right::Read),
+    "url_decode" => (PrimitiveString::url_decode as PrimitiveMethod, Right::Read),
     "encode_html_entities" => (PrimitiveString::encode_html_entities as PrimitiveMethod, Right::Read),
     "decode_html_entities" => (PrimitiveString::decode_html_entities as PrimitiveMethod, Right::Read),
+    "html_escape" => (PrimitiveString::html_escape as PrimitiveMethod, Right::Read),
+    "html_unescape" => (PrimitiveString::html_unescape as PrimitiveMethod, Right::Read),
+    "base64_encode" => (PrimitiveString::base64_encode as PrimitiveMethod, Right::Read),
+    "base64_decode" => (PrimitiveString::base64_decode as PrimitiveMethod, Right::Read),
 
     "is_email" => (PrimitiveString::is_email as PrimitiveMethod, Right::Read),
+    "is_url" => (PrimitiveString::is_url as PrimitiveMethod, Right::Read),
+    "is_phone" => (PrimitiveString::is_phone as PrimitiveMethod, Right::Read),
     "append" => (PrimitiveString::append as PrimitiveMethod, Right::Read),
     "contains" => (PrimitiveString::contains as PrimitiveMethod, Right::Read),
+    "contains_any" => (PrimitiveString::contains_any as PrimitiveMethod, Right::Read),
     "contains_regex" => (PrimitiveString::contains_regex as PrimitiveMethod, Right::Read),
     "replace_regex" => (PrimitiveString::replace_regex as PrimitiveMethod, Right::Read),
     "replace_all" => (PrimitiveString::replace_all as PrimitiveMethod, Right::Read),
     "replace" => (PrimitiveString::replace as PrimitiveMethod, Right::Read),
+    "index_of" => (PrimitiveString::index_of as PrimitiveMethod, Right::Read),
+    "last_index_of" => (PrimitiveString::last_index_of as PrimitiveMethod, Right::Read),
 
     "ends_with" => (PrimitiveString::ends_with as PrimitiveMethod, Right::Read),
     "ends_with_regex" => (PrimitiveString::ends_with_regex as PrimitiveMethod, Right::Read),
     "from_json" => (PrimitiveString::from_json as PrimitiveMethod, Right::Read),
     "is_empty" => (PrimitiveString::is_empty as PrimitiveMethod, Right::Read),
     "length" => (PrimitiveString::length as PrimitiveMethod, Right::Read),
+    "byte_length" => (PrimitiveString::byte_length as PrimitiveMethod, Right::Read),
     "match" => (PrimitiveString::do_match as PrimitiveMethod, Right::Read),
     "match_regex" => (PrimitiveString::do_match_regex as PrimitiveMethod, Right::Read),
+    "is_match" => (PrimitiveString::is_match as PrimitiveMethod, Right::Read),
+    "capture_regex" => (PrimitiveString::capture_regex as PrimitiveMethod, Right::Read),
     "starts_with" => (PrimitiveString::starts_with as PrimitiveMethod, Right::Read),
     "starts_with_regex" => (PrimitiveString::starts_with_regex as PrimitiveMethod, Right::Read),
     "to_lowercase" => (PrimitiveString::to_lowercase as PrimitiveMethod, Right::Read),
     "to_uppercase" => (PrimitiveString::to_uppercase as PrimitiveMethod, Right::Read),
     "capitalize" => (PrimitiveString::capitalize as PrimitiveMethod, Right::Read),
+    "title_case" => (PrimitiveString::title_case as PrimitiveMethod, Right::Read),
+    "chars" => (PrimitiveString::chars as PrimitiveMethod, Right::Read),
+    "lines" => (PrimitiveString::lines as PrimitiveMethod, Right::Read),
+    "char_at" => (PrimitiveString::char_at as PrimitiveMethod, Right::Read),
+    "reverse" => (PrimitiveString::reverse as PrimitiveMethod, Right::Read),
     "slice" => (PrimitiveString::slice as PrimitiveMethod, Right::Read),
+    "substring" => (PrimitiveString::slice as PrimitiveMethod, Right::Read),
     "split" => (PrimitiveString::split as PrimitiveMethod, Right::Read),
+    "split_regex" => (PrimitiveString::split_regex as PrimitiveMethod, Right::Read),
+    "pad_start" => (PrimitiveString::pad_start as PrimitiveMethod, Right::Read),
+    "pad_end" => (PrimitiveString::pad_end as PrimitiveMethod, Right::Read),
+    "repeat" => (PrimitiveString::repeat as PrimitiveMethod, Right::Read),
 
     "trim" => (PrimitiveString::trim as PrimitiveMethod, Right::Read),
-    "trim_left" => (PrimitiveString::trim_left as PrimitiveMethod, Right::Read),
-    "trim_right" => (PrimitiveString::trim_right as PrimitiveMethod, Right::Read),
+    "trim_start" => (PrimitiveString::trim_start as PrimitiveMethod, Right::Read),
+    "trim_end" => (PrimitiveString::trim_end as PrimitiveMethod, Right::Read),
+    "trim_left" => (PrimitiveString::trim_start as PrimitiveMethod, Right::Read),
+    "trim_right" => (PrimitiveString::trim_end as PrimitiveMethod, Right::Read),
+    "trim_matches" => (PrimitiveString::trim_matches as PrimitiveMethod, Right::Read),
 
     "abs" => (PrimitiveString::abs as PrimitiveMethod, Right::Read),
     "cos" => (PrimitiveString::cos as PrimitiveMethod, Right::Read),
@@ -92,8 +128,21 @@ const FUNCTIONS: phf::Map<&'static str, (PrimitiveMethod, Right)> = phf_map! {
     "tan" => (PrimitiveString::tan as PrimitiveMethod, Right::Read),
     "to_int" => (PrimitiveString::to_int as PrimitiveMethod, Right::Read),
     "to_float" =>(PrimitiveString::to_float as PrimitiveMethod, Right::Read),
+    "parse_number" => (PrimitiveString::parse_number as PrimitiveMethod, Right::Read),
+    "to_boolean" => (PrimitiveString::to_boolean as PrimitiveMethod, Right::Read),
+    "from_hex" => (PrimitiveString::from_hex as PrimitiveMethod, Right::Read),
+    "parse_int" => (PrimitiveString::parse_int as PrimitiveMethod, Right::Read),
 };
 
+// upper bound in bytes of a string built with repeat()
+const MAX_REPEAT_SIZE: usize = 4 * 1024 * 1024;
+
+// upper bound in graphemes of the width given to pad_start() and pad_end()
+const MAX_PAD_WIDTH: usize = 1024 * 1024;
+
+// upper bound in chars of the strings compared with levenshtein() and similarity()
+const MAX_FUZZY_MATCH_LEN: usize = 10_000;
+
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct PrimitiveString {
     pub value: String,
@@ -178,6 +227,178 @@ fn decode_value(pairs: &Parse) -> String {
     vec.concat()
 }
 
+fn get_case_insensitive_flag(
+    args: &HashMap<String, Literal>,
+    flow_name: &str,
+    interval: Interval,
+    usage: &str,
+) -> Result<bool, ErrorInfo> {
+    match args.get("arg1") {
+        Some(lit) if lit.primitive.get_type() == PrimitiveType::PrimitiveBoolean => {
+            Ok(*Literal::get_value::<bool>(
+                &lit.primitive,
+                flow_name,
+                lit.interval,
+                format!("usage: {}", usage),
+            )?)
+        }
+        Some(_) => Err(gen_error_info(
+            Position::new(interval, flow_name),
+            format!("usage: {}", usage),
+        )),
+        None => Ok(false),
+    }
+}
+
+fn get_string_list(
+    literal: &Literal,
+    flow_name: &str,
+    interval: Interval,
+    error_message: &str,
+) -> Result<Vec<String>, ErrorInfo> {
+    let array = Literal::get_value::<Vec<Literal>>(
+        &literal.primitive,
+        flow_name,
+        interval,
+        error_message.to_owned(),
+    )?;
+
+    array
+        .iter()
+        .map(|lit| match lit.primitive.get_type() {
+            PrimitiveType::PrimitiveString => Ok(lit.primitive.to_string()),
+            _ => Err(gen_error_info(
+                Position::new(interval, flow_name),
+                error_message.to_owned(),
+            )),
+        })
+        .collect()
+}
+
+fn get_string_candidates(
+    literal: &Literal,
+    flow_name: &str,
+    interval: Interval,
+    error_message: &str,
+) -> Result<Vec<String>, ErrorInfo> {
+    match literal.primitive.get_type() {
+        PrimitiveType::PrimitiveString => Ok(vec![literal.primitive.to_string()]),
+        PrimitiveType::PrimitiveArray => {
+            get_string_list(literal, flow_name, interval, error_message)
+        }
+        _ => Err(gen_error_info(
+            Position::new(interval, flow_name),
+            error_message.to_owned(),
+        )),
+    }
+}
+
+fn compile_regex(pattern: &str, flow_name: &str, interval: Interval) -> Result<Regex, ErrorInfo> {
+    match Regex::new(pattern) {
+        Ok(regex) => Ok(regex),
+        Err(_) => Err(gen_error_info(
+            Position::new(interval, flow_name),
+            format!("{}: {:?}", ERROR_STRING_VALID_REGEX, pattern),
+        )),
+    }
+}
+
+fn is_valid_percent_encoding(s: &str) -> bool {
+    let bytes = s.as_bytes();
+
+    bytes.iter().enumerate().all(|(index, byte)| {
+        *byte != b'%'
+            || (index + 2 < bytes.len()
+                && bytes[index + 1].is_ascii_hexdigit()
+                && bytes[index + 2].is_ascii_hexdigit())
+    })
+}
+
+fn get_base64_config(
+    args: &HashMap<String, Literal>,
+    flow_name: &str,
+    interval: Interval,
+    usage: &str,
+) -> Result<base64::Config, ErrorInfo> {
+    match args.get("arg0") {
+        None => Ok(base64::STANDARD),
+        Some(lit) if lit.primitive.get_type() == PrimitiveType::PrimitiveString => {
+            match lit.primitive.to_string().as_str() {
+                "standard" => Ok(base64::STANDARD),
+                "urlsafe" => Ok(base64::URL_SAFE),
+                _ => Err(gen_error_info(
+                    Position::new(interval, flow_name),
+                    format!("usage: {}", usage),
+                )),
+            }
+        }
+        Some(_) => Err(gen_error_info(
+            Position::new(interval, flow_name),
+            format!("usage: {}", usage),
+        )),
+    }
+}
+
+fn hash_string(
+    value: &str,
+    algo: &str,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<Literal, ErrorInfo> {
+    let digest = tools_crypto::hash_hex(algo, value.as_bytes(), flow_name, interval)?;
+
+    Ok(PrimitiveString::get_literal(&digest, interval))
+}
+
+fn levenshtein_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.iter().enumerate() {
+        current[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let cost = if a_char == b_char { 0 } else { 1 };
+
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+fn grapheme_to_byte_index(s: &str, grapheme_index: usize) -> usize {
+    s.grapheme_indices(true)
+        .nth(grapheme_index)
+        .map(|(index, _)| index)
+        .unwrap_or_else(|| s.len())
+}
+
+fn byte_to_grapheme_index(s: &str, byte_index: usize) -> usize {
+    s[..byte_index].graphemes(true).count()
+}
+
+fn capitalize_str(s: &str) -> String {
+    let mut graphemes = s.graphemes(true);
+
+    match graphemes.next() {
+        None => String::new(),
+        Some(first) => first.to_uppercase() + graphemes.as_str(),
+    }
+}
+
+fn contains_str(haystack: &str, needle: &str, case_insensitive: bool) -> bool {
+    if case_insensitive {
+        haystack.to_lowercase().contains(&needle.to_lowercase())
+    } else {
+        haystack.contains(needle)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // METHOD FUNCTIONS
 ////////////////////////////////////////////////////////////////////////////////
@@ -201,7 +422,7 @@ impl PrimitiveString {
             ));
         }
 
-        let result = string.value.parse::<f64>().is_ok();
+        let result = get_strict_number(&string.value).is_some();
 
         Ok(PrimitiveBoolean::get_literal(result, interval))
     }
@@ -224,7 +445,7 @@ impl PrimitiveString {
             ));
         }
 
-        let result = string.value.parse::<i64>().is_ok();
+        let result = string.value.trim().parse::<i64>().is_ok();
 
         Ok(PrimitiveBoolean::get_literal(result, interval))
     }
@@ -247,7 +468,7 @@ impl PrimitiveString {
             ));
         }
 
-        let result = string.value.parse::<f64>();
+        let result = string.value.trim().parse::<f64>();
 
         match result {
             Ok(_float) if string.value.find('.').is_some() => {
@@ -275,13 +496,70 @@ impl PrimitiveString {
             ));
         }
 
-        let email_regex = Regex::new(r"^[^@]+@[^@]+\.[^@]+$").unwrap();
+        // one '@', a non empty local part and a domain containing a dot, no whitespace
+        let email_regex = Regex::new(r"^[^@\s]+@[^@\s.]+(\.[^@\s.]+)+$").unwrap();
 
         let result = email_regex.is_match(&string.value);
 
         Ok(PrimitiveBoolean::get_literal(result, interval))
     }
 
+    fn is_url(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "is_url() => boolean";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let result = match Url::parse(&string.value) {
+            Ok(url) => {
+                (url.scheme() == "http" || url.scheme() == "https")
+                    && url.host_str().is_some_and(|host| !host.is_empty())
+            }
+            Err(_) => false,
+        };
+
+        Ok(PrimitiveBoolean::get_literal(result, interval))
+    }
+
+    fn is_phone(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "is_phone() => boolean";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        // E.164: an optional '+' followed by 7 to 15 digits, allowing ' ', '-', '.' and
+        // parentheses as separators
+        let phone_regex = Regex::new(r"^\+?[0-9]([ .()-]*[0-9]){6,14}$").unwrap();
+
+        let result = phone_regex.is_match(&string.value);
+
+        Ok(PrimitiveBoolean::get_literal(result, interval))
+    }
+
     fn type_of(
         _string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
@@ -557,7 +835,7 @@ impl PrimitiveString {
         }
     }
 
-    fn decode_html_entities(
+    fn base64_encode(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -566,21 +844,23 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "decode_html_entities() => String";
+        let usage = "base64_encode(alphabet: Optional<\"standard\" || \"urlsafe\">) => String";
 
-        if !args.is_empty() {
+        if args.len() > 1 {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let decoded = html_escape::decode_html_entities(&string.value);
+        let config = get_base64_config(args, &data.context.flow, interval, usage)?;
 
-        Ok(PrimitiveString::get_literal(&decoded, interval))
+        let encoded = base64::encode_config(string.value.as_bytes(), config);
+
+        Ok(PrimitiveString::get_literal(&encoded, interval))
     }
 
-    fn encode_html_entities(
+    fn base64_decode(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -589,23 +869,37 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "encode_html_entities() => String";
+        let usage = "base64_decode(alphabet: Optional<\"standard\" || \"urlsafe\">) => String";
 
-        if !args.is_empty() {
+        if args.len() > 1 {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let decoded = html_escape::encode_text(&string.value);
+        let config = get_base64_config(args, &data.context.flow, interval, usage)?;
 
-        Ok(PrimitiveString::get_literal(&decoded, interval))
+        let decoded = match base64::decode_config(string.value.as_bytes(), config) {
+            Ok(buf) => String::from_utf8(buf),
+            Err(_) => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    format!("[base64_decode] {}", ERROR_STRING_BASE64_INVALID),
+                ))
+            }
+        };
+
+        match decoded {
+            Ok(decoded) => Ok(PrimitiveString::get_literal(&decoded, interval)),
+            Err(_) => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("[base64_decode] {}", ERROR_STRING_BASE64_UTF8),
+            )),
+        }
     }
-}
 
-impl PrimitiveString {
-    fn append(
+    fn format(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -614,40 +908,120 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "append(value: string) => string";
-
-        if args.len() != 1 {
-            return Err(gen_error_info(
-                Position::new(interval, &data.context.flow),
-                format!("usage: {}", usage),
-            ));
-        }
-
-        let mut result = string.value.to_owned();
+        let usage = "format(values: object | ...String) => String";
 
-        let value = match args.get("arg0") {
-            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
-                Literal::get_value::<String>(
+        let named = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveObject => {
+                Some(Literal::get_value::<HashMap<String, Literal>>(
                     &res.primitive,
                     &data.context.flow,
                     interval,
-                    ERROR_STRING_APPEND.to_owned(),
-                )?
-            }
-            _ => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_STRING_APPEND.to_owned(),
-                ));
+                    format!("usage: {}", usage),
+                )?)
             }
+            _ => None,
         };
 
-        result.push_str(value);
+        let mut result = String::with_capacity(string.value.len());
+        let mut chars = string.value.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('{', Some('{')) | ('}', Some('}')) => {
+                    chars.next();
+                    result.push(c);
+                }
+                ('{', _) => {
+                    let mut placeholder = String::new();
+                    let mut closed = false;
+
+                    for c in chars.by_ref() {
+                        if c == '}' {
+                            closed = true;
+                            break;
+                        }
+                        placeholder.push(c);
+                    }
+
+                    if !closed {
+                        return Err(gen_error_info(
+                            Position::new(interval, &data.context.flow),
+                            format!("{} '{{{}'", ERROR_STRING_FORMAT_UNCLOSED, placeholder),
+                        ));
+                    }
+
+                    let value = match placeholder.parse::<usize>() {
+                        Ok(index) => args.get(&format!("arg{}", index)),
+                        Err(_) => named.and_then(|object| object.get(&placeholder)),
+                    };
+
+                    match value {
+                        Some(value) => result.push_str(&value.primitive.to_string()),
+                        None => {
+                            return Err(gen_error_info(
+                                Position::new(interval, &data.context.flow),
+                                format!("{} '{{{}}}'", ERROR_STRING_FORMAT_MISSING, placeholder),
+                            ));
+                        }
+                    }
+                }
+                ('}', _) => {
+                    return Err(gen_error_info(
+                        Position::new(interval, &data.context.flow),
+                        format!("{} '}}'", ERROR_STRING_FORMAT_UNCLOSED),
+                    ));
+                }
+                _ => result.push(c),
+            }
+        }
 
         Ok(PrimitiveString::get_literal(&result, interval))
     }
 
-    fn contains(
+    fn levenshtein(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "levenshtein(value: String) => int";
+
+        let (value, other) =
+            PrimitiveString::get_fuzzy_match_args(string, args, interval, data, usage)?;
+
+        let distance = levenshtein_distance(&value, &other);
+
+        Ok(PrimitiveInt::get_literal(distance as i64, interval))
+    }
+
+    fn similarity(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "similarity(value: String) => float";
+
+        let (value, other) =
+            PrimitiveString::get_fuzzy_match_args(string, args, interval, data, usage)?;
+
+        let max_len = value.len().max(other.len());
+        // two empty strings are identical
+        let similarity = match max_len {
+            0 => 1.0,
+            _ => 1.0 - levenshtein_distance(&value, &other) as f64 / max_len as f64,
+        };
+
+        Ok(PrimitiveFloat::get_literal(similarity, interval))
+    }
+
+    fn hash(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -656,7 +1030,7 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "contains(value: string) => boolean";
+        let usage = "hash(algorithm: String) => String";
 
         if args.len() != 1 {
             return Err(gen_error_info(
@@ -665,29 +1039,27 @@ impl PrimitiveString {
             ));
         }
 
-        let value = match args.get("arg0") {
+        let algo = match args.get("arg0") {
             Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
                 Literal::get_value::<String>(
                     &res.primitive,
                     &data.context.flow,
                     interval,
-                    ERROR_STRING_DO_MATCH.to_owned(),
+                    ERROR_STRING_HASH.to_owned(),
                 )?
             }
             _ => {
                 return Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
-                    ERROR_STRING_DO_MATCH.to_owned(),
+                    ERROR_STRING_HASH.to_owned(),
                 ));
             }
         };
 
-        let result = string.value.contains(value);
-
-        Ok(PrimitiveBoolean::get_literal(result, interval))
+        hash_string(&string.value, algo, &data.context.flow, interval)
     }
 
-    fn contains_regex(
+    fn sha256(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -696,48 +1068,40 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "contains_regex(value: string) => boolean";
+        let usage = "sha256() => String";
 
-        if args.len() != 1 {
+        if !args.is_empty() {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let value = match args.get("arg0") {
-            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
-                Literal::get_value::<String>(
-                    &res.primitive,
-                    &data.context.flow,
-                    interval,
-                    ERROR_STRING_CONTAINS_REGEX.to_owned(),
-                )?
-            }
-            _ => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_STRING_CONTAINS_REGEX.to_owned(),
-                ));
-            }
-        };
+        hash_string(&string.value, "sha256", &data.context.flow, interval)
+    }
 
-        let action = match Regex::new(value) {
-            Ok(res) => res,
-            Err(_) => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_STRING_CONTAINS_REGEX.to_owned(),
-                ));
-            }
-        };
+    fn sha1(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "sha1() => String";
 
-        let result = action.is_match(&string.value);
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
 
-        Ok(PrimitiveBoolean::get_literal(result, interval))
+        hash_string(&string.value, "sha1", &data.context.flow, interval)
     }
 
-    fn replace(
+    fn md5(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -746,49 +1110,19 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "replace(value_to_replace: string, replace_by: string) => string";
+        let usage = "md5() => String";
 
-        if args.len() != 2 {
+        if !args.is_empty() {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let (to_replace, replace_by) = match (args.get("arg0"), args.get("arg1")) {
-            (Some(old), Some(new))
-                if old.primitive.get_type() == PrimitiveType::PrimitiveString
-                    && new.primitive.get_type() == PrimitiveType::PrimitiveString =>
-            {
-                (
-                    Literal::get_value::<String>(
-                        &old.primitive,
-                        &data.context.flow,
-                        interval,
-                        ERROR_STRING_REPLACE.to_owned(),
-                    )?,
-                    Literal::get_value::<String>(
-                        &new.primitive,
-                        &data.context.flow,
-                        interval,
-                        ERROR_STRING_REPLACE.to_owned(),
-                    )?,
-                )
-            }
-            _ => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_STRING_REPLACE.to_owned(),
-                ));
-            }
-        };
-
-        let new_string = string.value.replacen(to_replace, replace_by, 1);
-
-        Ok(PrimitiveString::get_literal(&new_string, interval))
+        hash_string(&string.value, "md5", &data.context.flow, interval)
     }
 
-    fn replace_all(
+    fn url_encode(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -797,49 +1131,22 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "replace_all(value_to_replace: string, replace_by: string) => string";
+        let usage = "url_encode() => String";
 
-        if args.len() != 2 {
+        if !args.is_empty() {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let (to_replace, replace_by) = match (args.get("arg0"), args.get("arg1")) {
-            (Some(old), Some(new))
-                if old.primitive.get_type() == PrimitiveType::PrimitiveString
-                    && new.primitive.get_type() == PrimitiveType::PrimitiveString =>
-            {
-                (
-                    Literal::get_value::<String>(
-                        &old.primitive,
-                        &data.context.flow,
-                        interval,
-                        ERROR_STRING_REPLACE_ALL.to_owned(),
-                    )?,
-                    Literal::get_value::<String>(
-                        &new.primitive,
-                        &data.context.flow,
-                        interval,
-                        ERROR_STRING_REPLACE_ALL.to_owned(),
-                    )?,
-                )
-            }
-            _ => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_STRING_REPLACE_ALL.to_owned(),
-                ));
-            }
-        };
-
-        let new_string = string.value.replace(to_replace, replace_by);
+        // every byte outside of the RFC 3986 unreserved set is percent encoded
+        let encoded: String = urlencoding::encode(&string.value).into_owned();
 
-        Ok(PrimitiveString::get_literal(&new_string, interval))
+        Ok(PrimitiveString::get_literal(&encoded, interval))
     }
 
-    fn replace_regex(
+    fn url_decode(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -848,99 +1155,41 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "replace_regex(regex: string, replace_by: string) => string";
+        let usage = "url_decode(mode: Optional<\"form\">) => String";
 
-        if args.len() != 2 {
-            return Err(gen_error_info(
-                Position::new(interval, &data.context.flow),
-                format!("usage: {}", usage),
-            ));
-        }
-
-        let (regex, replace_by) = match (args.get("arg0"), args.get("arg1")) {
-            (Some(old), Some(new))
-                if old.primitive.get_type() == PrimitiveType::PrimitiveString
-                    && new.primitive.get_type() == PrimitiveType::PrimitiveString =>
-            {
-                (
-                    Literal::get_value::<String>(
-                        &old.primitive,
-                        &data.context.flow,
-                        interval,
-                        ERROR_STRING_REPLACE_REGEX.to_owned(),
-                    )?,
-                    Literal::get_value::<String>(
-                        &new.primitive,
-                        &data.context.flow,
-                        interval,
-                        ERROR_STRING_REPLACE_REGEX.to_owned(),
-                    )?,
-                )
-            }
+        let form = match args.get("arg0") {
+            None if args.is_empty() => false,
+            Some(lit) if args.len() == 1 && lit.primitive.to_string() == "form" => true,
             _ => {
                 return Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
-                    ERROR_STRING_REPLACE_REGEX.to_owned(),
-                ));
-            }
-        };
-
-        let reg = match Regex::new(regex) {
-            Ok(res) => res,
-            Err(_) => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_STRING_REPLACE_REGEX.to_owned(),
+                    format!("usage: {}", usage),
                 ));
             }
         };
 
-        let new_string = reg.replace_all(&string.value, replace_by);
-
-        Ok(PrimitiveString::get_literal(&new_string, interval))
-    }
-
-    fn ends_with(
-        string: &mut PrimitiveString,
-        args: &HashMap<String, Literal>,
-        _additional_info: &Option<HashMap<String, Literal>>,
-        interval: Interval,
-        data: &mut Data,
-        _msg_data: &mut MessageData,
-        _sender: &Option<mpsc::Sender<MSG>>,
-    ) -> Result<Literal, ErrorInfo> {
-        let usage = "ends_with(value: string) => boolean";
-
-        if args.len() != 1 {
+        if !is_valid_percent_encoding(&string.value) {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
-                format!("usage: {}", usage),
+                format!("[url_decode] {}", ERROR_STRING_URL_DECODE),
             ));
         }
 
-        let value = match args.get("arg0") {
-            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
-                Literal::get_value::<String>(
-                    &res.primitive,
-                    &data.context.flow,
-                    interval,
-                    ERROR_STRING_CONTAINS.to_owned(),
-                )?
-            }
-            _ => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_STRING_END_WITH.to_owned(),
-                ));
-            }
+        let value = match form {
+            true => string.value.replace('+', " "),
+            false => string.value.to_owned(),
         };
 
-        let result = string.value.ends_with(value);
-
-        Ok(PrimitiveBoolean::get_literal(result, interval))
+        match urlencoding::decode(&value) {
+            Ok(decoded) => Ok(PrimitiveString::get_literal(&decoded, interval)),
+            Err(_) => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("[url_decode] {}", ERROR_STRING_URL_DECODE),
+            )),
+        }
     }
 
-    fn ends_with_regex(
+    fn decode_html_entities(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -949,52 +1198,21 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "ends_with_regex(value: string) => boolean";
+        let usage = "decode_html_entities() => String";
 
-        if args.len() != 1 {
+        if !args.is_empty() {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let value = match args.get("arg0") {
-            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
-                Literal::get_value::<String>(
-                    &res.primitive,
-                    &data.context.flow,
-                    interval,
-                    ERROR_STRING_END_WITH_REGEX.to_owned(),
-                )?
-            }
-            _ => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_STRING_END_WITH_REGEX.to_owned(),
-                ));
-            }
-        };
-
-        let action = match Regex::new(value) {
-            Ok(res) => res,
-            Err(_) => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_STRING_END_WITH_REGEX.to_owned(),
-                ));
-            }
-        };
-
-        for key in action.find_iter(&string.value) {
-            if key.end() == string.value.len() {
-                return Ok(PrimitiveBoolean::get_literal(true, interval));
-            }
-        }
+        let decoded = html_escape::decode_html_entities(&string.value);
 
-        Ok(PrimitiveBoolean::get_literal(false, interval))
+        Ok(PrimitiveString::get_literal(&decoded, interval))
     }
 
-    fn from_json(
+    fn encode_html_entities(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1003,7 +1221,7 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "from_json() => object";
+        let usage = "encode_html_entities() => String";
 
         if !args.is_empty() {
             return Err(gen_error_info(
@@ -1012,20 +1230,12 @@ impl PrimitiveString {
             ));
         }
 
-        let object = match serde_json::from_str(&string.value) {
-            Ok(result) => result,
-            Err(_) => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_STRING_FROM_JSON.to_owned(),
-                ));
-            }
-        };
+        let decoded = html_escape::encode_text(&string.value);
 
-        json_to_literal(&object, interval, &data.context.flow)
+        Ok(PrimitiveString::get_literal(&decoded, interval))
     }
 
-    fn is_empty(
+    fn html_escape(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1034,21 +1244,22 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "is_empty() => boolean";
+        let usage = "html_escape() => String";
 
-        if args.len() != 1 {
+        if !args.is_empty() {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let result = string.value.is_empty();
+        // escape & < > " ' so the value is safe both as text and inside attributes
+        let escaped = html_escape::encode_quoted_attribute(&string.value);
 
-        Ok(PrimitiveBoolean::get_literal(result, interval))
+        Ok(PrimitiveString::get_literal(&escaped, interval))
     }
 
-    fn length(
+    fn html_unescape(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1057,7 +1268,7 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "length() => int";
+        let usage = "html_unescape() => String";
 
         if !args.is_empty() {
             return Err(gen_error_info(
@@ -1066,12 +1277,15 @@ impl PrimitiveString {
             ));
         }
 
-        let result = string.value.len();
+        // malformed or unknown entities are kept as is
+        let unescaped = html_escape::decode_html_entities(&string.value);
 
-        Ok(PrimitiveInt::get_literal(result as i64, interval))
+        Ok(PrimitiveString::get_literal(&unescaped, interval))
     }
+}
 
-    fn do_match(
+impl PrimitiveString {
+    fn append(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1080,7 +1294,7 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "match(value: string>) => array";
+        let usage = "append(value: string) => string";
 
         if args.len() != 1 {
             return Err(gen_error_info(
@@ -1089,7 +1303,7 @@ impl PrimitiveString {
             ));
         }
 
-        let mut vector: Vec<Literal> = Vec::new();
+        let mut result = string.value.to_owned();
 
         let value = match args.get("arg0") {
             Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
@@ -1097,29 +1311,23 @@ impl PrimitiveString {
                     &res.primitive,
                     &data.context.flow,
                     interval,
-                    ERROR_STRING_DO_MATCH.to_owned(),
+                    ERROR_STRING_APPEND.to_owned(),
                 )?
             }
             _ => {
                 return Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
-                    ERROR_STRING_DO_MATCH.to_owned(),
+                    ERROR_STRING_APPEND.to_owned(),
                 ));
             }
         };
 
-        for result in string.value.matches(value) {
-            vector.push(PrimitiveString::get_literal(result, interval));
-        }
-
-        if vector.is_empty() {
-            return Ok(PrimitiveNull::get_literal(interval));
-        }
+        result.push_str(value);
 
-        Ok(PrimitiveArray::get_literal(&vector, interval))
+        Ok(PrimitiveString::get_literal(&result, interval))
     }
 
-    fn do_match_regex(
+    fn contains(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1128,61 +1336,41 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "match_regex(value: string>) => array";
+        let usage = "contains(value: string, case_insensitive: Optional<boolean>) => boolean";
 
-        if args.len() != 1 {
+        if args.is_empty() || args.len() > 2 {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let mut s: &str = &string.value;
-        let mut vector: Vec<Literal> = Vec::new();
-
         let value = match args.get("arg0") {
             Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
                 Literal::get_value::<String>(
                     &res.primitive,
                     &data.context.flow,
                     interval,
-                    ERROR_STRING_MATCH_REGEX.to_owned(),
+                    ERROR_STRING_CONTAINS.to_owned(),
                 )?
             }
             _ => {
                 return Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
-                    ERROR_STRING_MATCH_REGEX.to_owned(),
+                    ERROR_STRING_CONTAINS.to_owned(),
                 ));
             }
         };
 
-        let action = match Regex::new(value) {
-            Ok(res) => res,
-            Err(_) => {
-                return Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_STRING_VALID_REGEX.to_owned(),
-                ));
-            }
-        };
+        let case_insensitive =
+            get_case_insensitive_flag(args, &data.context.flow, interval, usage)?;
 
-        while let Some(result) = action.find(&s) {
-            vector.push(PrimitiveString::get_literal(
-                &s[result.start()..result.end()],
-                interval,
-            ));
-            s = &s[result.end()..];
-        }
+        let result = contains_str(&string.value, value, case_insensitive);
 
-        if vector.is_empty() {
-            return Ok(PrimitiveNull::get_literal(interval));
-        }
-
-        Ok(PrimitiveArray::get_literal(&vector, interval))
+        Ok(PrimitiveBoolean::get_literal(result, interval))
     }
 
-    fn starts_with(
+    fn contains_any(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1191,38 +1379,39 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "starts_with(value: string) => boolean";
+        let usage =
+            "contains_any(values: array<string>, case_insensitive: Optional<boolean>) => boolean";
 
-        if args.len() != 1 {
+        if args.is_empty() || args.len() > 2 {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let value = match args.get("arg0") {
-            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
-                Literal::get_value::<String>(
-                    &res.primitive,
-                    &data.context.flow,
-                    interval,
-                    ERROR_STRING_START_WITH.to_owned(),
-                )?
+        let values = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveArray => {
+                get_string_list(res, &data.context.flow, interval, ERROR_STRING_CONTAINS_ANY)?
             }
             _ => {
                 return Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
-                    ERROR_STRING_START_WITH.to_owned(),
+                    ERROR_STRING_CONTAINS_ANY.to_owned(),
                 ));
             }
         };
 
-        let result = string.value.starts_with(value);
+        let case_insensitive =
+            get_case_insensitive_flag(args, &data.context.flow, interval, usage)?;
+
+        let result = values
+            .iter()
+            .any(|value| contains_str(&string.value, value, case_insensitive));
 
         Ok(PrimitiveBoolean::get_literal(result, interval))
     }
 
-    fn starts_with_regex(
+    fn contains_regex(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1231,7 +1420,7 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "starts_with_regex(value: string) => boolean";
+        let usage = "contains_regex(value: string) => boolean";
 
         if args.len() != 1 {
             return Err(gen_error_info(
@@ -1246,13 +1435,13 @@ impl PrimitiveString {
                     &res.primitive,
                     &data.context.flow,
                     interval,
-                    ERROR_STRING_START_WITH_REGEX.to_owned(),
+                    ERROR_STRING_CONTAINS_REGEX.to_owned(),
                 )?
             }
             _ => {
                 return Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
-                    ERROR_STRING_START_WITH_REGEX.to_owned(),
+                    ERROR_STRING_CONTAINS_REGEX.to_owned(),
                 ));
             }
         };
@@ -1262,21 +1451,17 @@ impl PrimitiveString {
             Err(_) => {
                 return Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
-                    ERROR_STRING_VALID_REGEX.to_owned(),
+                    ERROR_STRING_CONTAINS_REGEX.to_owned(),
                 ));
             }
         };
 
-        if let Some(res) = action.find(&string.value) {
-            if res.start() == 0 {
-                return Ok(PrimitiveBoolean::get_literal(true, interval));
-            }
-        }
+        let result = action.is_match(&string.value);
 
-        Ok(PrimitiveBoolean::get_literal(false, interval))
+        Ok(PrimitiveBoolean::get_literal(result, interval))
     }
 
-    fn to_lowercase(
+    fn index_of(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1285,20 +1470,22 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "to_lowercase() => string";
+        let usage = "index_of(value: string, start: Optional<Integer>) => int";
 
-        if !args.is_empty() {
-            return Err(gen_error_info(
-                Position::new(interval, &data.context.flow),
-                format!("usage: {}", usage),
-            ));
-        }
+        let (value, start) =
+            PrimitiveString::get_index_of_args(string, args, interval, data, usage)?;
 
-        let s = &string.value;
-        Ok(PrimitiveString::get_literal(&s.to_lowercase(), interval))
+        let byte_start = grapheme_to_byte_index(&string.value, start);
+
+        let result = match string.value[byte_start..].find(value.as_str()) {
+            Some(index) => byte_to_grapheme_index(&string.value, byte_start + index) as i64,
+            None => -1,
+        };
+
+        Ok(PrimitiveInt::get_literal(result, interval))
     }
 
-    fn to_uppercase(
+    fn last_index_of(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1307,20 +1494,88 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "to_uppercase() => string";
+        let usage = "last_index_of(value: string, start: Optional<Integer>) => int";
 
-        if !args.is_empty() {
+        let (value, start) = match args.get("arg1") {
+            Some(_) => PrimitiveString::get_index_of_args(string, args, interval, data, usage)?,
+            None => {
+                let (value, _) =
+                    PrimitiveString::get_index_of_args(string, args, interval, data, usage)?;
+
+                (value, string.value.graphemes(true).count())
+            }
+        };
+
+        // a match may begin at most at 'start', so it can end at 'start' + value length
+        let byte_end = grapheme_to_byte_index(&string.value, start + value.graphemes(true).count());
+
+        let result = match string.value[..byte_end].rfind(value.as_str()) {
+            Some(index) => byte_to_grapheme_index(&string.value, index) as i64,
+            None => -1,
+        };
+
+        Ok(PrimitiveInt::get_literal(result, interval))
+    }
+
+    fn replace(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "replace(value_to_replace: string, replace_by: string) => string";
+
+        if args.len() != 2 {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let s = &string.value;
-        Ok(PrimitiveString::get_literal(&s.to_uppercase(), interval))
+        let (to_replace, replace_by) = match (args.get("arg0"), args.get("arg1")) {
+            (Some(old), Some(new))
+                if old.primitive.get_type() == PrimitiveType::PrimitiveString
+                    && new.primitive.get_type() == PrimitiveType::PrimitiveString =>
+            {
+                (
+                    Literal::get_value::<String>(
+                        &old.primitive,
+                        &data.context.flow,
+                        interval,
+                        ERROR_STRING_REPLACE.to_owned(),
+                    )?,
+                    Literal::get_value::<String>(
+                        &new.primitive,
+                        &data.context.flow,
+                        interval,
+                        ERROR_STRING_REPLACE.to_owned(),
+                    )?,
+                )
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_REPLACE.to_owned(),
+                ));
+            }
+        };
+
+        if to_replace.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("[{}] {}", "replace", ERROR_STRING_REPLACE_EMPTY),
+            ));
+        }
+
+        let new_string = string.value.replacen(to_replace, replace_by, 1);
+
+        Ok(PrimitiveString::get_literal(&new_string, interval))
     }
 
-    fn capitalize(
+    fn replace_all(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1329,27 +1584,56 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "capitalize() => string";
+        let usage = "replace_all(value_to_replace: string, replace_by: string) => string";
 
-        if !args.is_empty() {
+        if args.len() != 2 {
             return Err(gen_error_info(
                 Position::new(interval, &data.context.flow),
                 format!("usage: {}", usage),
             ));
         }
 
-        let s = &string.value;
-
-        let mut c = s.chars();
-        let string = match c.next() {
-            None => String::new(),
-            Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+        let (to_replace, replace_by) = match (args.get("arg0"), args.get("arg1")) {
+            (Some(old), Some(new))
+                if old.primitive.get_type() == PrimitiveType::PrimitiveString
+                    && new.primitive.get_type() == PrimitiveType::PrimitiveString =>
+            {
+                (
+                    Literal::get_value::<String>(
+                        &old.primitive,
+                        &data.context.flow,
+                        interval,
+                        ERROR_STRING_REPLACE_ALL.to_owned(),
+                    )?,
+                    Literal::get_value::<String>(
+                        &new.primitive,
+                        &data.context.flow,
+                        interval,
+                        ERROR_STRING_REPLACE_ALL.to_owned(),
+                    )?,
+                )
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_REPLACE_ALL.to_owned(),
+                ));
+            }
         };
 
-        Ok(PrimitiveString::get_literal(&string, interval))
+        if to_replace.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("[{}] {}", "replace_all", ERROR_STRING_REPLACE_EMPTY),
+            ));
+        }
+
+        let new_string = string.value.replace(to_replace, replace_by);
+
+        Ok(PrimitiveString::get_literal(&new_string, interval))
     }
 
-    fn slice(
+    fn replace_regex(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1358,108 +1642,59 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "slice(start: Integer, end: Optional<Integer>) => string";
-        let text_vec = string.value.chars().collect::<Vec<_>>();
-        let len = text_vec.len();
-
-        match args.len() {
-            1 => match args.get("arg0") {
-                Some(literal) => {
-                    let mut int_start = Literal::get_value::<i64>(
-                        &literal.primitive,
-                        &data.context.flow,
-                        literal.interval,
-                        ERROR_SLICE_ARG_INT.to_owned(),
-                    )?
-                    .to_owned();
-
-                    if int_start < 0 {
-                        int_start = len as i64 + int_start;
-                    }
-
-                    let start = match int_start {
-                        value if value >= 0 && (value as usize) < len => value as usize,
-                        _ => {
-                            return Err(gen_error_info(
-                                Position::new(interval, &data.context.flow),
-                                ERROR_SLICE_ARG_LEN.to_owned(),
-                            ))
-                        }
-                    };
+        let usage = "replace_regex(regex: string, replace_by: string) => string";
 
-                    let value = text_vec[start..].iter().cloned().collect::<String>();
+        if args.len() != 2 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
 
-                    Ok(PrimitiveString::get_literal(&value, interval))
-                }
-                _ => Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_SLICE_ARG_INT.to_owned(),
-                )),
-            },
-            2 => match (args.get("arg0"), args.get("arg1")) {
-                (Some(literal_start), Some(literal_end)) => {
-                    let mut int_start = Literal::get_value::<i64>(
-                        &literal_start.primitive,
+        let (regex, replace_by) = match (args.get("arg0"), args.get("arg1")) {
+            (Some(old), Some(new))
+                if old.primitive.get_type() == PrimitiveType::PrimitiveString
+                    && new.primitive.get_type() == PrimitiveType::PrimitiveString =>
+            {
+                (
+                    Literal::get_value::<String>(
+                        &old.primitive,
                         &data.context.flow,
-                        literal_start.interval,
-                        ERROR_SLICE_ARG_INT.to_owned(),
-                    )?
-                    .to_owned();
-                    let mut int_end = Literal::get_value::<i64>(
-                        &literal_end.primitive,
+                        interval,
+                        ERROR_STRING_REPLACE_REGEX.to_owned(),
+                    )?,
+                    Literal::get_value::<String>(
+                        &new.primitive,
                         &data.context.flow,
-                        literal_end.interval,
-                        ERROR_SLICE_ARG_INT.to_owned(),
-                    )?
-                    .to_owned();
-
-                    if int_start < 0 {
-                        int_start = len as i64 + int_start;
-                    }
+                        interval,
+                        ERROR_STRING_REPLACE_REGEX.to_owned(),
+                    )?,
+                )
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_REPLACE_REGEX.to_owned(),
+                ));
+            }
+        };
 
-                    if int_end.is_negative() {
-                        int_end = len as i64 + int_end;
-                    }
-                    if int_end < int_start {
-                        return Err(gen_error_info(
-                            Position::new(interval, &data.context.flow),
-                            ERROR_SLICE_ARG2.to_owned(),
-                        ));
-                    }
+        let reg = match Regex::new(regex) {
+            Ok(res) => res,
+            Err(_) => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_REPLACE_REGEX.to_owned(),
+                ));
+            }
+        };
 
-                    let (start, end) = match (int_start, int_end) {
-                        (start, end)
-                            if int_start >= 0
-                                && end >= 0
-                                && (start as usize) < len
-                                && (end as usize) <= len =>
-                        {
-                            (start as usize, end as usize)
-                        }
-                        _ => {
-                            return Err(gen_error_info(
-                                Position::new(interval, &data.context.flow),
-                                ERROR_SLICE_ARG_LEN.to_owned(),
-                            ))
-                        }
-                    };
-                    let value = text_vec[start..end].iter().cloned().collect::<String>();
+        let new_string = reg.replace_all(&string.value, replace_by);
 
-                    Ok(PrimitiveString::get_literal(&value, interval))
-                }
-                _ => Err(gen_error_info(
-                    Position::new(interval, &data.context.flow),
-                    ERROR_SLICE_ARG_INT.to_owned(),
-                )),
-            },
-            _ => Err(gen_error_info(
-                Position::new(interval, &data.context.flow),
-                format!("usage: {}", usage),
-            )),
-        }
+        Ok(PrimitiveString::get_literal(&new_string, interval))
     }
 
-    fn split(
+    fn ends_with(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1468,7 +1703,7 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "string(separator: string) => array";
+        let usage = "ends_with(value: string || array<string>) => boolean";
 
         if args.len() != 1 {
             return Err(gen_error_info(
@@ -1477,28 +1712,975 @@ impl PrimitiveString {
             ));
         }
 
-        let separator = match args.get("arg0") {
-            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
-                Literal::get_value::<String>(
-                    &res.primitive,
-                    &data.context.flow,
-                    interval,
-                    ERROR_STRING_SPLIT.to_owned(),
-                )?
+        let values = match args.get("arg0") {
+            Some(res) => {
+                get_string_candidates(res, &data.context.flow, interval, ERROR_STRING_END_WITH)?
+            }
+            None => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_END_WITH.to_owned(),
+                ));
+            }
+        };
+
+        let result = values
+            .iter()
+            .any(|value| string.value.ends_with(value.as_str()));
+
+        Ok(PrimitiveBoolean::get_literal(result, interval))
+    }
+
+    fn ends_with_regex(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "ends_with_regex(value: string) => boolean";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let value = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_END_WITH_REGEX.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_END_WITH_REGEX.to_owned(),
+                ));
+            }
+        };
+
+        let action = match Regex::new(value) {
+            Ok(res) => res,
+            Err(_) => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_END_WITH_REGEX.to_owned(),
+                ));
+            }
+        };
+
+        for key in action.find_iter(&string.value) {
+            if key.end() == string.value.len() {
+                return Ok(PrimitiveBoolean::get_literal(true, interval));
+            }
+        }
+
+        Ok(PrimitiveBoolean::get_literal(false, interval))
+    }
+
+    fn from_json(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "from_json() => object";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let object = match serde_json::from_str(&string.value) {
+            Ok(result) => result,
+            Err(_) => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_FROM_JSON.to_owned(),
+                ));
+            }
+        };
+
+        json_to_literal(&object, interval, &data.context.flow)
+    }
+
+    fn is_empty(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "is_empty() => boolean";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let result = string.value.is_empty();
+
+        Ok(PrimitiveBoolean::get_literal(result, interval))
+    }
+
+    fn length(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "length() => int";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        // grapheme clusters, so that length() agrees with slice() and char_at()
+        let result = get_length(&*string)
+            .map_err(|err| gen_error_info(Position::new(interval, &data.context.flow), err))?;
+
+        Ok(PrimitiveInt::get_literal(result as i64, interval))
+    }
+
+    fn byte_length(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "byte_length() => int";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let result = string.value.len();
+
+        Ok(PrimitiveInt::get_literal(result as i64, interval))
+    }
+
+    fn do_match(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "match(value: string>) => array";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let mut vector: Vec<Literal> = Vec::new();
+
+        let value = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_DO_MATCH.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_DO_MATCH.to_owned(),
+                ));
+            }
+        };
+
+        for result in string.value.matches(value) {
+            vector.push(PrimitiveString::get_literal(result, interval));
+        }
+
+        if vector.is_empty() {
+            return Ok(PrimitiveNull::get_literal(interval));
+        }
+
+        Ok(PrimitiveArray::get_literal(&vector, interval))
+    }
+
+    fn do_match_regex(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "match_regex(value: string>) => array";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let mut s: &str = &string.value;
+        let mut vector: Vec<Literal> = Vec::new();
+
+        let value = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_MATCH_REGEX.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_MATCH_REGEX.to_owned(),
+                ));
+            }
+        };
+
+        let action = compile_regex(value, &data.context.flow, interval)?;
+
+        while let Some(result) = action.find(&s) {
+            vector.push(PrimitiveString::get_literal(
+                &s[result.start()..result.end()],
+                interval,
+            ));
+            s = &s[result.end()..];
+        }
+
+        if vector.is_empty() {
+            return Ok(PrimitiveNull::get_literal(interval));
+        }
+
+        Ok(PrimitiveArray::get_literal(&vector, interval))
+    }
+
+    fn is_match(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "is_match(pattern: string) => boolean";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let value = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_IS_MATCH.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_IS_MATCH.to_owned(),
+                ));
+            }
+        };
+
+        let action = compile_regex(value, &data.context.flow, interval)?;
+
+        Ok(PrimitiveBoolean::get_literal(
+            action.is_match(&string.value),
+            interval,
+        ))
+    }
+
+    // returns the full match followed by every capture group of the first match,
+    // groups that did not participate are null and no match at all gives null
+    fn capture_regex(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "capture_regex(pattern: string) => array";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let value = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_CAPTURE_REGEX.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_CAPTURE_REGEX.to_owned(),
+                ));
+            }
+        };
+
+        let action = compile_regex(value, &data.context.flow, interval)?;
+
+        let captures = match action.captures(&string.value) {
+            Some(captures) => captures,
+            None => return Ok(PrimitiveNull::get_literal(interval)),
+        };
+
+        let vector = captures
+            .iter()
+            .map(|group| match group {
+                Some(group) => PrimitiveString::get_literal(group.as_str(), interval),
+                None => PrimitiveNull::get_literal(interval),
+            })
+            .collect::<Vec<Literal>>();
+
+        Ok(PrimitiveArray::get_literal(&vector, interval))
+    }
+
+    fn starts_with(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "starts_with(value: string || array<string>) => boolean";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let values = match args.get("arg0") {
+            Some(res) => {
+                get_string_candidates(res, &data.context.flow, interval, ERROR_STRING_START_WITH)?
+            }
+            None => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_START_WITH.to_owned(),
+                ));
+            }
+        };
+
+        let result = values
+            .iter()
+            .any(|value| string.value.starts_with(value.as_str()));
+
+        Ok(PrimitiveBoolean::get_literal(result, interval))
+    }
+
+    fn starts_with_regex(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "starts_with_regex(value: string) => boolean";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let value = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_START_WITH_REGEX.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_START_WITH_REGEX.to_owned(),
+                ));
+            }
+        };
+
+        let action = match Regex::new(value) {
+            Ok(res) => res,
+            Err(_) => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_VALID_REGEX.to_owned(),
+                ));
+            }
+        };
+
+        if let Some(res) = action.find(&string.value) {
+            if res.start() == 0 {
+                return Ok(PrimitiveBoolean::get_literal(true, interval));
+            }
+        }
+
+        Ok(PrimitiveBoolean::get_literal(false, interval))
+    }
+
+    fn to_lowercase(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_lowercase() => string";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let s = &string.value;
+        Ok(PrimitiveString::get_literal(&s.to_lowercase(), interval))
+    }
+
+    fn to_uppercase(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_uppercase() => string";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let s = &string.value;
+        Ok(PrimitiveString::get_literal(&s.to_uppercase(), interval))
+    }
+
+    fn capitalize(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "capitalize() => string";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let string = capitalize_str(&string.value);
+
+        Ok(PrimitiveString::get_literal(&string, interval))
+    }
+
+    fn title_case(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "title_case() => string";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let mut word_start = true;
+        let string = string
+            .value
+            .graphemes(true)
+            .map(|grapheme| {
+                let result = match word_start {
+                    true => grapheme.to_uppercase(),
+                    false => grapheme.to_owned(),
+                };
+                word_start = grapheme.chars().all(char::is_whitespace);
+
+                result
+            })
+            .collect::<String>();
+
+        Ok(PrimitiveString::get_literal(&string, interval))
+    }
+
+    fn chars(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "chars() => array";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let vector = string
+            .value
+            .graphemes(true)
+            .map(|grapheme| PrimitiveString::get_literal(grapheme, interval))
+            .collect::<Vec<Literal>>();
+
+        Ok(PrimitiveArray::get_literal(&vector, interval))
+    }
+
+    fn lines(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "lines() => array";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        // split on \n and \r\n, a trailing line terminator does not add an empty line
+        let vector = string
+            .value
+            .lines()
+            .map(|line| PrimitiveString::get_literal(line, interval))
+            .collect::<Vec<Literal>>();
+
+        Ok(PrimitiveArray::get_literal(&vector, interval))
+    }
+
+    fn char_at(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "char_at(index: Integer) => string";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let index = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveInt => {
+                *Literal::get_value::<i64>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_CHAR_AT.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_CHAR_AT.to_owned(),
+                ));
+            }
+        };
+
+        let graphemes = string.value.graphemes(true).collect::<Vec<&str>>();
+        let len = graphemes.len() as i64;
+        let position = if index < 0 { len + index } else { index };
+
+        match graphemes.get(position as usize) {
+            Some(grapheme) if position >= 0 => Ok(PrimitiveString::get_literal(grapheme, interval)),
+            _ => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!(
+                    "[char_at] {} (index: {}, length: {})",
+                    ERROR_STRING_INDEX_OUT_OF_RANGE, index, len
+                ),
+            )),
+        }
+    }
+
+    fn reverse(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "reverse() => string";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let reversed = string.value.graphemes(true).rev().collect::<String>();
+
+        Ok(PrimitiveString::get_literal(&reversed, interval))
+    }
+
+    fn slice(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "slice(start: Integer, end: Optional<Integer>) => string";
+        let text_vec = string.value.graphemes(true).collect::<Vec<_>>();
+        let len = text_vec.len();
+
+        if args.is_empty() || args.len() > 2 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let int_start = match args.get("arg0") {
+            Some(literal) => *Literal::get_value::<i64>(
+                &literal.primitive,
+                &data.context.flow,
+                literal.interval,
+                ERROR_SLICE_ARG_INT.to_owned(),
+            )?,
+            None => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_SLICE_ARG_INT.to_owned(),
+                ))
+            }
+        };
+
+        let int_end = match args.get("arg1") {
+            Some(literal) => *Literal::get_value::<i64>(
+                &literal.primitive,
+                &data.context.flow,
+                literal.interval,
+                ERROR_SLICE_ARG_INT.to_owned(),
+            )?,
+            None => len as i64,
+        };
+
+        let (start, end) = get_slice_range(int_start, int_end, len);
+        let value = text_vec[start..end].concat();
+
+        Ok(PrimitiveString::get_literal(&value, interval))
+    }
+
+    fn pad_start(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "pad_start(width: Integer, pad: Optional<string>) => string";
+
+        let padding = PrimitiveString::get_padding(string, args, interval, data, usage)?;
+
+        Ok(PrimitiveString::get_literal(
+            &format!("{}{}", padding, string.value),
+            interval,
+        ))
+    }
+
+    fn pad_end(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "pad_end(width: Integer, pad: Optional<string>) => string";
+
+        let padding = PrimitiveString::get_padding(string, args, interval, data, usage)?;
+
+        Ok(PrimitiveString::get_literal(
+            &format!("{}{}", string.value, padding),
+            interval,
+        ))
+    }
+
+    fn repeat(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "repeat(count: Integer) => string";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let count = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveInt => {
+                *Literal::get_value::<i64>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_REPEAT.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_REPEAT.to_owned(),
+                ));
+            }
+        };
+
+        if count.is_negative() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                ERROR_STRING_REPEAT.to_owned(),
+            ));
+        }
+
+        match string.value.len().checked_mul(count as usize) {
+            Some(size) if size <= MAX_REPEAT_SIZE => Ok(PrimitiveString::get_literal(
+                &string.value.repeat(count as usize),
+                interval,
+            )),
+            _ => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!(
+                    "[repeat] {} {} bytes",
+                    ERROR_STRING_REPEAT_SIZE, MAX_REPEAT_SIZE
+                ),
+            )),
+        }
+    }
+
+    fn split(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "split(separator: string) => array";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let separator = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_SPLIT.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_SPLIT.to_owned(),
+                ));
+            }
+        };
+
+        // an empty separator splits the string into its characters
+        if separator.is_empty() {
+            let vector = PrimitiveString::get_array_char(string.value.to_owned(), interval);
+
+            return Ok(PrimitiveArray::get_literal(&vector, interval));
+        }
+
+        let mut vector: Vec<Literal> = Vec::new();
+
+        for result in string.value.split(separator) {
+            vector.push(PrimitiveString::get_literal(result, interval));
+        }
+
+        Ok(PrimitiveArray::get_literal(&vector, interval))
+    }
+
+    fn split_regex(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "split_regex(pattern: string, max_splits: Optional<Integer>) => array";
+
+        if args.is_empty() || args.len() > 2 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let pattern = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_SPLIT_REGEX.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_SPLIT_REGEX.to_owned(),
+                ));
+            }
+        };
+
+        let max_splits = match args.get("arg1") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveInt => {
+                match *Literal::get_value::<i64>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_SPLIT_REGEX.to_owned(),
+                )? {
+                    limit if limit >= 0 => Some(limit as usize),
+                    _ => {
+                        return Err(gen_error_info(
+                            Position::new(interval, &data.context.flow),
+                            ERROR_STRING_SPLIT_REGEX.to_owned(),
+                        ));
+                    }
+                }
             }
-            _ => {
+            Some(_) => {
                 return Err(gen_error_info(
                     Position::new(interval, &data.context.flow),
-                    ERROR_ARRAY_JOIN.to_owned(),
+                    ERROR_STRING_SPLIT_REGEX.to_owned(),
                 ));
             }
+            None => None,
         };
 
-        let mut vector: Vec<Literal> = Vec::new();
-
-        for result in string.value.split(separator) {
-            vector.push(PrimitiveString::get_literal(result, interval));
-        }
+        let action = compile_regex(pattern, &data.context.flow, interval)?;
+
+        let vector = match max_splits {
+            Some(max_splits) => action
+                .splitn(&string.value, max_splits + 1)
+                .map(|part| PrimitiveString::get_literal(part, interval))
+                .collect::<Vec<Literal>>(),
+            None => action
+                .split(&string.value)
+                .map(|part| PrimitiveString::get_literal(part, interval))
+                .collect::<Vec<Literal>>(),
+        };
 
         Ok(PrimitiveArray::get_literal(&vector, interval))
     }
@@ -1525,7 +2707,7 @@ impl PrimitiveString {
         Ok(PrimitiveString::get_literal(&s.trim(), interval))
     }
 
-    fn trim_left(
+    fn trim_start(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1534,7 +2716,7 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "trim_left() => string";
+        let usage = "trim_start() => string";
 
         if !args.is_empty() {
             return Err(gen_error_info(
@@ -1547,7 +2729,7 @@ impl PrimitiveString {
         Ok(PrimitiveString::get_literal(&s.trim_start(), interval))
     }
 
-    fn trim_right(
+    fn trim_end(
         string: &mut PrimitiveString,
         args: &HashMap<String, Literal>,
         _additional_info: &Option<HashMap<String, Literal>>,
@@ -1556,7 +2738,7 @@ impl PrimitiveString {
         _msg_data: &mut MessageData,
         _sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        let usage = "trim_right() => string";
+        let usage = "trim_end() => string";
 
         if !args.is_empty() {
             return Err(gen_error_info(
@@ -1568,6 +2750,47 @@ impl PrimitiveString {
         let s = &string.value;
         Ok(PrimitiveString::get_literal(&s.trim_end(), interval))
     }
+
+    fn trim_matches(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "trim_matches(chars: string) => string";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let chars = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_TRIM_MATCHES.to_owned(),
+                )?
+                .chars()
+                .collect::<Vec<char>>()
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_TRIM_MATCHES.to_owned(),
+                ));
+            }
+        };
+
+        let s = string.value.trim_matches(|c| chars.contains(&c));
+        Ok(PrimitiveString::get_literal(s, interval))
+    }
 }
 
 // memory type can be set tu 'use' because the result of the operation will create a new literal.
@@ -2032,7 +3255,9 @@ impl PrimitiveString {
         msg_data: &mut MessageData,
         sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        if let Ok(int) = string.value.parse::<i64>() {
+        let value = string.value.trim();
+
+        if let Ok(int) = value.parse::<i64>() {
             let mut primitive = PrimitiveInt::new(int);
 
             let (literal, _right) = primitive.do_exec(
@@ -2049,7 +3274,7 @@ impl PrimitiveString {
 
             return Ok(literal);
         }
-        if let Ok(float) = string.value.parse::<f64>() {
+        if let Ok(float) = value.parse::<f64>() {
             let mut primitive = PrimitiveFloat::new(float);
 
             let (literal, _right) = primitive.do_exec(
@@ -2069,7 +3294,10 @@ impl PrimitiveString {
 
         Err(gen_error_info(
             Position::new(interval, &data.context.flow),
-            format!("[{}] {}", "to_int", ERROR_STRING_NUMERIC),
+            format!(
+                "[{}] {}: {:?}",
+                "to_int", ERROR_STRING_NUMERIC, string.value
+            ),
         ))
     }
 
@@ -2082,7 +3310,9 @@ impl PrimitiveString {
         msg_data: &mut MessageData,
         sender: &Option<mpsc::Sender<MSG>>,
     ) -> Result<Literal, ErrorInfo> {
-        if let Ok(int) = string.value.parse::<i64>() {
+        let value = string.value.trim();
+
+        if let Ok(int) = value.parse::<i64>() {
             let mut primitive = PrimitiveInt::new(int);
 
             let (literal, _right) = primitive.do_exec(
@@ -2099,7 +3329,7 @@ impl PrimitiveString {
 
             return Ok(literal);
         }
-        if let Ok(float) = string.value.parse::<f64>() {
+        if let Ok(float) = value.parse::<f64>() {
             let mut primitive = PrimitiveFloat::new(float);
 
             let (literal, _right) = primitive.do_exec(
@@ -2119,9 +3349,322 @@ impl PrimitiveString {
 
         Err(gen_error_info(
             Position::new(interval, &data.context.flow),
-            format!("[{}] {}", "to_float", ERROR_STRING_NUMERIC),
+            format!(
+                "[{}] {}: {:?}",
+                "to_float", ERROR_STRING_NUMERIC, string.value
+            ),
         ))
     }
+
+    fn parse_number(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "parse_number() => number";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        match get_strict_number(&string.value) {
+            Some(number) => Ok(number.get_literal(interval)),
+            None => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!(
+                    "[parse_number] {} (value: '{}')",
+                    ERROR_STRING_PARSE_NUMBER, string.value
+                ),
+            )),
+        }
+    }
+
+    fn to_boolean(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "to_boolean() => boolean";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        match string.value.trim().to_ascii_lowercase().as_str() {
+            "true" => Ok(PrimitiveBoolean::get_literal(true, interval)),
+            "false" => Ok(PrimitiveBoolean::get_literal(false, interval)),
+            _ => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!(
+                    "[to_boolean] {} (value: '{}')",
+                    ERROR_STRING_TO_BOOLEAN, string.value
+                ),
+            )),
+        }
+    }
+
+    fn from_hex(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "from_hex() => int";
+
+        if !args.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let value = string.value.trim();
+        let value = value
+            .strip_prefix("0x")
+            .or_else(|| value.strip_prefix("0X"))
+            .unwrap_or(value);
+
+        match parse_radix(value, 16) {
+            Ok(int) => Ok(PrimitiveInt::get_literal(int, interval)),
+            Err(err) => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("[from_hex] {}", err),
+            )),
+        }
+    }
+
+    fn parse_int(
+        string: &mut PrimitiveString,
+        args: &HashMap<String, Literal>,
+        _additional_info: &Option<HashMap<String, Literal>>,
+        interval: Interval,
+        data: &mut Data,
+        _msg_data: &mut MessageData,
+        _sender: &Option<mpsc::Sender<MSG>>,
+    ) -> Result<Literal, ErrorInfo> {
+        let usage = "parse_int(radix: int) => int";
+
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let radix = match args.get("arg0").and_then(get_number) {
+            Some(Integer::Int(radix)) if (2..=36).contains(&radix) => radix as u32,
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_PARSE_INT_RADIX.to_owned(),
+                ));
+            }
+        };
+
+        match parse_radix(string.value.trim(), radix) {
+            Ok(int) => Ok(PrimitiveInt::get_literal(int, interval)),
+            Err(err) => Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("[parse_int] {}", err),
+            )),
+        }
+    }
+}
+
+impl PrimitiveString {
+    fn get_fuzzy_match_args(
+        string: &PrimitiveString,
+        args: &HashMap<String, Literal>,
+        interval: Interval,
+        data: &Data,
+        usage: &str,
+    ) -> Result<(Vec<char>, Vec<char>), ErrorInfo> {
+        if args.len() != 1 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let other = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_FUZZY_MATCH.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_FUZZY_MATCH.to_owned(),
+                ));
+            }
+        };
+
+        let value: Vec<char> = string.value.chars().collect();
+        let other: Vec<char> = other.chars().collect();
+
+        if value.len() > MAX_FUZZY_MATCH_LEN || other.len() > MAX_FUZZY_MATCH_LEN {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!(
+                    "{} {} characters",
+                    ERROR_STRING_FUZZY_MATCH_SIZE, MAX_FUZZY_MATCH_LEN
+                ),
+            ));
+        }
+
+        Ok((value, other))
+    }
+
+    fn get_index_of_args(
+        string: &PrimitiveString,
+        args: &HashMap<String, Literal>,
+        interval: Interval,
+        data: &Data,
+        usage: &str,
+    ) -> Result<(String, usize), ErrorInfo> {
+        if args.is_empty() || args.len() > 2 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let value = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_INDEX_OF.to_owned(),
+                )?
+                .to_owned()
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_INDEX_OF.to_owned(),
+                ));
+            }
+        };
+
+        let len = string.value.graphemes(true).count();
+
+        let start = match args.get("arg1") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveInt => {
+                let start = Literal::get_value::<i64>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_INDEX_OF.to_owned(),
+                )?;
+
+                get_slice_range(*start, len as i64, len).0
+            }
+            Some(_) => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_INDEX_OF.to_owned(),
+                ));
+            }
+            None => 0,
+        };
+
+        Ok((value, start))
+    }
+
+    fn get_padding(
+        string: &PrimitiveString,
+        args: &HashMap<String, Literal>,
+        interval: Interval,
+        data: &Data,
+        usage: &str,
+    ) -> Result<String, ErrorInfo> {
+        if args.is_empty() || args.len() > 2 {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!("usage: {}", usage),
+            ));
+        }
+
+        let width = match args.get("arg0") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveInt => {
+                *Literal::get_value::<i64>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_PAD.to_owned(),
+                )?
+            }
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_PAD.to_owned(),
+                ));
+            }
+        };
+
+        let pad = match args.get("arg1") {
+            Some(res) if res.primitive.get_type() == PrimitiveType::PrimitiveString => {
+                Literal::get_value::<String>(
+                    &res.primitive,
+                    &data.context.flow,
+                    interval,
+                    ERROR_STRING_PAD.to_owned(),
+                )?
+                .to_owned()
+            }
+            Some(_) => {
+                return Err(gen_error_info(
+                    Position::new(interval, &data.context.flow),
+                    ERROR_STRING_PAD.to_owned(),
+                ));
+            }
+            None => " ".to_owned(),
+        };
+
+        if width.is_negative() || pad.is_empty() {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                ERROR_STRING_PAD.to_owned(),
+            ));
+        }
+
+        if width as usize > MAX_PAD_WIDTH {
+            return Err(gen_error_info(
+                Position::new(interval, &data.context.flow),
+                format!(
+                    "[pad_start/pad_end] {} {} characters",
+                    ERROR_STRING_PAD_WIDTH, MAX_PAD_WIDTH
+                ),
+            ));
+        }
+
+        let len = string.value.graphemes(true).count();
+        let missing = (width as usize).saturating_sub(len);
+
+        Ok(pad.graphemes(true).cycle().take(missing).collect())
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
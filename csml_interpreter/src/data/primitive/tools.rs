@@ -1,16 +1,38 @@
-use crate::data::primitive::{PrimitiveString, PrimitiveType};
-use crate::data::{Literal, Position};
+use crate::data::primitive::{
+    Primitive, PrimitiveArray, PrimitiveFloat, PrimitiveInt, PrimitiveObject, PrimitiveString,
+    PrimitiveType,
+};
+use crate::data::{ast::Interval, Literal, Position};
 use crate::error_format::*;
 
+use unicode_segmentation::UnicodeSegmentation;
+
 ////////////////////////////////////////////////////////////////////////////////
 // DATA STRUCTURE
 ////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Debug, Clone, Copy)]
 pub enum Integer {
     Int(i64),
     Float(f64),
 }
 
+impl Integer {
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            Integer::Int(int) => *int as f64,
+            Integer::Float(float) => *float,
+        }
+    }
+
+    pub fn get_literal(&self, interval: Interval) -> Literal {
+        match self {
+            Integer::Int(int) => PrimitiveInt::get_literal(*int, interval),
+            Integer::Float(float) => PrimitiveFloat::get_literal(*float, interval),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // PUBLIC FUNCTIONS
 ////////////////////////////////////////////////////////////////////////////////
@@ -23,6 +45,232 @@ pub fn get_integer(text: &str) -> Result<Integer, String> {
     }
 }
 
+/// strict parsing of user input: surrounding whitespace and a sign are
+/// accepted, thousands separators, NaN and infinity are not
+pub fn get_strict_number(text: &str) -> Option<Integer> {
+    let text = text.trim();
+
+    let is_numeric = text
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e' | 'E'));
+    if !is_numeric || !text.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    if let Ok(int) = text.parse::<i64>() {
+        return Some(Integer::Int(int));
+    }
+
+    match text.parse::<f64>() {
+        Ok(float) if float.is_finite() => Some(Integer::Float(float)),
+        _ => None,
+    }
+}
+
+/// read an int or a float literal, any other type gives None
+pub fn get_number(literal: &Literal) -> Option<Integer> {
+    if let Some(int) = literal.primitive.as_any().downcast_ref::<PrimitiveInt>() {
+        return Some(Integer::Int(int.value));
+    }
+
+    literal
+        .primitive
+        .as_any()
+        .downcast_ref::<PrimitiveFloat>()
+        .map(|float| Integer::Float(float.value))
+}
+
+/// ints stay ints, mixing an int and a float gives a float
+pub fn min_number(lhs: Integer, rhs: Integer) -> Integer {
+    match (lhs, rhs) {
+        (Integer::Int(lhs), Integer::Int(rhs)) => Integer::Int(lhs.min(rhs)),
+        (lhs, rhs) => Integer::Float(lhs.as_f64().min(rhs.as_f64())),
+    }
+}
+
+/// ints stay ints, mixing an int and a float gives a float
+pub fn max_number(lhs: Integer, rhs: Integer) -> Integer {
+    match (lhs, rhs) {
+        (Integer::Int(lhs), Integer::Int(rhs)) => Integer::Int(lhs.max(rhs)),
+        (lhs, rhs) => Integer::Float(lhs.as_f64().max(rhs.as_f64())),
+    }
+}
+
+pub fn clamp_number(value: Integer, low: Integer, high: Integer) -> Result<Integer, String> {
+    if low.as_f64() > high.as_f64() {
+        return Err(format!(
+            "{} (low: {}, high: {})",
+            ERROR_NUMBER_CLAMP,
+            low.as_f64(),
+            high.as_f64()
+        ));
+    }
+
+    Ok(min_number(max_number(value, low), high))
+}
+
+/// logarithm of value in the given base, values outside of the domain
+/// are reported instead of silently giving NaN or -inf
+pub fn get_logarithm(
+    value: f64,
+    base: f64,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<Literal, ErrorInfo> {
+    if value.is_nan() || value <= 0.0 {
+        return Err(gen_error_info(
+            Position::new(interval, flow_name),
+            format!("{} (value: {})", ERROR_NUMBER_LOG, value),
+        ));
+    }
+    if base.is_nan() || base <= 0.0 || base == 1.0 {
+        return Err(gen_error_info(
+            Position::new(interval, flow_name),
+            format!("{} (base: {})", ERROR_NUMBER_LOG_BASE, base),
+        ));
+    }
+
+    let result = if base == std::f64::consts::E {
+        value.ln()
+    } else if base == 2.0 {
+        value.log2()
+    } else if base == 10.0 {
+        value.log10()
+    } else {
+        value.log(base)
+    };
+
+    Ok(PrimitiveFloat::get_literal(result, interval))
+}
+
+/// radix must be between 2 and 36, negative values keep their minus sign
+pub fn format_radix(value: i64, radix: u32) -> String {
+    let mut magnitude = value.unsigned_abs();
+    let mut digits = vec![];
+
+    loop {
+        if let Some(digit) = std::char::from_digit((magnitude % radix as u64) as u32, radix) {
+            digits.push(digit);
+        }
+        magnitude /= radix as u64;
+
+        if magnitude == 0 {
+            break;
+        }
+    }
+
+    if value < 0 {
+        digits.push('-');
+    }
+
+    digits.iter().rev().collect()
+}
+
+/// radix must be between 2 and 36, an optional sign can precede the digits
+pub fn parse_radix(text: &str, radix: u32) -> Result<i64, String> {
+    let digits = text
+        .strip_prefix('-')
+        .or_else(|| text.strip_prefix('+'))
+        .unwrap_or(text);
+
+    if let Some(character) = digits.chars().find(|c| c.to_digit(radix).is_none()) {
+        return Err(format!(
+            "{} (character: '{}', radix: {})",
+            ERROR_STRING_PARSE_INT, character, radix
+        ));
+    }
+    if digits.is_empty() {
+        return Err(format!(
+            "{} (value: '{}', radix: {})",
+            ERROR_STRING_PARSE_INT, text, radix
+        ));
+    }
+
+    i64::from_str_radix(text, radix)
+        .map_err(|_| format!("{} (value: '{}')", ERROR_STRING_PARSE_INT_RANGE, text))
+}
+
+/// round to a fixed number of decimals, ties are rounded half-up (away from
+/// zero) on the shortest decimal representation so 1.005 gives "1.01"
+pub fn format_fixed(value: f64, decimals: usize) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+
+    let repr = value.abs().to_string();
+    let (int_part, frac_part) = match repr.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, frac_part),
+        None => (repr.as_str(), ""),
+    };
+
+    let mut digits: Vec<u8> = int_part.bytes().collect();
+    digits.extend(
+        frac_part
+            .bytes()
+            .chain(std::iter::repeat(b'0'))
+            .take(decimals),
+    );
+
+    if frac_part
+        .as_bytes()
+        .get(decimals)
+        .is_some_and(|digit| *digit >= b'5')
+    {
+        let mut index = digits.len();
+
+        loop {
+            if index == 0 {
+                digits.insert(0, b'1');
+                break;
+            }
+            index -= 1;
+
+            if digits[index] == b'9' {
+                digits[index] = b'0';
+            } else {
+                digits[index] += 1;
+                break;
+            }
+        }
+    }
+
+    let point = digits.len() - decimals;
+    let mut result = String::new();
+
+    if value < 0.0 && digits.iter().any(|digit| *digit != b'0') {
+        result.push('-');
+    }
+    result.push_str(std::str::from_utf8(&digits[..point]).unwrap_or("0"));
+    if decimals > 0 {
+        result.push('.');
+        result.push_str(std::str::from_utf8(&digits[point..]).unwrap_or("0"));
+    }
+
+    result
+}
+
+/// shared by the Length builtin and the length() methods: grapheme clusters
+/// for strings, elements for arrays and keys for objects
+pub fn get_length(primitive: &dyn Primitive) -> Result<usize, String> {
+    let any = primitive.as_any();
+
+    if let Some(string) = any.downcast_ref::<PrimitiveString>() {
+        return Ok(string.value.graphemes(true).count());
+    }
+    if let Some(array) = any.downcast_ref::<PrimitiveArray>() {
+        return Ok(array.value.len());
+    }
+    if let Some(object) = any.downcast_ref::<PrimitiveObject>() {
+        return Ok(object.value.len());
+    }
+
+    Err(format!(
+        "{} (type: {})",
+        ERROR_LENGTH,
+        primitive.get_type().to_string()
+    ))
+}
+
 pub fn get_array(
     literal: Literal,
     flow_name: &str,
@@ -56,6 +304,20 @@ pub fn get_array(
     }
 }
 
+/// resolve slice bounds against a length: negative values count from the end
+/// and out of range values are clamped, so start > end gives an empty range
+pub fn get_slice_range(start: i64, end: i64, len: usize) -> (usize, usize) {
+    let resolve = |index: i64| -> usize {
+        let index = if index < 0 { len as i64 + index } else { index };
+
+        index.max(0).min(len as i64) as usize
+    };
+
+    let (start, end) = (resolve(start), resolve(end));
+
+    (start, end.max(start))
+}
+
 pub fn check_division_by_zero_i64(lhs: i64, rhs: i64) -> Result<i64, String> {
     if rhs == 0 {
         return Err(ERROR_OPS_DIV_INT.to_owned());
@@ -46,3 +46,60 @@ pub fn digest_data(
         )),
     }
 }
+
+/// hex digest of data with one of the algorithms of get_hash_algorithm
+pub fn hash_hex(
+    algo: &str,
+    data: &[u8],
+    flow_name: &str,
+    interval: Interval,
+) -> Result<String, ErrorInfo> {
+    let algo = get_hash_algorithm(algo, flow_name, interval)?;
+
+    match openssl::hash::hash(algo, data) {
+        Ok(digest) => Ok(hex::encode(digest)),
+        Err(e) => Err(gen_error_info(
+            Position::new(interval, flow_name),
+            format!("{}", e),
+        )),
+    }
+}
+
+pub fn hmac(
+    algo: &str,
+    secret: &[u8],
+    data: &[u8],
+    flow_name: &str,
+    interval: Interval,
+) -> Result<Vec<u8>, ErrorInfo> {
+    let algo = get_hash_algorithm(algo, flow_name, interval)?;
+    let to_error = |e: openssl::error::ErrorStack| {
+        gen_error_info(Position::new(interval, flow_name), format!("{}", e))
+    };
+
+    let key = openssl::pkey::PKey::hmac(secret).map_err(to_error)?;
+    let mut signer = openssl::sign::Signer::new(algo, &key).map_err(to_error)?;
+    signer.update(data).map_err(to_error)?;
+
+    signer.sign_to_vec().map_err(to_error)
+}
+
+/// compare a hex signature with the HMAC of data in constant time, a
+/// signature that is not valid hex or has the wrong length never matches
+pub fn verify_hmac(
+    algo: &str,
+    secret: &[u8],
+    data: &[u8],
+    signature: &str,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<bool, ErrorInfo> {
+    let expected = hmac(algo, secret, data, flow_name, interval)?;
+
+    match hex::decode(signature.trim()) {
+        Ok(signature) if signature.len() == expected.len() => {
+            Ok(openssl::memcmp::eq(&signature, &expected))
+        }
+        _ => Ok(false),
+    }
+}
@@ -1,4 +1,9 @@
-use crate::data::{ast::Interval, position::Position, primitive::PrimitiveString, Literal};
+use crate::data::{
+    ast::Interval,
+    position::Position,
+    primitive::{tools_time, PrimitiveInt, PrimitiveString},
+    Literal,
+};
 use crate::error_format::*;
 use crate::interpreter::json_to_literal;
 
@@ -79,6 +84,46 @@ fn header_to_literal(
     Ok(PrimitiveObject::get_literal(&map, interval.to_owned()))
 }
 
+/// Time() objects are stored in milliseconds, registered claims use seconds
+fn time_to_seconds(lit: &Literal) -> Option<i64> {
+    let time = lit.primitive.as_any().downcast_ref::<PrimitiveObject>()?;
+    let milliseconds = time
+        .value
+        .get("milliseconds")?
+        .primitive
+        .as_any()
+        .downcast_ref::<PrimitiveInt>()?;
+
+    Some(milliseconds.value.div_euclid(1000))
+}
+
+fn verify_error(reason: &str, message: &str, interval: Interval) -> Literal {
+    let mut map = HashMap::new();
+    map.insert(
+        "reason".to_owned(),
+        PrimitiveString::get_literal(reason, interval),
+    );
+    map.insert(
+        "message".to_owned(),
+        PrimitiveString::get_literal(message, interval),
+    );
+
+    let mut result = PrimitiveObject::get_literal(&map, interval);
+    result.add_info("error", PrimitiveString::get_literal(message, interval));
+
+    result
+}
+
+fn get_numeric_date(claims: &serde_json::Value, key: &str) -> Result<Option<f64>, String> {
+    match claims.get(key) {
+        Some(value) => match value.as_f64() {
+            Some(date) => Ok(Some(date)),
+            None => Err(format!("'{}' claim must be a number of seconds", key)),
+        },
+        None => Ok(None),
+    }
+}
+
 pub fn token_data_to_literal(
     data: jsonwebtoken::TokenData<serde_json::Value>,
     flow_name: &str,
@@ -293,3 +338,98 @@ pub fn get_validation(
 
     Ok(())
 }
+
+/// claims holding a Time() object (exp, iat, nbf...) are converted to unix
+/// seconds, everything else is serialized as is
+pub fn get_claims(lit: &Literal) -> serde_json::Value {
+    match lit.primitive.as_any().downcast_ref::<PrimitiveObject>() {
+        Some(object) => serde_json::Value::Object(
+            object
+                .value
+                .iter()
+                .map(|(key, value)| {
+                    let claim = match value.content_type.as_str() {
+                        "time" => time_to_seconds(value)
+                            .map(serde_json::Value::from)
+                            .unwrap_or_else(|| value.primitive.to_json()),
+                        _ => value.primitive.to_json(),
+                    };
+
+                    (key.to_owned(), claim)
+                })
+                .collect(),
+        ),
+        None => lit.primitive.to_json(),
+    }
+}
+
+/// check the signature with the algorithm of the token header, then exp and
+/// nbf against the interpreter clock. A rejected token is not a flow error,
+/// it gives an object with a "reason": "malformed", "bad_signature",
+/// "expired" or "not_yet_valid"
+pub fn verify_token(
+    token: &str,
+    secret: &str,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<Literal, ErrorInfo> {
+    let header = match jsonwebtoken::decode_header(token) {
+        Ok(header) => header,
+        Err(e) => {
+            return Ok(verify_error(
+                "malformed",
+                &format!("Invalid JWT {:?}", e.kind()),
+                interval,
+            ))
+        }
+    };
+
+    match header.alg {
+        jsonwebtoken::Algorithm::HS256
+        | jsonwebtoken::Algorithm::HS384
+        | jsonwebtoken::Algorithm::HS512 => {}
+        _ => return Ok(verify_error("bad_signature", ERROR_JWT_ALGO, interval)),
+    }
+
+    let mut validation = jsonwebtoken::Validation::new(header.alg);
+    validation.required_spec_claims = HashSet::new();
+    validation.validate_exp = false;
+
+    let key = jsonwebtoken::DecodingKey::from_secret(secret.as_ref());
+    let claims = match jsonwebtoken::decode::<serde_json::Value>(token, &key, &validation) {
+        Ok(token_data) => token_data.claims,
+        Err(e) => {
+            let reason = match e.kind() {
+                jsonwebtoken::errors::ErrorKind::InvalidSignature => "bad_signature",
+                _ => "malformed",
+            };
+
+            return Ok(verify_error(
+                reason,
+                &format!("Invalid JWT {:?}", e.kind()),
+                interval,
+            ));
+        }
+    };
+
+    let now = tools_time::now().timestamp() as f64;
+    let (exp, nbf) = match (
+        get_numeric_date(&claims, "exp"),
+        get_numeric_date(&claims, "nbf"),
+    ) {
+        (Ok(exp), Ok(nbf)) => (exp, nbf),
+        (Err(message), _) | (_, Err(message)) => {
+            return Ok(verify_error("malformed", &message, interval))
+        }
+    };
+
+    match (exp, nbf) {
+        (Some(exp), _) if now >= exp => Ok(verify_error("expired", "JWT has expired", interval)),
+        (_, Some(nbf)) if now < nbf => Ok(verify_error(
+            "not_yet_valid",
+            "JWT is not valid yet (nbf)",
+            interval,
+        )),
+        _ => json_to_literal(&claims, interval, flow_name),
+    }
+}
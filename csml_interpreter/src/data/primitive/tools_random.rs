@@ -0,0 +1,46 @@
+use rand::rngs::{OsRng, StdRng};
+use rand::{RngCore, SeedableRng};
+use std::cell::RefCell;
+
+////////////////////////////////////////////////////////////////////////////////
+// DATA STRUCTURE
+////////////////////////////////////////////////////////////////////////////////
+
+thread_local! {
+    static RNG: RefCell<StdRng> = RefCell::new(StdRng::from_entropy());
+    static ID_RNG: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// PUBLIC FUNCTIONS
+////////////////////////////////////////////////////////////////////////////////
+
+/// every random value drawn by the interpreter on this thread comes from the
+/// same generator, seeding it makes Random(), shuffle, one_of and
+/// rand_between deterministic; without a seed it is reset from entropy so a
+/// seeded bot never leaks its sequence to the next bot run on the thread
+pub fn set_seed(seed: Option<u64>) {
+    RNG.with(|rng| {
+        *rng.borrow_mut() = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        }
+    });
+}
+
+pub fn with_rng<T>(f: impl FnOnce(&mut StdRng) -> T) -> T {
+    RNG.with(|rng| f(&mut rng.borrow_mut()))
+}
+
+/// identifiers generated by UUID() must stay unique even when the bot sets a
+/// random_seed, they are drawn from the OS unless a test seeds them here
+pub fn set_id_seed(seed: Option<u64>) {
+    ID_RNG.with(|rng| *rng.borrow_mut() = seed.map(StdRng::seed_from_u64));
+}
+
+pub fn with_id_rng<T>(f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+    ID_RNG.with(|rng| match &mut *rng.borrow_mut() {
+        Some(rng) => f(rng),
+        None => f(&mut OsRng),
+    })
+}
@@ -8,8 +8,17 @@ use crate::data::{
 };
 use crate::error_format::*;
 use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, TimeZone, Utc};
+use std::cell::Cell;
 use std::collections::HashMap;
 
+////////////////////////////////////////////////////////////////////////////////
+// DATA STRUCTURE
+////////////////////////////////////////////////////////////////////////////////
+
+thread_local! {
+    static MOCK_NOW: Cell<Option<i64>> = const { Cell::new(None) };
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // PRIVATE FUNCTIONS
 ////////////////////////////////////////////////////////////////////////////////
@@ -45,6 +54,19 @@ fn get_date_string(
 // PUBLIC FUNCTIONS
 ////////////////////////////////////////////////////////////////////////////////
 
+/// freeze the clock read by Time() and JWT verification to a timestamp in
+/// milliseconds, None goes back to the system clock
+pub fn set_mock_now(milliseconds: Option<i64>) {
+    MOCK_NOW.with(|now| now.set(milliseconds));
+}
+
+pub fn now() -> DateTime<Utc> {
+    match MOCK_NOW.with(|now| now.get()) {
+        Some(milliseconds) => Utc.timestamp_millis(milliseconds),
+        None => Utc::now(),
+    }
+}
+
 pub fn get_date(args: &HashMap<String, Literal>) -> [i64; 7] {
     let mut date: [i64; 7] = [0; 7];
 
@@ -25,6 +25,7 @@ pub const ASSIGN: &str = "=";
 
 pub const OR: &str = "||";
 pub const AND: &str = "&&";
+pub const XOR: &str = "^";
 
 pub const SUBTRACTION_ASSIGNMENT: &str = "-=";
 pub const ADDITION_ASSIGNMENT: &str = "+=";
@@ -37,6 +38,10 @@ pub const LESS_THAN_EQUAL: &str = "<=";
 pub const GREATER_THAN: &str = ">";
 pub const LESS_THAN: &str = "<";
 
+pub const ARROW: &str = "=>";
+pub const PIPE: &str = "|";
+pub const WILDCARD: &str = "_";
+
 pub const COMMA: &str = ",";
 pub const DOT: &str = ".";
 pub const SEMICOLON: &str = ";";
@@ -146,14 +151,18 @@ pub const SHUFFLE: &str = "Shuffle";
 pub const LENGTH: &str = "Length";
 pub const FIND: &str = "Find";
 pub const RANDOM: &str = "Random";
+pub const RANDOM_INT: &str = "RandomInt";
 pub const FLOOR: &str = "Floor";
 pub const VIDEO: &str = "Video";
 pub const AUDIO: &str = "Audio";
+pub const LOCATION: &str = "Location";
 
 pub const QUESTION: &str = "Question";
 pub const BUTTON: &str = "Button";
 pub const CAROUSEL: &str = "Carousel";
 pub const CARD: &str = "Card";
+pub const QUICK_REPLY: &str = "QuickReply";
+pub const QUICK_REPLIES: &str = "QuickReplies";
 pub const FN: &str = "Fn";
 pub const APP: &str = "App";
 pub const HTTP: &str = "HTTP";
@@ -169,10 +178,30 @@ pub const TIME: &str = "Time";
 pub const EXISTS: &str = "Exists";
 
 pub const OBJECT: &str = "Object";
+pub const ARRAY_BUILT_IN: &str = "Array";
 
 pub const BUILT_IN: &[&str] = &[
-    ONE_OF, SHUFFLE, LENGTH, FIND, RANDOM, FLOOR, FN, APP, HTTP, OBJECT, DEBUG, UUID, BASE64, HEX,
-    JWT, CRYPTO, TIME, SMTP, EXISTS,
+    ONE_OF,
+    SHUFFLE,
+    LENGTH,
+    FIND,
+    RANDOM,
+    RANDOM_INT,
+    FLOOR,
+    FN,
+    APP,
+    HTTP,
+    OBJECT,
+    DEBUG,
+    UUID,
+    BASE64,
+    HEX,
+    JWT,
+    CRYPTO,
+    TIME,
+    SMTP,
+    EXISTS,
+    ARRAY_BUILT_IN,
 ];
 
 pub const OR_BUILT_IN: &str = "Or";
@@ -10,6 +10,9 @@ pub const WARNING_FN: &str =
     "'Fn()' will soon be deprecated. Please use the 'App()' keyword instead";
 pub const WARNING_OBJECT: & str = "'Object(key = value)' will be soon a deprecated Macro please use '{key: value}' instead; https://docs.csml.dev/automatic-type-inference/literals-objects-arrays";
 pub const WARNING_USE: & str = "use will be soon a deprecated keyword please use 'do' instead. https://docs.csml.dev/memory/temporary-and-long-term-variables";
+pub const WARNING_FILE_MIME_TYPE: &str = "File component received an unknown mime_type";
+pub const WARNING_MATCH_UNREACHABLE: &str =
+    "unreachable match arm: a previous arm already matches this pattern";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Warnings {
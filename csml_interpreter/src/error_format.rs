@@ -17,6 +17,7 @@ pub const ERROR_PARENTHESES: &str = "list elem type ( ... ) not found";
 pub const ERROR_PARENTHESES_END: &str =
     "Invalid argument. Expecting one ',' between each argument or ')' to end the list";
 pub const ERROR_NUMBER_AS_IDENT: &str = "Int/Float can't be used as identifier";
+pub const ERROR_MINUS_AS_IDENT: &str = "identifier can't start with '-', it is read as a negation";
 pub const ERROR_FLOW_STEP: &str = "syntax error.";
 pub const ERROR_RESERVED: &str = "reserved keyword can't be used as identifier";
 pub const ERROR_PARSING: &str =
@@ -33,6 +34,8 @@ pub const ERROR_INSERT_ARGUMENT: &str =
     "'insert' expecting valid step name. Example: 'insert step from flow'";
 pub const ERROR_BREAK: &str = "break can only be used inside loops";
 pub const ERROR_RETURN: &str = "return expects a value to return";
+pub const ERROR_MATCH_ARM: &str =
+    "match arms must be written as pattern => { ... }. Example: match event { \"a\" | \"b\" => { say \"ab\" } _ => { say \"other\" } }";
 pub const ERROR_LEFT_BRACE: &str = "expecting '{'";
 pub const ERROR_RIGHT_BRACE: &str = "expecting '}'";
 pub const ERROR_RIGHT_BRACKET: &str = "expecting ']'";
@@ -57,7 +60,7 @@ pub const ERROR_INVALID_FLOW: &str = "invalid flow: ";
 pub const ERROR_START_INSTRUCTIONS: &str =
     "to start an action one of the following instructions is expected: [say, do, if, foreach, goto]";
 pub const ERROR_FOREACH: &str =
-    "foreach only accepts iterable elements like arrays and strings. Example: foreach(elem) in [1, 2, 3]";
+    "foreach only accepts iterable elements like arrays, objects and strings. Example: foreach(elem) in [1, 2, 3]";
 pub const ERROR_FIND_BY_INDEX: &str =
     "index must be of type int or string. Example var.[42] or var.[\"key\"]";
 pub const ERROR_ASSIGN_IDENT: &str = "key must be of type identifier";
@@ -70,6 +73,8 @@ pub const ERROR_PAYLOAD_EXCEED_MAX_SIZE: &str = "payload exceeds max payload siz
 
 pub const ERROR_STEP_LIMIT: &str =
     "[Infinite loop] Step limit reached: 100 steps where executed in a single run";
+pub const ERROR_LOOP_LIMIT: &str = "[Infinite loop] Loop limit reached";
+pub const ERROR_FN_DEPTH_LIMIT: &str = "[Infinite recursion] Function call depth limit reached";
 
 // Event
 pub const ERROR_EVENT_CONTENT_TYPE: &str = "event can only be of ContentType::Event";
@@ -106,45 +111,86 @@ pub const ERROR_FN_COLON: &str =
 // ### Built-in
 pub const ERROR_TEXT: &str =
     "Text component expects one argument of type string. Example: Text(\"hola\")";
-pub const ERROR_TYPING: &str =
-    "Typing component expects one argument of type int or float. Example: Typing(3, ..)";
-pub const ERROR_WAIT: &str =
-    "Wait component expects one argument of type int or float. Example: Wait(3)";
+pub const ERROR_TYPING: &str = "Typing component expects a non-negative duration in milliseconds or a string with a unit. Example: Typing(500) or Typing(\"2s\")";
+pub const ERROR_WAIT: &str = "Wait component expects a non-negative duration in milliseconds or a string with a unit. Example: Wait(500) or Wait(\"2s\")";
+pub const ERROR_DURATION_MAX: &str = "duration is longer than the max_duration of the bot";
 pub const ERROR_BUTTON: &str =
     "Button component expects at least one argument of type string. Example: Button(\"hola\")";
+pub const ERROR_BUTTON_URL: &str = "argument 'url' in Button component must be an http or https url. Example: Button(\"Docs\", url = \"https://csml.dev\")";
+pub const ERROR_BUTTON_URL_PAYLOAD: &str =
+    "Button component can't have both a url and a payload, a link button doesn't send a payload";
+pub const ERROR_BUTTON_DISABLED: &str =
+    "argument 'disabled' in Button component must be of type Boolean. Example: disabled = true";
 pub const ERROR_CARD_BUTTON: &str = "argument 'buttons' in Card component must be of type Array<Button>. Example: [ Button(\"b1\"), Button(\"b2\") ]";
 pub const ERROR_CARD_TITLE: &str = "argument title in Card component must be of type String";
+pub const ERROR_CARD_SUBTITLE: &str = "argument subtitle in Card component must be of type String";
+pub const ERROR_CARD_IMAGE_URL: &str =
+    "argument image_url in Card component must be of type String";
 pub const ERROR_QUESTION: &str = "argument 'buttons' in Question component must be of type Array<Button>. Example: [ Button(\"b1\"), Button(\"b2\") ]";
+pub const ERROR_QUESTION_ACCEPTS: &str = "argument 'accepts' in Question component must be of type Array<String>. Example: accepts = [\"yes\", \"no\"]";
+pub const ERROR_QUESTION_RETRIES: &str =
+    "argument 'retries' in Question component must be a non-negative int. Example: retries = 2";
+pub const ERROR_QUESTION_FALLBACK: &str = "argument 'fallback' in Question component must be the name of a step. Example: fallback = \"no_answer\"";
+pub const ERROR_QUESTION_NO_ANSWERS: &str = "arguments 'retries' and 'fallback' in Question component need 'buttons' or 'accepts' to check the answer. Example: accepts = [\"yes\", \"no\"]";
+pub const ERROR_QUICK_REPLY: &str = "QuickReply component expects title, payload and image_url arguments of type String. Example: QuickReply(\"yes\", payload = \"YES\")";
+pub const ERROR_QUICK_REPLIES: &str = "argument 'quick_replies' in QuickReplies component must be of type Array<QuickReply>. Example: [ QuickReply(\"yes\"), QuickReply(\"no\") ]";
+pub const ERROR_LOCATION_LATITUDE: &str =
+    "Location component expects lat to be a number between -90 and 90";
+pub const ERROR_LOCATION_LONGITUDE: &str =
+    "Location component expects long to be a number between -180 and 180";
+pub const ERROR_LOCATION_TEXT: &str = "Location component expects title and address to be of type String. Example: Location(48.8584, 2.2945, title = \"Eiffel Tower\")";
 pub const ERROR_CAROUSEL: &str =
     "argument 'cards' in Carousel component must be of type Array<Card>";
 pub const ERROR_ONE_OF: &str =
     "OneOf builtin expects one value of type Array. Example: OneOf( [1, 2, 3] )";
+pub const ERROR_ONE_OF_WEIGHTS: &str = "argument 'weights' in OneOf builtin must be an Array of positive numbers with one weight per value. Example: OneOf( [\"a\", \"b\"], weights = [1, 3] )";
+pub const ERROR_ONE_OF_NO_REPEAT: &str =
+    "argument 'no_repeat' in OneOf builtin must be of type Boolean. Example: no_repeat = true";
 pub const ERROR_VAR_EXISTS: &str =
     "Exists builtin expects one value of type String. Example: Exists( \"var_name\" )";
 pub const ERROR_SHUFFLE: &str =
     "Shuffle builtin expects one value of type Array. Example: Shuffle( [1, 2, 3] )";
 pub const ERROR_LENGTH: &str =
-    "Length builtin expects one value of type Array or String. Example: Length( value )";
-pub const ERROR_FIND: &str = "Find builtin expects 'in' param to be of type String. Example: Find(value, in = \"hola\", case_sensitive = true)";
+    "Length builtin expects one value of type String, Array or Object. Example: Length( value )";
+pub const ERROR_DEBUG: &str = "Debug builtin expects one value and an optional label of type String. Example: Debug(value, \"label\")";
+pub const ERROR_FIND: &str = "Find builtin expects a value and an 'in' param of type String or Array, and an optional case_sensitive boolean. Example: Find(value, in = \"hola\", case_sensitive = true) or Find(value, in = [1, 2, 3])";
+pub const ERROR_RANDOM_INT: &str =
+    "RandomInt builtin expects two arguments of type int. Example: RandomInt(1, 6)";
+pub const ERROR_RANDOM_INT_RANGE: &str =
+    "RandomInt builtin expects min to be lower than or equal to max";
 pub const ERROR_FLOOR: &str =
     "Floor builtin expects one argument of type float. Example: Floor(4.2)";
 pub const ERROR_UUID: &str =
-    "UUID builtin expects one optional argument of type String. Example: UUID(\"v4\") or UUID(\"v1\")";
+    "UUID builtin expects one optional argument of type String: \"v4\" (default), \"v1\" or \"short\". Example: UUID(\"short\")";
 pub const ERROR_IMAGE: &str =
     "Image component expects one argument of type string. Example: Image(\"hola\")";
 pub const ERROR_URL: &str = "Url component expects one argument of type string and 2 optional string arguments: text, title. Example: Url(\"hola\", text = \"text\", title = \"title\")";
 pub const ERROR_VIDEO: &str =
     "Video component expects one argument of type string. Example: Video(url = \"hola\")";
+pub const ERROR_VIDEO_URL: &str = "Video component expects url and thumbnail_url to be valid http or https URLs. Example: Video(\"https://example.com/video.mp4\")";
+pub const ERROR_VIDEO_CAPTION: &str = "argument caption in Video component must be of type String";
 pub const ERROR_AUDIO: &str =
     "Audio component expects one argument of type string. Example: Audio(url = \"hola\")";
+pub const ERROR_AUDIO_URL: &str = "Audio component expects url to be a valid http or https URL. Example: Audio(\"https://example.com/note.mp3\")";
+pub const ERROR_AUDIO_DURATION: &str = "Audio component expects duration to be a non-negative int of seconds. Example: Audio(\"https://example.com/note.mp3\", duration = 12)";
+pub const ERROR_AUDIO_CAPTION: &str = "argument caption in Audio component must be of type String";
 pub const ERROR_FILE: &str =
     "File component expects one argument of type string. Example: File(url = \"hola\")";
+pub const ERROR_FILE_URL: &str = "File component expects url to be a valid http or https URL. Example: File(\"https://example.com/invoice.pdf\")";
+pub const ERROR_FILE_FILENAME: &str = "argument filename in File component must be of type String";
+pub const ERROR_FILE_MIME_TYPE: &str = "argument mime_type in File component must be of type String. Example: File(\"https://example.com/invoice.pdf\", mime_type = \"application/pdf\")";
 pub const ERROR_HTTP_GET_VALUE: &str =
     "not found in HTTP object. Use the HTTP() builtin to construct the correct object to make HTTP calls";
 pub const ERROR_HTTP_QUERY_VALUES: &str =
     "must have a value of type String. Example: {key: \"value\"}";
 pub const ERROR_HTTP: &str =
     "HTTP builtin expects one url of type string. Example: HTTP(\"https://clevy.io\")";
+pub const ERROR_OBJECT: &str =
+    "Object builtin expects named arguments. Example: Object(name = \"Jane\", age = 42)";
+pub const ERROR_ARRAY: &str =
+    "Array builtin expects positional arguments. Example: Array(1, \"two\", Object(three = 3))";
+pub const ERROR_BASE64: &str =
+    "Base64 builtin expects one argument of type String or Object. Example: Base64(\"Hello\")";
 pub const ERROR_JWT: &str = "JWT builtin expects payload as argument. Example: JWT({
         \"user\": \"name\",
         \"somekey\": {
@@ -156,7 +202,7 @@ pub const ERROR_JWT: &str = "JWT builtin expects payload as argument. Example: J
 pub const ERROR_SMTP: &str =
     "SMTP builtin expects SMTP Server Address. Example: SMTP(\"smtp.gmail.com\")";
 pub const ERROR_CRYPTO: &str =
-    "CRYPTO builtin expects one argument of type string. Example: CRYPTO(\"text\")";
+    "Crypto builtin expects one argument of type String. Example: Crypto(\"text\")";
 pub const ERROR_BUILTIN_UNKNOWN: &str = "Unknown builtin";
 
 // ### native Components
@@ -184,11 +230,30 @@ pub const ERROR_BOOLEAN_UNKNOWN_METHOD: &str = "is not a method of Boolean";
 pub const ERROR_NUMBER_POW: &str =
     "[pow] takes one parameter of type int or float usage: number.pow(42)";
 
+pub const ERROR_NUMBER_CLAMP: &str = "[clamp] low must be lower than or equal to high";
+
+pub const ERROR_NUMBER_LOG: &str = "logarithm is only defined for numbers greater than 0";
+pub const ERROR_NUMBER_LOG_BASE: &str =
+    "[log] takes one parameter of type int or float greater than 0 and different from 1. Usage: number.log(2)";
+pub const ERROR_NUMBER_TRIG_DOMAIN: &str = "is only defined for values between -1 and 1";
+pub const ERROR_NUMBER_RAND_BETWEEN: &str = "[rand_between] min must be lower than or equal to max";
+pub const ERROR_NUMBER_RAND_BETWEEN_FINITE: &str =
+    "[rand_between] min and max must be finite numbers";
+
 // #### Float
 pub const ERROR_FLOAT_UNKNOWN_METHOD: &str = "is not a method of Float";
+pub const ERROR_FLOAT_DECIMALS: &str =
+    "takes one parameter of type int between 0 and 100. Usage: number.to_fixed(2)";
+pub const ERROR_FLOAT_PARITY: &str =
+    "parity is only defined for ints, use round() or floor() first. Usage: number.round().is_even()";
 
 // #### Int
 pub const ERROR_INT_UNKNOWN_METHOD: &str = "is not a method of Int";
+pub const ERROR_INT_TO_BASE: &str =
+    "[to_base] takes one parameter of type int between 2 and 36. Usage: int.to_base(16)";
+pub const ERROR_INT_BITWISE: &str = "takes one parameter of type int. Usage: int.bitxor(6)";
+pub const ERROR_INT_SHIFT: &str =
+    "takes one parameter of type int between 0 and 63. Usage: int.shl(2)";
 
 // #### Null
 pub const ERROR_NULL_UNKNOWN_METHOD: &str = "is not a method of Null";
@@ -199,31 +264,72 @@ pub const ERROR_STRING_DO_MATCH: &str =
 pub const ERROR_STRING_APPEND: &str =
     "[append] takes one parameter of type String. Usage: string.append(\"text to append\")";
 pub const ERROR_STRING_CONTAINS: &str =
-    "[contains] takes one parameter of type String. Usage: string.contains(\"word\")";
+    "[contains] takes one parameter of type String and an optional Boolean. Usage: string.contains(\"word\", case_insensitive)";
+pub const ERROR_STRING_CONTAINS_ANY: &str =
+    "[contains_any] takes one parameter of type Array of String and an optional Boolean. Usage: string.contains_any([\"word\", \"other\"], case_insensitive)";
 pub const ERROR_STRING_REPLACE: &str =
     "[replace] takes tow parameter of type String. Usage: \"this is old\".replace(\"old\", \"new\")";
 pub const ERROR_STRING_REPLACE_ALL: &str =
     "[replace_all] takes tow parameter of type String. Usage: \"old old old old\".replace_all(\"old\", \"new\")";
 pub const ERROR_STRING_REPLACE_REGEX: &str =
     "[replace_regex] takes tow parameter of type String. Usage: \"hello world\".replace_regex(\"world\", \"Clevy\")";
+pub const ERROR_STRING_REPLACE_EMPTY: &str = "the value to replace can not be an empty string";
 pub const ERROR_STRING_CONTAINS_REGEX: &str =
     "[contains_regex] takes one parameter of type String. Usage: string.contains_regex(\"regex\")";
 pub const ERROR_STRING_VALID_REGEX: &str = "parameter must be a valid regex expression"; // link to docs
 pub const ERROR_STRING_START_WITH: &str =
-    "[starts_with] takes one parameter of type String. Usage: string.starts_with(\"tag\")";
+    "[starts_with] takes one parameter of type String or Array of String. Usage: string.starts_with(\"tag\")";
 pub const ERROR_STRING_START_WITH_REGEX: &str = "[starts_with_regex] takes one parameter of type String. Usage: string.start_with_regex(\"regex\")";
 pub const ERROR_STRING_END_WITH: &str =
-    "[ends_with] takes one parameter of type String. Usage: string.ends_with(\"tag\")";
+    "[ends_with] takes one parameter of type String or Array of String. Usage: string.ends_with(\"tag\")";
 pub const ERROR_STRING_END_WITH_REGEX: &str =
     "[ends_with_regex] takes one parameter of type String. Usage: string.ends_with_regex(\"regex\")";
+pub const ERROR_STRING_TRIM_MATCHES: &str =
+    "[trim_matches] takes one parameter of type String. Usage: string.trim_matches(\"-_\")";
+pub const ERROR_STRING_INDEX_OF: &str =
+    "[index_of] takes one parameter of type String and an optional Integer start. Usage: string.index_of(\"=\", 2)";
+pub const ERROR_STRING_PAD: &str =
+    "[pad_start/pad_end] takes one positive Integer width and an optional non empty String pad. Usage: string.pad_start(6, \"0\")";
+pub const ERROR_STRING_PAD_WIDTH: &str = "the width can not be larger than";
+pub const ERROR_STRING_REPEAT: &str =
+    "[repeat] takes one positive parameter of type Integer. Usage: string.repeat(3)";
+pub const ERROR_STRING_REPEAT_SIZE: &str = "the resulting string can not be larger than";
+pub const ERROR_STRING_CHAR_AT: &str =
+    "[char_at] takes one parameter of type Integer. Usage: string.char_at(0)";
+pub const ERROR_STRING_INDEX_OUT_OF_RANGE: &str = "index is out of range";
+pub const ERROR_STRING_BASE64_INVALID: &str = "the string is not valid base64";
+pub const ERROR_STRING_BASE64_UTF8: &str = "the decoded value is not a valid UTF-8 string";
+pub const ERROR_BASE64_JSON: &str = "the decoded value is not valid JSON";
+pub const ERROR_STRING_FORMAT_MISSING: &str = "format(values) no value found for placeholder";
+pub const ERROR_STRING_FORMAT_UNCLOSED: &str =
+    "format(values) unbalanced brace in template, use '{{' and '}}' for literal braces:";
+pub const ERROR_STRING_FUZZY_MATCH: &str =
+    "levenshtein(value: String) and similarity(value: String) expect value argument of type String";
+pub const ERROR_STRING_FUZZY_MATCH_SIZE: &str = "compared strings can not be longer than";
+pub const ERROR_STRING_HASH: &str =
+    "hash(algorithm: String) => String, expect algorithm argument of type String";
+pub const ERROR_STRING_URL_DECODE: &str =
+    "the string contains an invalid percent encoded sequence or is not valid UTF-8";
 pub const ERROR_STRING_FROM_JSON: &str = "[from_json] [!] string to object failed]";
 pub const ERROR_STRING_SPLIT: &str =
     "[split] takes one parameter of type String. Usage: string.split(\"separator\")";
+pub const ERROR_STRING_SPLIT_REGEX: &str =
+    "[split_regex] takes one parameter of type String and an optional positive Integer. Usage: string.split_regex(\"[,;]\", 2)";
 pub const ERROR_STRING_MATCH_REGEX: &str =
     "[match_regex] takes one parameter of type String. Usage: string.match_regex(\"regex\")";
+pub const ERROR_STRING_IS_MATCH: &str =
+    "[is_match] takes one parameter of type String. Usage: string.is_match(\"regex\")";
+pub const ERROR_STRING_CAPTURE_REGEX: &str =
+    "[capture_regex] takes one parameter of type String. Usage: string.capture_regex(\"(\\w+)=(\\w+)\")";
 pub const ERROR_STRING_POW: &str =
     "[pow] takes one parameter of type Float or Int. Usage: string.pow(number)";
 pub const ERROR_STRING_COS: &str = "[cos] the string must be of numeric type in order to use cos. Verify first with 'string.is_number() == true' ";
+pub const ERROR_STRING_PARSE_NUMBER: &str = "the string is not a valid number";
+pub const ERROR_STRING_TO_BOOLEAN: &str = "only 'true' and 'false' can be converted to a boolean";
+pub const ERROR_STRING_PARSE_INT: &str = "invalid digit found while parsing an int";
+pub const ERROR_STRING_PARSE_INT_RANGE: &str = "the parsed value does not fit in an int";
+pub const ERROR_STRING_PARSE_INT_RADIX: &str =
+    "[parse_int] takes one parameter of type int between 2 and 36. Usage: string.parse_int(16)";
 pub const ERROR_STRING_NUMERIC: &str = "the string must be of numeric type in order to use this method. Verify first with 'string.is_number() == true' to check it";
 pub const ERROR_STRING_RHS: &str = "rhs must be of type string";
 
@@ -242,8 +348,12 @@ pub const ERROR_ARRAY_INDEX_EXIST: &str = "index does not exist";
 pub const ERROR_ARRAY_INDEX_TYPE: &str = "index must be of type int";
 pub const ERROR_ARRAY_NEGATIVE: &str = "index must be positive. Udage: array[1]";
 pub const ERROR_ARRAY_INDEX: &str = "index must be lower than or equal to array.length()";
+pub const ERROR_ARRAY_INDEX_OUT_OF_RANGE: &str = "index is out of range";
 pub const ERROR_ARRAY_OVERFLOW: &str = "[push] Cannot push inside array, since array limit is ";
+pub const ERROR_ARRAY_UNSHIFT_OVERFLOW: &str =
+    "[unshift] Cannot unshift inside array, since array limit is ";
 pub const ERROR_ARRAY_POP: &str = "[pop] Cannot pop if array is empty";
+pub const ERROR_ARRAY_SHIFT: &str = "[shift] Cannot shift if array is empty";
 pub const ERROR_ARRAY_INSERT_AT: &str =
     "[insert_at] takes two arguments. Usage: array.insert_at(1, elem)";
 pub const ERROR_ARRAY_INSERT_AT_INT: &str =
@@ -254,14 +364,32 @@ pub const ERROR_ARRAY_JOIN: &str =
     "[join] takes one parameter of type String. Usage: array.join(\"elem\") ";
 pub const ERROR_ARRAY_INDEX_OF: &str =
     "[index_of] takes one parameter. Usage: array.index_of(elem)";
-pub const ERROR_ARRAY_FIND: &str = "[find] takes one parameter. Usage: array.find(elem)";
+pub const ERROR_ARRAY_CONCAT: &str =
+    "[concat] takes one parameter of type Array or a value. Usage: array.concat([elem])";
+pub const ERROR_ARRAY_SORT: &str =
+    "only arrays of numbers or arrays of strings can be sorted, can not compare";
+pub const ERROR_ARRAY_SORT_BY_KEY: &str =
+    "[sort_by] every element must be an object containing the key";
+pub const ERROR_ARRAY_SORT_BY_CMP: &str = "[sort_by] values can not be compared for key";
+pub const ERROR_ARRAY_FLATTEN: &str =
+    "[flatten] depth must be a positive int, -1 or \"all\". Usage: array.flatten(2)";
+pub const ERROR_ARRAY_NUMERIC: &str = "every element must be of type int or float";
+pub const ERROR_ARRAY_AGGREGATE_EMPTY: &str = "can not be computed on an empty array";
+pub const ERROR_ARRAY_SUM_OVERFLOW: &str = "the sum is too large to fit in an int";
+pub const ERROR_ARRAY_FROM_ENTRIES: &str =
+    "[from_entries] every element must be a [key, value] pair with a key of type String. Usage: [[\"key\", value]].from_entries()";
+pub const ERROR_ARRAY_CHUNK: &str =
+    "[chunk] takes one parameter of type int greater than 0. Usage: array.chunk(5)";
+pub const ERROR_ARRAY_FILTER_OPERATOR: &str =
+    "is not a valid operator, supported operators are == != > < >= <=";
+pub const ERROR_ARRAY_FIND: &str =
+    "[find] takes one or two parameters. Usage: array.find(elem) or array.find(\"key\", elem)";
 pub const ERROR_ARRAY_UNKNOWN_METHOD: &str = "is not a method of Array";
 
 // #### CRYPTO OBJECT
 // ## HMAC and HASH OBJECT
 pub const ERROR_HASH: &str = "Crypto(string) command expect argument of type String";
-pub const ERROR_HASH_ALGO: &str =
-    "Invalid Algorithm, supported Algorithms are md5 sha1 sha256 sha384 sha512";
+pub const ERROR_HASH_ALGO: &str = "Invalid Algorithm, supported Algorithms are md5 sha1 sha256 sha384 sha512 sha3_224 sha3_256 sha3_384 sha3_512 shake_128 shake_256 ripemd160 sm3";
 pub const ERROR_HMAC_KEY: &str = "HMAC key need to be of type string";
 
 pub const ERROR_DIGEST: &str = "Invalid argument, '.digest' is use incorrectly";
@@ -275,8 +403,8 @@ pub const ERROR_JWT_SECRET: &str = "secret must be of type String";
 pub const ERROR_JWT_SIGN_CLAIMS: &str =
     "JWT(claims) command expect argument 'claims' of type Object";
 pub const ERROR_JWT_SIGN_ALGO: &str =
-    "JWT(claims).sign(algo, secret, Optional<Header>) expect first argument 'algo' of type String";
-pub const ERROR_JWT_SIGN_SECRET: &str = "JWT(claims).sign(algo, secret, Optional<Header>) expect second argument 'claims' of type String";
+    "JWT(claims).sign(secret, Optional<algo>, Optional<Header>) expect argument 'algo' of type String";
+pub const ERROR_JWT_SIGN_SECRET: &str = "JWT(claims).sign(secret, Optional<algo>, Optional<Header>) expect argument 'secret' of type String";
 
 pub const ERROR_JWT_TOKEN: &str = "JWT(jwt) command expect argument 'jwt' of type String";
 
@@ -291,6 +419,8 @@ pub const ERROR_JWT_VALIDATION_ALGO: &str =
     "JWT(jwt).verify(claims, algo, secret) expect second argument 'algo' of type String";
 pub const ERROR_JWT_VALIDATION_SECRETE: &str =
     "JWT(jwt).verify(claims, algo, secret) expect third argument 'secrete' of type String";
+pub const ERROR_JWT_VERIFY_SECRET: &str =
+    "JWT(jwt).verify(secret) expect argument 'secret' of type String";
 
 // #### HTTP OBJECT
 pub const ERROR_HTTP_SET: &str =
@@ -300,14 +430,30 @@ pub const ERROR_HTTP_QUERY: &str =
 
 pub const ERROR_HTTP_SEND: &str = "[send] HTTP Object is bad formatted read doc for correct usage";
 pub const ERROR_HTTP_UNKNOWN_METHOD: &str = "is not a method of HTTP";
+pub const ERROR_HTTP_TIMEOUT: &str =
+    "[timeout] takes one positive number of seconds. Usage: HTTP(...).timeout(3)";
+pub const ERROR_HTTP_RESPONSE_BODY: &str =
+    "Invalid Response format, please send a json or a valid UTF-8 sequence";
 
 // #### OBJECT
 pub const ERROR_OBJECT_TYPE: &str = "value must be of type Object";
 pub const ERROR_OBJECT_GET: &str = "key does not exist";
 pub const ERROR_OBJECT_CONTAINS: &str =
     "[contains] takes one argument of type String. Usage: object.contains(\"key\")";
+pub const ERROR_OBJECT_HAS_PATH: &str =
+    "[has_path] takes one argument of type String. Usage: object.has_path(\"key.nested_key\")";
 pub const ERROR_OBJECT_GET_GENERICS: &str =
     "[get_generics] takes one argument of type String. Usage: object.get_generics(\"key\")";
+pub const ERROR_OBJECT_GET_OR: &str =
+    "[get_or] takes two arguments, the first of type String. Usage: object.get_or(\"key\", default)";
+pub const ERROR_OBJECT_FLATTEN_KEYS_COLLISION: &str =
+    "[flatten_keys] two values end up under the same path";
+pub const ERROR_OBJECT_UNFLATTEN_COLLISION: &str =
+    "[unflatten] a value and an object end up under the same path";
+pub const ERROR_OBJECT_QUERY: &str =
+    "[query] unsupported path syntax, supported forms are $.key, $.*, $[0] and $[*]. Usage: object.query(\"$.items[*].price\")";
+pub const ERROR_OBJECT_SET_PATH: &str =
+    "[set] can not create a key inside a value that is not an object";
 pub const ERROR_OBJECT_INSERT: &str =
     "[insert] take tow arguments. Usage: object.insert(string, any_type)";
 pub const ERROR_OBJECT_ASSIGN: &str =
@@ -14,7 +14,10 @@ use crate::data::{
 };
 use crate::error_format::*;
 use crate::interpreter::{
-    ast_interpreter::{for_loop, match_actions, solve_if_statement, while_loop},
+    ast_interpreter::{
+        for_loop, match_actions, send_auto_typing, solve_if_statement, solve_match_statement,
+        while_loop,
+    },
     variable_handler::{expr_to_literal, interval::interval_from_expr},
 };
 use crate::parser::ExitCondition;
@@ -64,6 +67,11 @@ pub fn interpret_scope(
             return Ok(message_data);
         }
 
+        // a Typing("auto") only looks at the say that directly follows it
+        if !matches!(action, Expr::ObjectExpr(ObjectType::Say(..))) {
+            message_data = send_auto_typing(None, message_data, data, sender);
+        }
+
         match action {
             Expr::ObjectExpr(ObjectType::Return(var)) => {
                 let lit = expr_to_literal(
@@ -102,7 +110,8 @@ pub fn interpret_scope(
                     data.context.flow.clone(),
                     data.previous_info.clone(),
                     false,
-                );
+                )
+                .with_question(data.question.take());
 
                 message_data.hold = Some(hold.to_owned());
 
@@ -124,7 +133,8 @@ pub fn interpret_scope(
                     data.context.flow.clone(),
                     data.previous_info.clone(),
                     true,
-                );
+                )
+                .with_question(data.question.take());
 
                 message_data.hold = Some(hold.to_owned());
 
@@ -159,6 +169,9 @@ pub fn interpret_scope(
             Expr::WhileExpr(expr, block, range) => {
                 message_data = while_loop(expr, block, range, message_data, data, &sender)?
             }
+            Expr::MatchExpr(expr, arms, ..) => {
+                message_data = solve_match_statement(expr, arms, message_data, data, sender)?
+            }
             e => {
                 return Err(gen_error_info(
                     Position::new(interval_from_expr(e), &data.context.flow),
@@ -168,5 +181,5 @@ pub fn interpret_scope(
         };
     }
 
-    Ok(message_data)
+    Ok(send_auto_typing(None, message_data, data, sender))
 }
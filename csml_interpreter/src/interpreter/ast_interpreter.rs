@@ -1,9 +1,11 @@
 mod actions;
 mod for_loop;
 mod if_statement;
+mod match_statement;
 mod while_loop;
 
-pub use actions::match_actions;
+pub use actions::{match_actions, send_auto_typing};
 pub use for_loop::for_loop;
 pub use if_statement::{evaluate_condition, solve_if_statement};
+pub use match_statement::solve_match_statement;
 pub use while_loop::while_loop;
@@ -8,9 +8,10 @@ use crate::data::{
     literal::ContentType,
     message::*,
     primitive::{closure::capture_variables, PrimitiveNull, PrimitiveString},
-    Literal, Memory, MemoryType, MessageData, MSG,
+    Literal, Memory, MemoryType, MessageData, QuestionHold, MSG,
 };
 use crate::error_format::*;
+use crate::interpreter::components::typing::{get_auto_typing_duration, AUTO_TYPING};
 use crate::interpreter::variable_handler::{
     exec_path_actions, expr_to_literal,
     forget_memories::{forget_scope_memories, remove_message_data_memories},
@@ -91,6 +92,30 @@ fn check_if_inserted_step<'a>(name: &str, interval: &Interval, data: &'a Data) -
     }
 }
 
+/// send the pending Typing("auto") with a duration based on the next message
+/// when it's a text, the default duration otherwise
+pub fn send_auto_typing(
+    next: Option<&Message>,
+    msg_data: MessageData,
+    data: &mut Data,
+    sender: &Option<mpsc::Sender<MSG>>,
+) -> MessageData {
+    let mut typing = match data.typing.take() {
+        Some(typing) => typing,
+        None => return msg_data,
+    };
+
+    let text = next
+        .filter(|msg| msg.content_type == "text")
+        .and_then(|msg| msg.content["text"].as_str());
+    let duration = get_auto_typing_duration(text, &data.auto_typing).min(data.max_duration);
+
+    typing.content["duration"] = serde_json::json!(duration);
+
+    MSG::send(sender, MSG::Message(typing.clone()));
+    msg_data.add_message(typing)
+}
+
 pub fn match_actions(
     function: &ObjectType,
     mut msg_data: MessageData,
@@ -102,6 +127,11 @@ pub fn match_actions(
             let lit =
                 expr_to_literal(arg, &DisplayWarnings::On, None, data, &mut msg_data, sender)?;
 
+            // the output of Debug() is dropped when the bot disables debug messages
+            if lit.content_type == "debug" && !data.debug {
+                return Ok(msg_data);
+            }
+
             // check if it is secure variable
             if lit.secure_variable {
                 let err = gen_error_info(
@@ -109,10 +139,26 @@ pub fn match_actions(
                     "Secure variable can not be displayed".to_owned(),
                 );
 
+                msg_data = send_auto_typing(None, msg_data, data, sender);
                 MSG::send_error_msg(&sender, &mut msg_data, Err(err));
                 Ok(msg_data)
             } else {
                 let msg = Message::new(lit, &data.context.flow)?;
+
+                // the duration of Typing("auto") depends on the next message
+                if msg.content_type == "typing" && msg.content["duration"] == AUTO_TYPING {
+                    msg_data = send_auto_typing(None, msg_data, data, sender);
+                    data.typing = Some(msg);
+
+                    return Ok(msg_data);
+                }
+
+                msg_data = send_auto_typing(Some(&msg), msg_data, data, sender);
+
+                if msg.content_type == "question" {
+                    data.question = QuestionHold::from_message(&msg.content);
+                }
+
                 MSG::send(&sender, MSG::Message(msg.clone()));
                 Ok(Message::add_to_message(msg_data, MessageType::Msg(msg)))
             }
@@ -120,6 +166,10 @@ pub fn match_actions(
         ObjectType::Debug(args, interval) => {
             let args = resolve_fn_args(args, data, &mut msg_data, &DisplayWarnings::On, sender)?;
 
+            if !data.debug {
+                return Ok(msg_data);
+            }
+
             let lit = args.args_to_debug(interval.to_owned());
 
             // check if it is secure variable
@@ -129,10 +179,21 @@ pub fn match_actions(
                     "Secure variable can not be displayed".to_owned(),
                 );
 
+                msg_data = send_auto_typing(None, msg_data, data, sender);
                 MSG::send_error_msg(&sender, &mut msg_data, Err(err));
                 Ok(msg_data)
             } else {
                 let msg = Message::new(lit, &data.context.flow)?;
+
+                // the duration of Typing("auto") depends on the next message
+                if msg.content_type == "typing" && msg.content["duration"] == AUTO_TYPING {
+                    msg_data = send_auto_typing(None, msg_data, data, sender);
+                    data.typing = Some(msg);
+
+                    return Ok(msg_data);
+                }
+
+                msg_data = send_auto_typing(Some(&msg), msg_data, data, sender);
                 MSG::send(&sender, MSG::Message(msg.clone()));
                 Ok(Message::add_to_message(msg_data, MessageType::Msg(msg)))
             }
@@ -188,10 +249,14 @@ pub fn match_actions(
                 tmp_loop_index,
                 &mut tmp_step_count,
                 tmp_step_limit,
+                data.loop_limit,
                 tmp_step_vars,
                 data.previous_info.clone(),
                 data.custom_component,
                 data.native_component,
+                data.debug,
+                data.max_duration,
+                data.auto_typing,
             );
             // #####################
 
@@ -213,6 +278,16 @@ pub fn match_actions(
             let memory: HashMap<String, Literal> = data.get_all_memories();
             capture_variables(&mut &mut new_value, memory, &data.context.flow);
 
+            // 'do x = value' binds a step variable that shadows the memory of the same name
+            if let (AssignType::Assignment, Expr::IdentExpr(var)) = (assign_type, &**old) {
+                if !data.flow.constants.contains_key(&var.ident) {
+                    data.step_vars.insert(
+                        var.ident.to_owned(),
+                        PrimitiveNull::get_literal(var.interval.to_owned()),
+                    );
+                }
+            }
+
             let (lit, name, mem_type, path) = get_var_info(old, None, data, &mut msg_data, sender)?;
 
             let primitive = match assign_type {
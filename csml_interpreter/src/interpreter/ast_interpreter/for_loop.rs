@@ -1,4 +1,4 @@
-use crate::data::primitive::PrimitiveInt;
+use crate::data::primitive::{PrimitiveInt, PrimitiveObject, PrimitiveString, PrimitiveType};
 use crate::data::{
     ast::*,
     hold::{
@@ -6,14 +6,46 @@ use crate::data::{
     },
     primitive::tools::get_array,
     warnings::DisplayWarnings,
-    Data, MessageData, MSG,
+    Data, Literal, MessageData, Position, MSG,
 };
 use crate::error_format::*;
 use crate::interpreter::interpret_scope;
 use crate::interpreter::variable_handler::expr_to_literal::expr_to_literal;
 use crate::parser::ExitCondition;
+use std::collections::HashMap;
 use std::sync::mpsc;
 
+////////////////////////////////////////////////////////////////////////////////
+// PRIVATE FUNCTIONS
+////////////////////////////////////////////////////////////////////////////////
+
+// objects are iterated as {"key": key, "value": value} pairs sorted by key
+fn get_object_pairs(literal: Literal, flow_name: &str) -> Result<Vec<Literal>, ErrorInfo> {
+    let object = Literal::get_value::<HashMap<String, Literal>>(
+        &literal.primitive,
+        flow_name,
+        literal.interval.to_owned(),
+        ERROR_FOREACH.to_owned(),
+    )?;
+
+    let mut keys: Vec<&String> = object.keys().collect();
+    keys.sort();
+
+    Ok(keys
+        .into_iter()
+        .map(|key| {
+            let mut pair = HashMap::new();
+            pair.insert(
+                "key".to_owned(),
+                PrimitiveString::get_literal(key, literal.interval.to_owned()),
+            );
+            pair.insert("value".to_owned(), object[key].to_owned());
+
+            PrimitiveObject::get_literal(&pair, literal.interval.to_owned())
+        })
+        .collect())
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // PUBLIC FUNCTION
 ////////////////////////////////////////////////////////////////////////////////
@@ -23,7 +55,7 @@ pub fn for_loop(
     index: &Option<Identifier>,
     expr: &Expr,
     block: &Block,
-    _range_interval: &Interval,
+    range_interval: &Interval,
     mut msg_data: MessageData,
     data: &mut Data,
     sender: &Option<mpsc::Sender<MSG>>,
@@ -36,7 +68,17 @@ pub fn for_loop(
         &mut msg_data,
         sender,
     )?;
-    let mut array = get_array(literal, &data.context.flow, ERROR_FOREACH.to_owned())?;
+    let mut array = match literal.primitive.get_type() {
+        PrimitiveType::PrimitiveObject => get_object_pairs(literal, &data.context.flow)?,
+        _ => get_array(literal, &data.context.flow, ERROR_FOREACH.to_owned())?,
+    };
+
+    if array.len() > data.loop_limit {
+        return Err(gen_error_info(
+            Position::new(range_interval.to_owned(), &data.context.flow),
+            format!("{} ({} iterations)", ERROR_LOOP_LIMIT, data.loop_limit),
+        ));
+    }
 
     let mut value_skipped = 0;
     let array = hold_index_start_loop(data, &mut array, &mut value_skipped);
@@ -0,0 +1,87 @@
+use crate::data::{
+    ast::{Expr, Infix, MatchArm},
+    warnings::DisplayWarnings,
+    Data, Literal, MessageData, MSG,
+};
+use crate::error_format::*;
+use crate::interpreter::{
+    interpret_scope,
+    variable_handler::{
+        expr_to_literal,
+        operations::{evaluate_infix, valid_literal},
+    },
+};
+use std::sync::mpsc;
+
+////////////////////////////////////////////////////////////////////////////////
+// PRIVATE FUNCTIONS
+////////////////////////////////////////////////////////////////////////////////
+
+// patterns are compared with the same equality as '=='
+fn valid_arm(
+    arm: &MatchArm,
+    value: &Result<Literal, ErrorInfo>,
+    data: &mut Data,
+    msg_data: &mut MessageData,
+    sender: &Option<mpsc::Sender<MSG>>,
+) -> bool {
+    let patterns = match &arm.patterns {
+        Some(patterns) => patterns,
+        None => return true,
+    };
+
+    patterns.iter().any(|pattern| {
+        let pattern = expr_to_literal(pattern, &DisplayWarnings::Off, None, data, msg_data, sender);
+
+        valid_literal(evaluate_infix(
+            &data.context.flow,
+            &Infix::Equal,
+            value.to_owned(),
+            pattern,
+        ))
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// PUBLIC FUNCTIONS
+////////////////////////////////////////////////////////////////////////////////
+
+pub fn solve_match_statement(
+    value: &Expr,
+    arms: &[MatchArm],
+    mut msg_data: MessageData,
+    data: &mut Data,
+    sender: &Option<mpsc::Sender<MSG>>,
+) -> Result<MessageData, ErrorInfo> {
+    // resume in the arm that contains the hold
+    if let Some(hold) = &data.context.hold {
+        let command_index = hold.index.command_index;
+
+        if let Some(arm) = arms
+            .iter()
+            .find(|arm| command_index <= arm.last_action_index)
+        {
+            msg_data = msg_data + interpret_scope(&arm.block, data, sender)?;
+        }
+
+        return Ok(msg_data);
+    }
+
+    let value = expr_to_literal(
+        value,
+        &DisplayWarnings::Off,
+        None,
+        data,
+        &mut msg_data,
+        sender,
+    );
+
+    for arm in arms.iter() {
+        if valid_arm(arm, &value, data, &mut msg_data, sender) {
+            msg_data = msg_data + interpret_scope(&arm.block, data, sender)?;
+            break;
+        }
+    }
+
+    Ok(msg_data)
+}
@@ -6,6 +6,7 @@ use crate::data::{
     // primitive::tools::get_array,
     Data,
     MessageData,
+    Position,
     MSG,
 };
 use crate::error_format::*;
@@ -20,12 +21,22 @@ use std::sync::mpsc;
 pub fn while_loop(
     cond: &Expr,
     block: &Block,
-    _range_interval: &Interval,
+    range_interval: &Interval,
     mut msg_data: MessageData,
     data: &mut Data,
     sender: &Option<mpsc::Sender<MSG>>,
 ) -> Result<MessageData, ErrorInfo> {
+    let mut iterations = 0;
+
     while valid_condition(cond, data, &mut msg_data, sender) {
+        if iterations >= data.loop_limit {
+            return Err(gen_error_info(
+                Position::new(range_interval.to_owned(), &data.context.flow),
+                format!("{} ({} iterations)", ERROR_LOOP_LIMIT, data.loop_limit),
+            ));
+        }
+        iterations += 1;
+
         msg_data = msg_data + interpret_scope(block, data, sender)?;
 
         match msg_data.exit_condition {
@@ -14,6 +14,9 @@ use crate::data::{
     ast::*, position::Position, tokens::*, ArgsType, Data, Literal, MessageData, MSG,
 };
 use crate::error_format::{gen_error_info, ErrorInfo, ERROR_NATIVE_COMPONENT};
+use crate::interpreter::components::{
+    check_native_component, format_native_args, format_native_component,
+};
 use crate::interpreter::variable_handler::gen_generic_component::gen_generic_component;
 use std::sync::mpsc;
 
@@ -35,7 +38,20 @@ pub fn match_native_builtin(
     data: &mut Data,
 ) -> Result<Literal, ErrorInfo> {
     if let Some(component) = data.native_component.get(name) {
-        gen_generic_component(name, false, &data.context.flow, &interval, &args, component)
+        let native_args =
+            format_native_args(name, args.clone(), &data.context.flow, data.max_duration)?;
+        check_native_component(name, &native_args, &data.context.flow)?;
+
+        let literal = gen_generic_component(
+            name,
+            false,
+            &data.context.flow,
+            &interval,
+            &native_args,
+            component,
+        )?;
+
+        format_native_component(name, literal, &args, &data.context.flow, interval)
     } else {
         Err(gen_error_info(
             Position::new(interval, &data.context.flow),
@@ -58,19 +74,21 @@ pub fn match_builtin(
         BASE64 => base64(args, &data.context.flow, interval),
         HEX => hex(args, &data.context.flow, interval),
         FN | APP => api(args, interval, data, msg_data, sender),
-        ONE_OF => one_of(args, &data.context.flow, interval),
+        ONE_OF => one_of(args, data, interval, sender),
         OR_BUILT_IN => or(args, &data.context.flow, interval),
         SHUFFLE => shuffle(args, &data.context.flow, interval),
         LENGTH => length(args, &data.context.flow, interval),
         FIND => find(args, &data.context.flow, interval),
         RANDOM => random(interval),
-        DEBUG => debug(args, interval),
+        RANDOM_INT => random_int(args, &data.context.flow, interval),
+        DEBUG => debug(args, &data.context.flow, interval),
         FLOOR => floor(args, &data.context.flow, interval),
         UUID => uuid_command(args, &data.context.flow, interval),
         JWT => jwt(args, &data.context.flow, interval),
         CRYPTO => crypto(args, &data.context.flow, interval),
         TIME => time(args, &data.context.flow, interval),
         EXISTS => exists(args, data, interval),
+        ARRAY_BUILT_IN => array(args, &data.context.flow, interval),
 
         //old builtin
         _object => object(args, &data.context.flow, interval),
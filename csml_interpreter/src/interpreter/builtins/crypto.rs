@@ -1,5 +1,5 @@
 use crate::data::position::Position;
-use crate::data::primitive::PrimitiveObject;
+use crate::data::primitive::{PrimitiveObject, PrimitiveType};
 use std::collections::HashMap;
 
 use crate::data::{ast::Interval, ArgsType, Literal};
@@ -7,7 +7,7 @@ use crate::error_format::*;
 
 pub fn crypto(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Literal, ErrorInfo> {
     match args.get("value", 0) {
-        Some(value) => {
+        Some(value) if value.primitive.get_type() == PrimitiveType::PrimitiveString => {
             let mut map: HashMap<String, Literal> = HashMap::new();
 
             map.insert("value".to_owned(), value.to_owned());
@@ -16,7 +16,11 @@ pub fn crypto(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Lit
             result.set_content_type("crypto");
             Ok(result)
         }
-        _ => Err(gen_error_info(
+        Some(value) => Err(gen_error_info(
+            Position::new(value.interval, flow_name),
+            ERROR_CRYPTO.to_owned(),
+        )),
+        None => Err(gen_error_info(
             Position::new(interval, flow_name),
             ERROR_CRYPTO.to_owned(),
         )),
@@ -1,15 +1,99 @@
 use crate::data::position::Position;
-use crate::data::primitive::{object::PrimitiveObject, PrimitiveType};
+use crate::data::primitive::{
+    object::PrimitiveObject, PrimitiveArray, PrimitiveInt, PrimitiveString, PrimitiveType,
+};
 use crate::data::{ast::Interval, ArgsType, Literal};
 use crate::error_format::*;
 use std::collections::HashMap;
 
-pub fn debug(args: ArgsType, interval: Interval) -> Result<Literal, ErrorInfo> {
-    Ok(args.args_to_debug(interval))
+fn interval_to_literal(interval: Interval) -> Literal {
+    let mut map = HashMap::new();
+
+    map.insert(
+        "start_line".to_owned(),
+        PrimitiveInt::get_literal(interval.start_line as i64, interval),
+    );
+    map.insert(
+        "start_column".to_owned(),
+        PrimitiveInt::get_literal(interval.start_column as i64, interval),
+    );
+    if let (Some(end_line), Some(end_column)) = (interval.end_line, interval.end_column) {
+        map.insert(
+            "end_line".to_owned(),
+            PrimitiveInt::get_literal(end_line as i64, interval),
+        );
+        map.insert(
+            "end_column".to_owned(),
+            PrimitiveInt::get_literal(end_column as i64, interval),
+        );
+    }
+
+    PrimitiveObject::get_literal(&map, interval)
 }
 
-// TODO: old builtin need to be rm when no one use it
+/// Debug(value) and Debug(value, "label") report the value, its type and its
+/// position in the flow, any other list of positional arguments keeps the
+/// {"args": [...]} message of the debug action
+pub fn debug(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Literal, ErrorInfo> {
+    if let ArgsType::Normal(_) = args {
+        let is_labelled = match args.get("label", 1) {
+            Some(label) => label.primitive.get_type() == PrimitiveType::PrimitiveString,
+            None => false,
+        };
+
+        if args.len() != 1 && !(args.len() == 2 && is_labelled) {
+            return Ok(args.args_to_debug(interval));
+        }
+    }
+
+    let value = match args.get("value", 0) {
+        Some(value) if args.len() <= 2 => value,
+        _ => {
+            return Err(gen_error_info(
+                Position::new(interval, flow_name),
+                ERROR_DEBUG.to_owned(),
+            ))
+        }
+    };
+
+    let mut map = HashMap::new();
+
+    if let Some(label) = args.get("label", 1) {
+        if label.primitive.get_type() != PrimitiveType::PrimitiveString {
+            return Err(gen_error_info(
+                Position::new(label.interval, flow_name),
+                ERROR_DEBUG.to_owned(),
+            ));
+        }
+        map.insert("label".to_owned(), label.to_owned());
+    }
+
+    map.insert("value".to_owned(), value.to_owned());
+    map.insert(
+        "type".to_owned(),
+        PrimitiveString::get_literal(&value.primitive.get_type().to_string(), value.interval),
+    );
+    map.insert("interval".to_owned(), interval_to_literal(value.interval));
+
+    let mut result = PrimitiveObject::get_literal(&map, interval);
+    result.secure_variable = value.secure_variable;
+    result.set_content_type("debug");
+
+    Ok(result)
+}
+
+/// Object(key = value, ...) builds an object from named arguments, a key given
+/// twice keeps its last value
 pub fn object(object: ArgsType, flow_name: &str, interval: Interval) -> Result<Literal, ErrorInfo> {
+    let (ArgsType::Named(args) | ArgsType::Normal(args)) = &object;
+
+    if let Some(literal) = args.get("arg0") {
+        return Err(gen_error_info(
+            Position::new(literal.interval, flow_name),
+            ERROR_OBJECT.to_owned(),
+        ));
+    }
+
     let mut map = HashMap::new();
 
     object.populate(&mut map, &[], flow_name, interval)?;
@@ -17,9 +101,37 @@ pub fn object(object: ArgsType, flow_name: &str, interval: Interval) -> Result<L
     Ok(PrimitiveObject::get_literal(&map, interval))
 }
 
+/// Array(a, b, c) builds an array from positional arguments, in order
+pub fn array(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Literal, ErrorInfo> {
+    match args {
+        ArgsType::Normal(args) => {
+            let vec: Vec<Literal> = (0..args.len())
+                .filter_map(|index| args.get(&format!("arg{}", index)))
+                .cloned()
+                .collect();
+
+            Ok(PrimitiveArray::get_literal(&vec, interval))
+        }
+        ArgsType::Named(args) => {
+            let interval = args
+                .iter()
+                .find(|(key, _)| *key != "arg0")
+                .map_or(interval, |(_, literal)| literal.interval);
+
+            Err(gen_error_info(
+                Position::new(interval, flow_name),
+                ERROR_ARRAY.to_owned(),
+            ))
+        }
+    }
+}
+
 pub fn base64(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Literal, ErrorInfo> {
     match args.get("string", 0) {
-        Some(literal) if literal.primitive.get_type() == PrimitiveType::PrimitiveString => {
+        Some(literal)
+            if literal.primitive.get_type() == PrimitiveType::PrimitiveString
+                || literal.primitive.get_type() == PrimitiveType::PrimitiveObject =>
+        {
             let mut object: HashMap<String, Literal> = HashMap::new();
             object.insert("string".to_owned(), literal.to_owned());
 
@@ -29,9 +141,13 @@ pub fn base64(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Lit
 
             Ok(result)
         }
-        _ => Err(gen_error_info(
+        Some(literal) => Err(gen_error_info(
+            Position::new(literal.interval, flow_name),
+            ERROR_BASE64.to_owned(),
+        )),
+        None => Err(gen_error_info(
             Position::new(interval, flow_name),
-            ERROR_HTTP.to_owned(),
+            ERROR_BASE64.to_owned(),
         )),
     }
 }
@@ -1,43 +1,167 @@
 use crate::data::position::Position;
 use crate::data::primitive::{
-    PrimitiveArray, PrimitiveBoolean, PrimitiveFloat, PrimitiveInt, PrimitiveString,
+    tools, tools_random, PrimitiveArray, PrimitiveBoolean, PrimitiveFloat, PrimitiveInt,
+    PrimitiveString, PrimitiveType,
 };
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{SystemTime, SystemTimeError, UNIX_EPOCH};
 
-use crate::data::{ast::Interval, ArgsType, Literal};
+use crate::data::{ast::Interval, ArgsType, Data, Literal, MSG};
 use crate::error_format::*;
 use uuid::v1::{Context, Timestamp};
-use uuid::Uuid;
+use uuid::{Builder, Uuid};
 
+use rand::distributions::{Distribution, WeightedIndex};
 use rand::seq::SliceRandom;
 use rand::Rng;
+use std::sync::mpsc;
 
 ////////////////////////////////////////////////////////////////////////////////
 // PUBLIC FUNCTIONS
 ////////////////////////////////////////////////////////////////////////////////
 
-pub fn one_of(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Literal, ErrorInfo> {
-    match args.get("array", 0) {
-        Some(literal) => {
-            let res = Literal::get_value::<Vec<Literal>>(
-                &literal.primitive,
-                flow_name,
-                literal.interval,
-                ERROR_ONE_OF.to_owned(),
-            )?;
-            match res.get(rand::thread_rng().gen_range(0..res.len())) {
-                Some(lit) => Ok(lit.to_owned()),
-                None => Err(gen_error_info(
-                    Position::new(literal.interval, flow_name),
-                    ERROR_ONE_OF.to_owned(),
-                )),
+fn get_one_of_weights(
+    weights: &Literal,
+    len: usize,
+    flow_name: &str,
+) -> Result<Vec<f64>, ErrorInfo> {
+    let error = |interval| {
+        gen_error_info(
+            Position::new(interval, flow_name),
+            ERROR_ONE_OF_WEIGHTS.to_owned(),
+        )
+    };
+
+    let array = match weights.primitive.as_any().downcast_ref::<PrimitiveArray>() {
+        Some(array) if array.value.len() == len => &array.value,
+        _ => return Err(error(weights.interval)),
+    };
+
+    array
+        .iter()
+        .map(|weight| {
+            let value = match (
+                weight.primitive.as_any().downcast_ref::<PrimitiveInt>(),
+                weight.primitive.as_any().downcast_ref::<PrimitiveFloat>(),
+            ) {
+                (Some(int), _) => int.value as f64,
+                (_, Some(float)) => float.value,
+                _ => return Err(error(weight.interval)),
+            };
+
+            if value.is_finite() && value > 0.0 {
+                Ok(value)
+            } else {
+                Err(error(weight.interval))
             }
+        })
+        .collect()
+}
+
+/// OneOf(array, weights = [..], no_repeat = true), with no_repeat the same
+/// OneOf in a step never picks the same value twice in a row, the last pick is
+/// kept in the conversation context
+pub fn one_of(
+    args: ArgsType,
+    data: &mut Data,
+    interval: Interval,
+    sender: &Option<mpsc::Sender<MSG>>,
+) -> Result<Literal, ErrorInfo> {
+    let flow_name = data.context.flow.to_owned();
+
+    let literal = match args.get("array", 0) {
+        Some(literal) => literal,
+        None => {
+            return Err(gen_error_info(
+                Position::new(interval, &flow_name),
+                ERROR_ONE_OF.to_owned(),
+            ))
         }
-        None => Err(gen_error_info(
-            Position::new(interval, flow_name),
+    };
+
+    let array = Literal::get_value::<Vec<Literal>>(
+        &literal.primitive,
+        &flow_name,
+        literal.interval,
+        ERROR_ONE_OF.to_owned(),
+    )?;
+
+    if array.is_empty() {
+        return Err(gen_error_info(
+            Position::new(literal.interval, &flow_name),
             ERROR_ONE_OF.to_owned(),
-        )),
+        ));
     }
+
+    let weights = match args.get("weights", 1) {
+        Some(weights) => Some(get_one_of_weights(weights, array.len(), &flow_name)?),
+        None => None,
+    };
+
+    let no_repeat = match args.get("no_repeat", 2) {
+        Some(no_repeat) => match no_repeat
+            .primitive
+            .as_any()
+            .downcast_ref::<PrimitiveBoolean>()
+        {
+            Some(no_repeat) => no_repeat.value,
+            None => {
+                return Err(gen_error_info(
+                    Position::new(no_repeat.interval, &flow_name),
+                    ERROR_ONE_OF_NO_REPEAT.to_owned(),
+                ))
+            }
+        },
+        None => false,
+    };
+
+    let key = format!(
+        "{}.{}:{}:{}",
+        flow_name,
+        data.context.step.get_step(),
+        interval.start_line,
+        interval.start_column
+    );
+    let last = match data.context.one_of.get(&key) {
+        Some(last) if no_repeat && array.len() > 1 && *last < array.len() => Some(*last),
+        _ => None,
+    };
+
+    let index = match (weights, last) {
+        (Some(mut weights), last) => {
+            if let Some(last) = last {
+                weights[last] = 0.0;
+            }
+
+            match WeightedIndex::new(&weights) {
+                Ok(weights) => tools_random::with_rng(|rng| weights.sample(rng)),
+                Err(_) => {
+                    return Err(gen_error_info(
+                        Position::new(interval, &flow_name),
+                        ERROR_ONE_OF_WEIGHTS.to_owned(),
+                    ))
+                }
+            }
+        }
+        (None, Some(last)) => {
+            let index = tools_random::with_rng(|rng| rng.gen_range(0..array.len() - 1));
+
+            if index >= last {
+                index + 1
+            } else {
+                index
+            }
+        }
+        (None, None) => tools_random::with_rng(|rng| rng.gen_range(0..array.len())),
+    };
+
+    let value = array[index].to_owned();
+
+    if no_repeat {
+        data.context.one_of.insert(key.to_owned(), index);
+        MSG::send(sender, MSG::OneOf { key, index });
+    }
+
+    Ok(value)
 }
 
 pub fn or(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Literal, ErrorInfo> {
@@ -59,11 +183,11 @@ pub fn shuffle(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Li
             let res = Literal::get_value::<Vec<Literal>>(
                 &literal.primitive,
                 flow_name,
-                interval,
+                literal.interval,
                 ERROR_SHUFFLE.to_owned(),
             )?;
             let mut vec = res.to_owned();
-            vec.shuffle(&mut rand::thread_rng());
+            tools_random::with_rng(|rng| vec.shuffle(rng));
             Ok(PrimitiveArray::get_literal(&vec, literal.interval))
         }
         None => Err(gen_error_info(
@@ -75,35 +199,13 @@ pub fn shuffle(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Li
 
 pub fn length(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Literal, ErrorInfo> {
     match args.get("length", 0) {
-        Some(literal) => {
-            if let Ok(res) = Literal::get_value::<Vec<Literal>>(
-                &literal.primitive,
-                flow_name,
-                interval,
-                ERROR_LENGTH.to_owned(),
-            ) {
-                return Ok(PrimitiveInt::get_literal(
-                    res.len() as i64,
-                    literal.interval,
-                ));
-            }
-            if let Ok(res) = Literal::get_value::<String>(
-                &literal.primitive,
-                flow_name,
-                interval,
-                ERROR_LENGTH.to_owned(),
-            ) {
-                return Ok(PrimitiveInt::get_literal(
-                    res.len() as i64,
-                    literal.interval,
-                ));
-            }
-
-            Err(gen_error_info(
-                Position::new(interval, flow_name),
-                ERROR_LENGTH.to_owned(),
-            ))
-        }
+        Some(literal) => match tools::get_length(&*literal.primitive) {
+            Ok(length) => Ok(PrimitiveInt::get_literal(length as i64, literal.interval)),
+            Err(err) => Err(gen_error_info(
+                Position::new(literal.interval, flow_name),
+                err,
+            )),
+        },
         None => Err(gen_error_info(
             Position::new(interval, flow_name),
             ERROR_LENGTH.to_owned(),
@@ -111,72 +213,117 @@ pub fn length(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Lit
     }
 }
 
+/// substring test when the haystack is a string (case insensitive unless
+/// case_sensitive is true), element equality when it is an array
 pub fn find(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Literal, ErrorInfo> {
-    let mut string = None;
-    let mut case = false;
-
-    if let Some(literal) = args.get("in", 1) {
-        if let Ok(res) = Literal::get_value::<String>(
-            &literal.primitive,
-            flow_name,
-            interval,
-            ERROR_FIND.to_owned(),
-        ) {
-            string = Some(res);
-        }
-    } else if string.is_none() {
-        return Err(gen_error_info(
+    let error = |interval: Interval| {
+        Err(gen_error_info(
             Position::new(interval, flow_name),
             ERROR_FIND.to_owned(),
-        ));
-    }
+        ))
+    };
 
-    if let Some(literal) = args.get("in", 1) {
-        if let Ok(res) = Literal::get_value::<bool>(
-            &literal.primitive,
-            flow_name,
-            interval,
-            ERROR_FIND.to_owned(),
-        ) {
-            case = *res;
-        }
-    }
+    let (value, haystack) = match (args.get("value", 0), args.get("in", 1)) {
+        (Some(value), Some(haystack)) if args.len() <= 3 => (value, haystack),
+        _ => return error(interval),
+    };
 
-    match (args.get("value", 0), string) {
-        (Some(literal), Some(string)) => {
-            let res = Literal::get_value::<String>(
-                &literal.primitive,
+    // 'case' is the name used before case_sensitive
+    let case_sensitive = match args
+        .get("case_sensitive", 2)
+        .or_else(|| args.get("case", 2))
+    {
+        Some(literal) => match literal
+            .primitive
+            .as_any()
+            .downcast_ref::<PrimitiveBoolean>()
+        {
+            Some(boolean) => boolean.value,
+            None => return error(literal.interval),
+        },
+        None => false,
+    };
+
+    match haystack.primitive.get_type() {
+        PrimitiveType::PrimitiveString => {
+            let string = Literal::get_value::<String>(
+                &haystack.primitive,
                 flow_name,
-                interval,
+                haystack.interval,
                 ERROR_FIND.to_owned(),
             )?;
-            if case {
-                Ok(PrimitiveBoolean::get_literal(
-                    string.contains(res),
-                    interval,
-                ))
+            let value = Literal::get_value::<String>(
+                &value.primitive,
+                flow_name,
+                value.interval,
+                ERROR_FIND.to_owned(),
+            )?;
+
+            let result = if case_sensitive {
+                string.contains(value.as_str())
             } else {
-                Ok(PrimitiveBoolean::get_literal(
-                    string.to_lowercase().contains(&res.to_lowercase()),
-                    interval,
-                ))
-            }
+                string.to_lowercase().contains(&value.to_lowercase())
+            };
+
+            Ok(PrimitiveBoolean::get_literal(result, interval))
         }
-        (_, _) => Err(gen_error_info(
-            Position::new(interval, flow_name),
-            ERROR_FIND.to_owned(),
-        )),
+        PrimitiveType::PrimitiveArray => {
+            let array = Literal::get_value::<Vec<Literal>>(
+                &haystack.primitive,
+                flow_name,
+                haystack.interval,
+                ERROR_FIND.to_owned(),
+            )?;
+
+            let result = array
+                .iter()
+                .any(|element| element.primitive.is_eq(&*value.primitive));
+
+            Ok(PrimitiveBoolean::get_literal(result, interval))
+        }
+        _ => error(haystack.interval),
     }
 }
 
 pub fn random(interval: Interval) -> Result<Literal, ErrorInfo> {
-    let mut rng = rand::thread_rng();
-
-    let random: f64 = rng.gen();
+    let random: f64 = tools_random::with_rng(|rng| rng.gen());
 
     Ok(PrimitiveFloat::get_literal(random, interval))
 }
 
+pub fn random_int(
+    args: ArgsType,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<Literal, ErrorInfo> {
+    let get_bound = |name: &str, index: usize| match args.get(name, index) {
+        Some(literal) => Literal::get_value::<i64>(
+            &literal.primitive,
+            flow_name,
+            literal.interval,
+            ERROR_RANDOM_INT.to_owned(),
+        )
+        .copied(),
+        None => Err(gen_error_info(
+            Position::new(interval, flow_name),
+            ERROR_RANDOM_INT.to_owned(),
+        )),
+    };
+
+    let (min, max) = (get_bound("min", 0)?, get_bound("max", 1)?);
+
+    if min > max {
+        return Err(gen_error_info(
+            Position::new(interval, flow_name),
+            format!("{} (min: {}, max: {})", ERROR_RANDOM_INT_RANGE, min, max),
+        ));
+    }
+
+    let random = tools_random::with_rng(|rng| rng.gen_range(min..=max));
+
+    Ok(PrimitiveInt::get_literal(random, interval))
+}
+
 pub fn floor(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Literal, ErrorInfo> {
     match args.get("float", 0) {
         Some(literal) => {
@@ -195,52 +342,53 @@ pub fn floor(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Lite
     }
 }
 
+const BASE62: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+fn gen_uuid(mode: &str) -> Result<String, SystemTimeError> {
+    tools_random::with_id_rng(|rng| match mode {
+        "v1" => {
+            let time = SystemTime::now().duration_since(UNIX_EPOCH)?;
+            let context = Context::new(rng.gen());
+            let ts = Timestamp::from_unix(&context, time.as_secs(), time.subsec_nanos());
+            let node_id: [u8; 6] = rng.gen();
+
+            Ok(Uuid::new_v1(ts, &node_id).hyphenated().to_string())
+        }
+        // 8 base62 characters, short enough to be read or typed by a user
+        "short" => Ok((0..8)
+            .map(|_| BASE62[rng.gen_range(0..BASE62.len())] as char)
+            .collect()),
+        _ => Ok(Builder::from_random_bytes(rng.gen())
+            .into_uuid()
+            .hyphenated()
+            .to_string()),
+    })
+}
+
 pub fn uuid_command(
     args: ArgsType,
     flow_name: &str,
     interval: Interval,
 ) -> Result<Literal, ErrorInfo> {
     if args.len() == 0 {
-        return Ok(PrimitiveString::get_literal(
-            &Uuid::new_v4().to_string(),
-            interval,
-        ));
+        return Ok(PrimitiveString::get_literal(&gen_uuid("v4")?, interval));
     }
 
     match args.get("value", 0) {
-        Some(literal) => {
-            let arg = Literal::get_value::<String>(
+        Some(literal) if args.len() == 1 => {
+            let mode = Literal::get_value::<String>(
                 &literal.primitive,
                 flow_name,
-                interval,
-                ERROR_FLOOR.to_owned(),
+                literal.interval,
+                ERROR_UUID.to_owned(),
             )?;
 
-            match arg {
-                arg if arg == "v1" => {
-                    let time = SystemTime::now().duration_since(UNIX_EPOCH)?;
-                    let context = Context::new(rand::thread_rng().gen());
-                    let ts = Timestamp::from_unix(&context, time.as_secs(), time.subsec_nanos());
-
-                    let node_id = &[
-                        rand::thread_rng().gen(),
-                        rand::thread_rng().gen(),
-                        rand::thread_rng().gen(),
-                        rand::thread_rng().gen(),
-                        rand::thread_rng().gen(),
-                        rand::thread_rng().gen(),
-                    ];
-                    Ok(PrimitiveString::get_literal(
-                        &Uuid::new_v1(ts, node_id).hyphenated().to_string(),
-                        interval,
-                    ))
+            match mode.as_str() {
+                "v1" | "v4" | "short" => {
+                    Ok(PrimitiveString::get_literal(&gen_uuid(mode)?, interval))
                 }
-                arg if arg == "v4" => Ok(PrimitiveString::get_literal(
-                    &Uuid::new_v4().to_string(),
-                    interval,
-                )),
                 _ => Err(gen_error_info(
-                    Position::new(interval, flow_name),
+                    Position::new(literal.interval, flow_name),
                     ERROR_UUID.to_owned(),
                 )),
             }
@@ -1,13 +1,18 @@
 use crate::data::error_info::ErrorInfo;
 use crate::data::position::Position;
-use crate::data::primitive::{PrimitiveInt, PrimitiveObject, PrimitiveString, PrimitiveType};
+use crate::data::primitive::{
+    tools::get_number, PrimitiveInt, PrimitiveNull, PrimitiveObject, PrimitiveString, PrimitiveType,
+};
 use crate::data::{ast::Interval, csml_logs::*, ArgsType, Literal};
 use crate::error_format::*;
+use crate::interpreter::json_to_literal;
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::env;
+use std::time::Duration;
 
 use std::sync::Arc;
-use ureq::{Request, Response};
+use ureq::Response;
 
 use rustls::{
     client::{ServerCertVerified, ServerCertVerifier, ServerName},
@@ -18,6 +23,80 @@ use rustls::{
 /// DATA TYPES
 ////////////////////////////////////////////////////////////////////////////////
 
+/// timeout of an HTTP(...) request that doesn't set one with .timeout(seconds),
+/// App() and Fn() calls keep no timeout
+pub const DEFAULT_HTTP_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<serde_json::Value>,
+    pub timeout: Option<Duration>,
+    pub disable_ssl_verify: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    /// None when the body is not a valid UTF-8 sequence
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum HttpError {
+    /// the server answered with a non 2xx status
+    Status(HttpResponse),
+    /// connection failure, timeout, invalid url ...
+    Transport(String),
+}
+
+/// sends the requests built by the HTTP object. The interpreter uses ureq by
+/// default, tests replace it with set_http_client to never hit the network
+pub trait HttpClient {
+    fn call(&self, request: &HttpRequest) -> Result<HttpResponse, HttpError>;
+}
+
+struct UreqClient;
+
+impl HttpClient for UreqClient {
+    fn call(&self, request: &HttpRequest) -> Result<HttpResponse, HttpError> {
+        let method = request.method.to_uppercase();
+
+        let mut ureq_request = match request.disable_ssl_verify {
+            true => get_no_certificate_verifier_agent().request(&method, &request.url),
+            false => ureq::request(&method, &request.url),
+        };
+
+        if let Some(timeout) = request.timeout {
+            ureq_request = ureq_request.timeout(timeout);
+        }
+
+        for (key, value) in request.headers.iter() {
+            ureq_request = ureq_request.set(key, value);
+        }
+
+        let response = match &request.body {
+            Some(body) => ureq_request.send_json(body.to_owned()),
+            None => ureq_request.call(),
+        };
+
+        match response {
+            Ok(response) => Ok(to_http_response(response)),
+            Err(ureq::Error::Status(_, response)) => {
+                Err(HttpError::Status(to_http_response(response)))
+            }
+            Err(err) => Err(HttpError::Transport(err.to_string())),
+        }
+    }
+}
+
+thread_local! {
+    static CLIENT: RefCell<Option<Box<dyn HttpClient>>> = RefCell::new(None);
+}
+
 pub(crate) struct NoVerifier;
 
 impl ServerCertVerifier for NoVerifier {
@@ -72,20 +151,37 @@ fn set_http_error_info(
     error
 }
 
-fn get_request_info(response: &Response, interval: Interval) -> HashMap<String, Literal> {
+fn to_http_response(response: Response) -> HttpResponse {
+    let status = response.status();
+    let headers = response
+        .headers_names()
+        .iter()
+        .filter_map(|name| {
+            response
+                .header(name)
+                .map(|header| (name.to_owned(), header.to_owned()))
+        })
+        .collect();
+
+    HttpResponse {
+        status,
+        headers,
+        body: response.into_string().ok(),
+    }
+}
+
+fn get_request_info(response: &HttpResponse, interval: Interval) -> HashMap<String, Literal> {
     let mut response_info = HashMap::new();
 
-    let status = PrimitiveInt::get_literal(response.status() as i64, interval);
+    let status = PrimitiveInt::get_literal(response.status as i64, interval);
     response_info.insert("status".to_owned(), status);
 
     let headers = response
-        .headers_names()
+        .headers
         .iter()
-        .fold(HashMap::new(), |mut acc, name| {
-            if let Some(header) = response.header(name) {
-                let value = PrimitiveString::get_literal(header, interval);
-                acc.insert(name.to_owned(), value);
-            }
+        .fold(HashMap::new(), |mut acc, (name, header)| {
+            let value = PrimitiveString::get_literal(header, interval);
+            acc.insert(name.to_owned(), value);
             acc
         });
 
@@ -106,56 +202,6 @@ pub fn get_ssl_state(object: &HashMap<String, Literal>) -> bool {
     }
 }
 
-////////////////////////////////////////////////////////////////////////////////
-/// PUBLIC FUNCTIONS
-////////////////////////////////////////////////////////////////////////////////
-
-pub fn get_url(
-    object: &HashMap<String, Literal>,
-    flow_name: &str,
-    interval: Interval,
-) -> Result<String, ErrorInfo> {
-    let url = &mut get_value::<String>("url", object, flow_name, interval, ERROR_HTTP_GET_VALUE)?
-        .to_owned();
-
-    if object.get("query").is_some() {
-        let query = get_value::<HashMap<String, Literal>>(
-            "query",
-            object,
-            flow_name,
-            interval,
-            ERROR_HTTP_GET_VALUE,
-        )?;
-
-        let length = query.len();
-        if length > 0 {
-            url.push_str("?");
-
-            for (index, key) in query.keys().enumerate() {
-                let value = match query.get(key) {
-                    Some(val) => val.primitive.to_string(),
-                    None => {
-                        return Err(gen_error_info(
-                            Position::new(interval, flow_name),
-                            format!("'{}' {}", key, ERROR_HTTP_GET_VALUE),
-                        ))
-                    }
-                };
-
-                url.push_str(key);
-                url.push_str("=");
-                url.push_str(&value);
-
-                if index + 1 < length {
-                    url.push_str("&");
-                }
-            }
-        }
-    }
-
-    Ok(url.to_owned())
-}
-
 fn get_no_certificate_verifier_agent() -> ureq::Agent {
     let root_store = rustls::RootCertStore::empty();
 
@@ -173,64 +219,65 @@ fn get_no_certificate_verifier_agent() -> ureq::Agent {
         .build()
 }
 
-fn get_http_request(
-    method: &str,
-    url: &str,
-    flow_name: &str,
-    interval: Interval,
-    is_ssl_disable: bool,
-) -> Result<Request, ErrorInfo> {
-    if let Ok(disable_ssl_verify) = env::var("DISABLE_SSL_VERIFY") {
-        match disable_ssl_verify.parse::<bool>() {
-            Ok(low_data) if low_data || is_ssl_disable => {
-                let agent = get_no_certificate_verifier_agent();
-
-                let request = match method {
-                    delete if delete == "delete" => agent.delete(url),
-                    put if put == "put" => agent.put(url),
-                    patch if patch == "patch" => agent.request("PATCH", url),
-                    post if post == "post" => agent.post(url),
-                    get if get == "get" => agent.get(url),
-                    _ => {
-                        return Err(gen_error_info(
-                            Position::new(interval, flow_name),
-                            ERROR_HTTP_UNKNOWN_METHOD.to_string(),
-                        ))
-                    }
-                };
-
-                return Ok(request);
-            }
-            _ => {}
-        }
+fn get_method(method: &str, flow_name: &str, interval: Interval) -> Result<String, ErrorInfo> {
+    match method {
+        "delete" | "put" | "patch" | "post" | "get" => Ok(method.to_owned()),
+        _ => Err(gen_error_info(
+            Position::new(interval, flow_name),
+            ERROR_HTTP_UNKNOWN_METHOD.to_string(),
+        )),
     }
+}
 
-    let request = match method {
-        delete if delete == "delete" => ureq::delete(url),
-        put if put == "put" => ureq::put(url),
-        patch if patch == "patch" => ureq::request("PATCH", url),
-        post if post == "post" => ureq::post(url),
-        get if get == "get" => ureq::get(url),
-        _ => {
-            return Err(gen_error_info(
-                Position::new(interval, flow_name),
-                ERROR_HTTP_UNKNOWN_METHOD.to_string(),
-            ))
+/// the timeout must be a positive number of seconds small enough to fit in a
+/// Duration, anything else is reported on the timeout value itself
+pub fn get_timeout_duration(literal: &Literal, flow_name: &str) -> Result<Duration, ErrorInfo> {
+    let duration = match get_number(literal) {
+        Some(seconds) if seconds.as_f64() > 0.0 => {
+            Duration::try_from_secs_f64(seconds.as_f64()).ok()
         }
+        _ => None,
     };
 
-    Ok(request)
+    match duration {
+        Some(duration) => Ok(duration),
+        None => Err(gen_error_info(
+            Position::new(literal.interval, flow_name),
+            ERROR_HTTP_TIMEOUT.to_owned(),
+        )),
+    }
 }
 
-pub fn http_request(
+fn get_timeout(
+    object: &HashMap<String, Literal>,
+    flow_name: &str,
+    is_app_call: bool,
+) -> Result<Option<Duration>, ErrorInfo> {
+    match object.get("timeout") {
+        Some(literal) => get_timeout_duration(literal, flow_name).map(Some),
+        None if is_app_call => Ok(None),
+        None => Ok(Some(DEFAULT_HTTP_TIMEOUT)),
+    }
+}
+
+fn get_request(
     object: &HashMap<String, Literal>,
     method: &str,
     flow_name: &str,
     interval: Interval,
     is_app_call: bool,
-) -> Result<(serde_json::Value, HashMap<String, Literal>), ErrorInfo> {
+) -> Result<HttpRequest, ErrorInfo> {
     let url = get_url(object, flow_name, interval)?;
-    let is_ssl_disable = get_ssl_state(object);
+    let method = get_method(method, flow_name, interval)?;
+    let timeout = get_timeout(object, flow_name, is_app_call)?;
+
+    let disable_ssl_verify = match env::var("DISABLE_SSL_VERIFY") {
+        Ok(disable_ssl_verify) => match disable_ssl_verify.parse::<bool>() {
+            Ok(low_data) => low_data || get_ssl_state(object),
+            Err(_) => false,
+        },
+        Err(_) => false,
+    };
 
     let header = get_value::<HashMap<String, Literal>>(
         "header",
@@ -240,22 +287,26 @@ pub fn http_request(
         ERROR_HTTP_GET_VALUE,
     )?;
 
-    let mut request = get_http_request(method, &url, flow_name, interval, is_ssl_disable)?;
-
-    for key in header.keys() {
-        let value = match header.get(key) {
-            Some(val) => val.primitive.to_string(),
-            None => {
-                return Err(gen_error_info(
-                    Position::new(interval, flow_name),
-                    format!("'{}' {}", key, ERROR_HTTP_GET_VALUE),
-                ))
-            }
-        };
-
-        request = request.set(key, &value);
-    }
+    let headers = header
+        .iter()
+        .map(|(key, value)| (key.to_owned(), value.primitive.to_string()))
+        .collect();
+
+    Ok(HttpRequest {
+        method,
+        url,
+        headers,
+        body: object.get("body").map(|body| body.primitive.to_json()),
+        timeout,
+        disable_ssl_verify,
+    })
+}
 
+fn send_request(
+    request: &HttpRequest,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<HttpResponse, HttpError> {
     csml_logger(
         CsmlLog::new(
             None,
@@ -275,29 +326,116 @@ pub fn http_request(
         LogLvl::Debug,
     );
 
-    let response = match object.get("body") {
-        Some(body) => request.send_json(body.primitive.to_json()),
-        None => request.call(),
-    };
+    CLIENT.with(|client| match &*client.borrow() {
+        Some(client) => client.call(request),
+        None => UreqClient.call(request),
+    })
+}
+
+fn log_http_error(error_message: &str, flow_name: &str, interval: Interval) {
+    csml_logger(
+        CsmlLog::new(
+            None,
+            Some(flow_name.to_string()),
+            Some(interval.start_line),
+            format!("Http call failed: {:?}", error_message),
+        ),
+        LogLvl::Error,
+    );
+}
+
+fn get_error_message(request: &HttpRequest, error: &HttpError) -> String {
+    match error {
+        HttpError::Status(response) => {
+            format!("{}: status code {}", request.url, response.status)
+        }
+        HttpError::Transport(error) => error.to_owned(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+/// PUBLIC FUNCTIONS
+////////////////////////////////////////////////////////////////////////////////
+
+/// replace the client used to send HTTP requests on this thread, None restores
+/// the default ureq client
+pub fn set_http_client(client: Option<Box<dyn HttpClient>>) {
+    CLIENT.with(|current| *current.borrow_mut() = client);
+}
+
+pub fn get_url(
+    object: &HashMap<String, Literal>,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<String, ErrorInfo> {
+    let url = &mut get_value::<String>("url", object, flow_name, interval, ERROR_HTTP_GET_VALUE)?
+        .to_owned();
+
+    if object.get("query").is_some() {
+        let query = get_value::<HashMap<String, Literal>>(
+            "query",
+            object,
+            flow_name,
+            interval,
+            ERROR_HTTP_GET_VALUE,
+        )?;
+
+        let length = query.len();
+        if length > 0 {
+            url.push_str("?");
 
-    match response {
+            for (index, key) in query.keys().enumerate() {
+                let value = match query.get(key) {
+                    Some(val) => val.primitive.to_string(),
+                    None => {
+                        return Err(gen_error_info(
+                            Position::new(interval, flow_name),
+                            format!("'{}' {}", key, ERROR_HTTP_GET_VALUE),
+                        ))
+                    }
+                };
+
+                url.push_str(key);
+                url.push_str("=");
+                url.push_str(&value);
+
+                if index + 1 < length {
+                    url.push_str("&");
+                }
+            }
+        }
+    }
+
+    Ok(url.to_owned())
+}
+
+pub fn http_request(
+    object: &HashMap<String, Literal>,
+    method: &str,
+    flow_name: &str,
+    interval: Interval,
+    is_app_call: bool,
+) -> Result<(serde_json::Value, HashMap<String, Literal>), ErrorInfo> {
+    let request = get_request(object, method, flow_name, interval, is_app_call)?;
+
+    match send_request(&request, flow_name, interval) {
         Ok(response) => {
             let response_info = get_request_info(&response, interval);
 
-            match response.into_string() {
-                Ok(string_value) => {
+            match response.body {
+                Some(string_value) => {
                     match serde_json::from_str::<serde_json::Value>(&string_value) {
                         Ok(json_value) => Ok((json_value, response_info)),
                         Err(_) => Ok((serde_json::json!(string_value), response_info)),
                     }
                 }
-                Err(err) => {
+                None => {
                     csml_logger(
                         CsmlLog::new(
                             None,
                             Some(flow_name.to_string()),
                             Some(interval.start_line),
-                            format!("Http response Json parsing failed: {:?}", err),
+                            "Http response Json parsing failed: invalid UTF-8 sequence".to_string(),
                         ),
                         LogLvl::Error,
                     );
@@ -308,49 +446,35 @@ pub fn http_request(
                         interval,
                     );
 
-                    let error_body =
-                        "Invalid Response format, please send a json or a valid UTF-8 sequence";
-                    error.add_info("body", PrimitiveString::get_literal(error_body, interval));
+                    error.add_info(
+                        "body",
+                        PrimitiveString::get_literal(ERROR_HTTP_RESPONSE_BODY, interval),
+                    );
                     Err(error)
                 }
             }
         }
         Err(err) => {
             // if this function is call by the APP system hide the apps_endpoint for de error message
-            let error_message = match is_app_call {
-                true => {
-                    if let ureq::Error::Status(code, _) = err {
-                        format!("Apps service: status code {}", code)
-                    } else {
-                        format!("Apps service: error")
-                    }
+            let error_message = match (is_app_call, &err) {
+                (true, HttpError::Status(response)) => {
+                    format!("Apps service: status code {}", response.status)
                 }
-                false => err.to_string(),
+                (true, HttpError::Transport(_)) => "Apps service: error".to_owned(),
+                (false, err) => get_error_message(&request, err),
             };
 
-            csml_logger(
-                CsmlLog::new(
-                    None,
-                    Some(flow_name.to_string()),
-                    Some(interval.start_line),
-                    format!("Http call failed: {:?}", error_message),
-                ),
-                LogLvl::Error,
-            );
+            log_http_error(&error_message, flow_name, interval);
 
-            if let ureq::Error::Status(_, response) = err {
+            if let HttpError::Status(response) = err {
                 let response_info = get_request_info(&response, interval);
 
                 let mut error =
                     set_http_error_info(&response_info, error_message, flow_name, interval);
 
-                let body = match response.into_string() {
-                    Ok(body) => body,
-                    Err(_) => {
-                        "Invalid Response format, please send a json or a valid UTF-8 sequence"
-                            .to_owned()
-                    }
-                };
+                let body = response
+                    .body
+                    .unwrap_or_else(|| ERROR_HTTP_RESPONSE_BODY.to_owned());
 
                 error.add_info("body", PrimitiveString::get_literal(&body, interval));
 
@@ -365,6 +489,58 @@ pub fn http_request(
     }
 }
 
+/// send the request and return its status, headers and body (parsed as JSON
+/// when possible) in an object. Non 2xx statuses and network failures do not
+/// interrupt the flow, they are reported in the "error" field of the object
+pub fn http_fetch(
+    object: &HashMap<String, Literal>,
+    method: &str,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<Literal, ErrorInfo> {
+    let request = get_request(object, method, flow_name, interval, false)?;
+    let mut result = HashMap::new();
+
+    let response = match send_request(&request, flow_name, interval) {
+        Ok(response) => Some(response),
+        Err(err) => {
+            let error_message = get_error_message(&request, &err);
+            log_http_error(&error_message, flow_name, interval);
+
+            result.insert(
+                "error".to_owned(),
+                PrimitiveString::get_literal(&error_message, interval),
+            );
+
+            match err {
+                HttpError::Status(response) => Some(response),
+                HttpError::Transport(_) => None,
+            }
+        }
+    };
+
+    if let Some(response) = response {
+        result.extend(get_request_info(&response, interval));
+
+        let body = match response.body {
+            Some(body) => match serde_json::from_str::<serde_json::Value>(&body) {
+                Ok(json_value) => json_to_literal(&json_value, interval, flow_name)?,
+                Err(_) => PrimitiveString::get_literal(&body, interval),
+            },
+            None => {
+                result.insert(
+                    "error".to_owned(),
+                    PrimitiveString::get_literal(ERROR_HTTP_RESPONSE_BODY, interval),
+                );
+                PrimitiveNull::get_literal(interval)
+            }
+        };
+        result.insert("body".to_owned(), body);
+    }
+
+    Ok(PrimitiveObject::get_literal(&result, interval))
+}
+
 pub fn http(args: ArgsType, flow_name: &str, interval: Interval) -> Result<Literal, ErrorInfo> {
     let mut http: HashMap<String, Literal> = HashMap::new();
     let mut header = HashMap::new();
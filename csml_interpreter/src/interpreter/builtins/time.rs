@@ -1,7 +1,6 @@
 use crate::data::error_info::ErrorInfo;
-use crate::data::primitive::{PrimitiveInt, PrimitiveObject};
+use crate::data::primitive::{tools_time, PrimitiveInt, PrimitiveObject};
 use crate::data::{ast::Interval, ArgsType, Literal};
-use chrono::Utc;
 use std::collections::HashMap;
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -10,7 +9,7 @@ use std::collections::HashMap;
 
 pub fn time(_args: ArgsType, _flow_name: &str, interval: Interval) -> Result<Literal, ErrorInfo> {
     let mut time: HashMap<String, Literal> = HashMap::new();
-    let date = Utc::now();
+    let date = tools_time::now();
 
     time.insert(
         "milliseconds".to_owned(),
@@ -4,14 +4,24 @@ pub mod card;
 pub mod carousel;
 pub mod file;
 pub mod image;
+pub mod location;
 pub mod question;
+pub mod quick_reply;
 pub mod text;
 pub mod typing;
 pub mod url;
 pub mod video;
 pub mod wait;
 
-use crate::data::error_info::ErrorInfo;
+use crate::data::tokens::{
+    AUDIO, BUTTON, CARD, FILE, LOCATION, QUESTION, QUICK_REPLIES, QUICK_REPLY, TYPING, VIDEO, WAIT,
+};
+use crate::data::{
+    error_info::ErrorInfo, position::Position, primitive::PrimitiveString, ArgsType, Interval,
+    Literal,
+};
+use crate::error_format::{gen_error_info, ERROR_TYPING, ERROR_WAIT};
+use ::url::Url;
 use std::io::prelude::*;
 use std::path::Path;
 use std::{env, fs};
@@ -65,7 +75,10 @@ pub fn load_components() -> Result<serde_json::Map<String, serde_json::Value>, E
     carousel::add_carousel(&mut map);
     file::add_file(&mut map);
     image::add_image(&mut map);
+    location::add_location(&mut map);
     question::add_question(&mut map);
+    quick_reply::add_quick_reply(&mut map);
+    quick_reply::add_quick_replies(&mut map);
     text::add_text(&mut map);
     typing::add_typing(&mut map);
     url::add_url(&mut map);
@@ -83,3 +96,81 @@ pub fn load_components() -> Result<serde_json::Map<String, serde_json::Value>, E
 
     Ok(map)
 }
+
+/// media urls must be strings with an http or https scheme, the error points
+/// to the argument
+pub fn check_media_url(url: &Literal, error: &str, flow_name: &str) -> Result<(), ErrorInfo> {
+    let is_valid = match url.primitive.as_any().downcast_ref::<PrimitiveString>() {
+        Some(string) => match Url::parse(&string.value) {
+            Ok(parsed) => parsed.scheme() == "http" || parsed.scheme() == "https",
+            Err(_) => false,
+        },
+        None => false,
+    };
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(gen_error_info(
+            Position::new(url.interval, flow_name),
+            error.to_owned(),
+        ))
+    }
+}
+
+/// native components whose arguments are converted before the component is
+/// generated from its JSON description
+pub fn format_native_args(
+    name: &str,
+    args: ArgsType,
+    flow_name: &str,
+    max_duration: u64,
+) -> Result<ArgsType, ErrorInfo> {
+    match name {
+        TYPING if typing::is_auto_typing(&args) => Ok(typing::format_auto_typing(args)),
+        TYPING => wait::format_duration(args, ERROR_TYPING, flow_name, max_duration),
+        WAIT => wait::format_duration(args, ERROR_WAIT, flow_name, max_duration),
+        _ => Ok(args),
+    }
+}
+
+/// native components that need more than their JSON description to be
+/// validated, checked before the component is generated
+pub fn check_native_component(
+    name: &str,
+    args: &ArgsType,
+    flow_name: &str,
+) -> Result<(), ErrorInfo> {
+    match name {
+        AUDIO => audio::check_audio(args, flow_name),
+        BUTTON => button::check_button(args, flow_name),
+        CARD => card::check_card(args, flow_name),
+        FILE => file::check_file(args, flow_name),
+        LOCATION => location::check_location(args, flow_name),
+        QUESTION => question::check_question(args, flow_name),
+        QUICK_REPLY => quick_reply::check_quick_reply(args, flow_name),
+        QUICK_REPLIES => quick_reply::check_quick_replies(args, flow_name),
+        VIDEO => video::check_video(args, flow_name),
+        _ => Ok(()),
+    }
+}
+
+/// native components whose message format can't be described in JSON,
+/// applied to the generated component
+pub fn format_native_component(
+    name: &str,
+    component: Literal,
+    args: &ArgsType,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<Literal, ErrorInfo> {
+    match name {
+        BUTTON => button::format_button(component, args, flow_name, interval),
+        FILE => file::format_file(component, flow_name, interval),
+        QUESTION => question::format_question(component, flow_name, interval),
+        QUICK_REPLY => Ok(quick_reply::format_quick_reply(component, interval)),
+        QUICK_REPLIES => quick_reply::format_quick_replies(args, flow_name, interval),
+        TYPING => typing::format_typing(component, args, flow_name, interval),
+        _ => Ok(component),
+    }
+}
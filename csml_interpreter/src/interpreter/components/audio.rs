@@ -1,3 +1,11 @@
+use crate::data::{
+    position::Position,
+    primitive::{PrimitiveInt, PrimitiveType},
+    ArgsType,
+};
+use crate::error_format::*;
+use crate::interpreter::components::check_media_url;
+
 pub fn add_audio(map: &mut serde_json::Map<String, serde_json::Value>) {
     map.insert(
         "Audio".to_owned(),
@@ -9,9 +17,51 @@ pub fn add_audio(map: &mut serde_json::Map<String, serde_json::Value>) {
                             "required": true,
                             "type": "String"
                         }
+                    },
+                    {
+                        "duration": {
+                            "required": false,
+                            "type": "Number"
+                        }
+                    },
+                    {
+                        "caption": {
+                            "required": false,
+                            "type": "String"
+                        }
                     }
                 ]
             }
         ),
     );
 }
+
+/// duration is a number of seconds and must be a non-negative int
+pub fn check_audio(args: &ArgsType, flow_name: &str) -> Result<(), ErrorInfo> {
+    if let Some(url) = args.get("url", 0) {
+        check_media_url(url, ERROR_AUDIO_URL, flow_name)?;
+    }
+
+    if let Some(duration) = args.get("duration", 1) {
+        match duration.primitive.as_any().downcast_ref::<PrimitiveInt>() {
+            Some(seconds) if seconds.value >= 0 => {}
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(duration.interval, flow_name),
+                    ERROR_AUDIO_DURATION.to_owned(),
+                ))
+            }
+        }
+    }
+
+    if let Some(caption) = args.get("caption", 2) {
+        if caption.primitive.get_type() != PrimitiveType::PrimitiveString {
+            return Err(gen_error_info(
+                Position::new(caption.interval, flow_name),
+                ERROR_AUDIO_CAPTION.to_owned(),
+            ));
+        }
+    }
+
+    Ok(())
+}
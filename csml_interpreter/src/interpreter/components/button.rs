@@ -1,3 +1,12 @@
+use crate::data::{position::Position, primitive::PrimitiveType, ArgsType, Interval, Literal};
+use crate::error_format::*;
+use crate::interpreter::components::check_media_url;
+
+use std::collections::HashMap;
+
+// a Button with an url is a link, it opens the url instead of sending a
+// payload so it can't have both
+
 pub fn add_button(map: &mut serde_json::Map<String, serde_json::Value>) {
     map.insert(
         "Button".to_owned(),
@@ -30,9 +39,67 @@ pub fn add_button(map: &mut serde_json::Map<String, serde_json::Value>) {
                                 {"$_get": "payload" }
                             ]
                         }
+                    },
+                    {
+                        "url": {
+                            "required": false,
+                            "type": "String"
+                        }
+                    },
+                    {
+                        "disabled": {
+                            "required": false,
+                            "type": "Bool"
+                        }
                     }
                 ]
             }
         ),
     );
 }
+
+pub fn check_button(args: &ArgsType, flow_name: &str) -> Result<(), ErrorInfo> {
+    if let Some(url) = args.get("url", 3) {
+        check_media_url(url, ERROR_BUTTON_URL, flow_name)?;
+
+        if let Some(payload) = args.get("payload", 1) {
+            return Err(gen_error_info(
+                Position::new(payload.interval, flow_name),
+                ERROR_BUTTON_URL_PAYLOAD.to_owned(),
+            ));
+        }
+    }
+
+    match args.get("disabled", 4) {
+        Some(disabled) if disabled.primitive.get_type() != PrimitiveType::PrimitiveBoolean => {
+            Err(gen_error_info(
+                Position::new(disabled.interval, flow_name),
+                ERROR_BUTTON_DISABLED.to_owned(),
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// link buttons don't send anything back, the default payload and accepts are
+/// removed
+pub fn format_button(
+    mut component: Literal,
+    args: &ArgsType,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<Literal, ErrorInfo> {
+    if args.get("url", 3).is_some() {
+        let content = Literal::get_mut_value::<HashMap<String, Literal>>(
+            &mut component.primitive,
+            flow_name,
+            interval,
+            ERROR_BUTTON.to_owned(),
+        )?;
+
+        content.remove("payload");
+        content.remove("accepts");
+    }
+
+    Ok(component)
+}
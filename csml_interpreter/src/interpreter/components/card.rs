@@ -1,3 +1,6 @@
+use crate::data::{position::Position, primitive::PrimitiveType, ArgsType, Literal};
+use crate::error_format::*;
+
 pub fn add_card(map: &mut serde_json::Map<String, serde_json::Value>) {
     map.insert(
         "Card".to_owned(),
@@ -15,9 +18,54 @@ pub fn add_card(map: &mut serde_json::Map<String, serde_json::Value>) {
                             "required": false,
                             "type": "Array"
                         }
+                    },
+                    {
+                        "subtitle": {
+                            "required": false,
+                            "type": "String"
+                        }
+                    },
+                    {
+                        "image_url": {
+                            "required": false,
+                            "type": "String"
+                        }
                     }
                 ]
             }
         ),
     );
 }
+
+/// title, subtitle and image_url must be strings and buttons an array of
+/// Button components, errors point to the faulty argument
+pub fn check_card(args: &ArgsType, flow_name: &str) -> Result<(), ErrorInfo> {
+    let check_string = |literal: Option<&Literal>, error: &str| match literal {
+        Some(literal) if literal.primitive.get_type() != PrimitiveType::PrimitiveString => Err(
+            gen_error_info(Position::new(literal.interval, flow_name), error.to_owned()),
+        ),
+        _ => Ok(()),
+    };
+
+    check_string(args.get("title", 0), ERROR_CARD_TITLE)?;
+    check_string(args.get("subtitle", 2), ERROR_CARD_SUBTITLE)?;
+    check_string(args.get("image_url", 3), ERROR_CARD_IMAGE_URL)?;
+
+    if let Some(buttons) = args.get("buttons", 1) {
+        let array = Literal::get_value::<Vec<Literal>>(
+            &buttons.primitive,
+            flow_name,
+            buttons.interval,
+            ERROR_CARD_BUTTON.to_owned(),
+        )?;
+
+        if let Some(button) = array.iter().find(|button| button.content_type != "button") {
+            return Err(gen_error_info(
+                Position::new(button.interval, flow_name),
+                ERROR_CARD_BUTTON.to_owned(),
+            ));
+        }
+    }
+
+    Ok(())
+}
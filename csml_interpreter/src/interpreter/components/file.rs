@@ -1,3 +1,35 @@
+use crate::data::{
+    csml_logs::{csml_logger, CsmlLog, LogLvl},
+    position::Position,
+    primitive::{PrimitiveString, PrimitiveType},
+    warnings::WARNING_FILE_MIME_TYPE,
+    ArgsType, Interval, Literal,
+};
+use crate::error_format::*;
+use crate::interpreter::components::check_media_url;
+
+use std::collections::HashMap;
+use url::Url;
+
+const KNOWN_MIME_TYPES: &[&str] = &[
+    "application/json",
+    "application/msword",
+    "application/pdf",
+    "application/vnd.ms-excel",
+    "application/vnd.ms-powerpoint",
+    "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    "application/zip",
+    "audio/mpeg",
+    "image/gif",
+    "image/jpeg",
+    "image/png",
+    "text/csv",
+    "text/plain",
+    "video/mp4",
+];
+
 pub fn add_file(map: &mut serde_json::Map<String, serde_json::Value>) {
     map.insert(
         "File".to_owned(),
@@ -9,9 +41,111 @@ pub fn add_file(map: &mut serde_json::Map<String, serde_json::Value>) {
                             "required": true,
                             "type": "String"
                         }
+                    },
+                    {
+                        "filename": {
+                            "required": false,
+                            "type": "String"
+                        }
+                    },
+                    {
+                        "mime_type": {
+                            "required": false,
+                            "type": "String"
+                        }
                     }
                 ]
             }
         ),
     );
 }
+
+/// unknown mime types are only logged as a warning, channels may support more
+/// types than the ones we know about
+pub fn check_file(args: &ArgsType, flow_name: &str) -> Result<(), ErrorInfo> {
+    if let Some(url) = args.get("url", 0) {
+        check_media_url(url, ERROR_FILE_URL, flow_name)?;
+    }
+
+    if let Some(filename) = args.get("filename", 1) {
+        if filename.primitive.get_type() != PrimitiveType::PrimitiveString {
+            return Err(gen_error_info(
+                Position::new(filename.interval, flow_name),
+                ERROR_FILE_FILENAME.to_owned(),
+            ));
+        }
+    }
+
+    if let Some(mime_type) = args.get("mime_type", 2) {
+        match mime_type
+            .primitive
+            .as_any()
+            .downcast_ref::<PrimitiveString>()
+        {
+            Some(string) if KNOWN_MIME_TYPES.contains(&string.value.to_lowercase().as_str()) => {}
+            Some(string) => csml_logger(
+                CsmlLog::new(
+                    None,
+                    Some(flow_name.to_string()),
+                    Some(mime_type.interval.start_line),
+                    format!("{} (mime_type: '{}')", WARNING_FILE_MIME_TYPE, string.value),
+                ),
+                LogLvl::Warn,
+            ),
+            None => {
+                return Err(gen_error_info(
+                    Position::new(mime_type.interval, flow_name),
+                    ERROR_FILE_MIME_TYPE.to_owned(),
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// without a filename, use the last segment of the url path
+pub fn format_file(
+    mut component: Literal,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<Literal, ErrorInfo> {
+    let content = Literal::get_mut_value::<HashMap<String, Literal>>(
+        &mut component.primitive,
+        flow_name,
+        interval,
+        ERROR_FILE_URL.to_owned(),
+    )?;
+
+    if content.contains_key("filename") {
+        return Ok(component);
+    }
+
+    let filename = content
+        .get("url")
+        .and_then(|url| url.primitive.as_any().downcast_ref::<PrimitiveString>())
+        .and_then(|url| get_filename(&url.value));
+
+    if let Some(filename) = filename {
+        content.insert(
+            "filename".to_owned(),
+            PrimitiveString::get_literal(&filename, interval),
+        );
+    }
+
+    Ok(component)
+}
+
+fn get_filename(url: &str) -> Option<String> {
+    let url = Url::parse(url).ok()?;
+    let segment = url.path_segments()?.next_back()?;
+
+    if segment.is_empty() {
+        return None;
+    }
+
+    match urlencoding::decode(segment) {
+        Ok(segment) => Some(segment.into_owned()),
+        Err(_) => Some(segment.to_owned()),
+    }
+}
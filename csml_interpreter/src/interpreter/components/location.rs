@@ -0,0 +1,77 @@
+use crate::data::{
+    position::Position,
+    primitive::{tools::get_number, PrimitiveType},
+    ArgsType,
+};
+use crate::error_format::*;
+
+pub fn add_location(map: &mut serde_json::Map<String, serde_json::Value>) {
+    map.insert(
+        "Location".to_owned(),
+        serde_json::json!(
+            {
+                "params": [
+                    {
+                        "lat": {
+                            "required": true,
+                            "type": "Number"
+                        }
+                    },
+                    {
+                        "long": {
+                            "required": true,
+                            "type": "Number"
+                        }
+                    },
+                    {
+                        "title": {
+                            "required": false,
+                            "type": "String"
+                        }
+                    },
+                    {
+                        "address": {
+                            "required": false,
+                            "type": "String"
+                        }
+                    }
+                ]
+            }
+        ),
+    );
+}
+
+/// latitude must be between -90 and 90 and longitude between -180 and 180
+pub fn check_location(args: &ArgsType, flow_name: &str) -> Result<(), ErrorInfo> {
+    let coordinates = [
+        (args.get("lat", 0), 90.0, ERROR_LOCATION_LATITUDE),
+        (args.get("long", 1), 180.0, ERROR_LOCATION_LONGITUDE),
+    ];
+
+    for (literal, limit, error) in coordinates.iter() {
+        if let Some(literal) = literal {
+            match get_number(literal) {
+                Some(value) if value.as_f64().abs() <= *limit => {}
+                _ => {
+                    return Err(gen_error_info(
+                        Position::new(literal.interval, flow_name),
+                        format!("{} (value: {})", error, literal.primitive.to_string()),
+                    ))
+                }
+            }
+        }
+    }
+
+    for (key, index) in [("title", 2), ("address", 3)].iter() {
+        if let Some(literal) = args.get(key, *index) {
+            if literal.primitive.get_type() != PrimitiveType::PrimitiveString {
+                return Err(gen_error_info(
+                    Position::new(literal.interval, flow_name),
+                    format!("{} [{}]", ERROR_LOCATION_TEXT, key),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,3 +1,17 @@
+use crate::data::{
+    position::Position,
+    primitive::{PrimitiveArray, PrimitiveInt, PrimitiveType},
+    ArgsType, Interval, Literal,
+};
+use crate::error_format::*;
+use crate::interpreter::builtins::tools::accept_to_array;
+
+use std::collections::HashMap;
+
+// a Question with 'accepts', 'retries' or 'fallback' is validated when the
+// flow resumes after the next hold: a wrong answer sends the question again,
+// up to 'retries' times, then the flow goes to the 'fallback' step if any
+
 pub fn add_question(map: &mut serde_json::Map<String, serde_json::Value>) {
     map.insert(
         "Question".to_owned(),
@@ -19,9 +33,165 @@ pub fn add_question(map: &mut serde_json::Map<String, serde_json::Value>) {
                             "required": true,
                             "type": "Array"
                         }
+                    },
+                    {
+                        "accepts": {
+                            "required": false,
+                            "type": "Array"
+                        }
+                    },
+                    {
+                        "retries": {
+                            "required": false,
+                            "type": "Number"
+                        }
+                    },
+                    {
+                        "fallback": {
+                            "required": false,
+                            "type": "String"
+                        }
                     }
                 ]
             }
         ),
     );
 }
+
+pub fn check_question(args: &ArgsType, flow_name: &str) -> Result<(), ErrorInfo> {
+    if let Some(accepts) = args.get("accepts", 2) {
+        let array = Literal::get_value::<Vec<Literal>>(
+            &accepts.primitive,
+            flow_name,
+            accepts.interval,
+            ERROR_QUESTION_ACCEPTS.to_owned(),
+        )?;
+
+        if let Some(accept) = array
+            .iter()
+            .find(|accept| accept.primitive.get_type() != PrimitiveType::PrimitiveString)
+        {
+            return Err(gen_error_info(
+                Position::new(accept.interval, flow_name),
+                ERROR_QUESTION_ACCEPTS.to_owned(),
+            ));
+        }
+    }
+
+    if let Some(retries) = args.get("retries", 3) {
+        match retries.primitive.as_any().downcast_ref::<PrimitiveInt>() {
+            Some(int) if int.value >= 0 => {}
+            _ => {
+                return Err(gen_error_info(
+                    Position::new(retries.interval, flow_name),
+                    ERROR_QUESTION_RETRIES.to_owned(),
+                ))
+            }
+        }
+    }
+
+    if let Some(fallback) = args.get("fallback", 4) {
+        if fallback.primitive.get_type() != PrimitiveType::PrimitiveString {
+            return Err(gen_error_info(
+                Position::new(fallback.interval, flow_name),
+                ERROR_QUESTION_FALLBACK.to_owned(),
+            ));
+        }
+    }
+
+    check_question_answers(args, flow_name)
+}
+
+// retries and fallback need answers to check the next event against, either
+// an explicit 'accepts' or the buttons of the question
+fn check_question_answers(args: &ArgsType, flow_name: &str) -> Result<(), ErrorInfo> {
+    let argument = match (args.get("retries", 3), args.get("fallback", 4)) {
+        (Some(retries), _) => retries,
+        (None, Some(fallback)) => fallback,
+        (None, None) => return Ok(()),
+    };
+
+    let has_buttons = match args.get("buttons", 1) {
+        Some(buttons) => match buttons.primitive.as_any().downcast_ref::<PrimitiveArray>() {
+            Some(array) => !array.value.is_empty(),
+            None => false,
+        },
+        None => false,
+    };
+
+    if args.get("accepts", 2).is_none() && !has_buttons {
+        return Err(gen_error_info(
+            Position::new(argument.interval, flow_name),
+            ERROR_QUESTION_NO_ANSWERS.to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// a question with retries or a fallback but no explicit 'accepts' accepts the
+/// titles, payloads and accepts of its buttons
+pub fn format_question(
+    mut component: Literal,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<Literal, ErrorInfo> {
+    let content = Literal::get_mut_value::<HashMap<String, Literal>>(
+        &mut component.primitive,
+        flow_name,
+        interval,
+        ERROR_QUESTION.to_owned(),
+    )?;
+
+    if (content.contains_key("retries") || content.contains_key("fallback"))
+        && !content.contains_key("accepts")
+    {
+        if let Some(buttons) = content.get("buttons") {
+            let accepts = accepts_from_buttons(buttons, flow_name);
+            content.insert(
+                "accepts".to_owned(),
+                PrimitiveArray::get_literal(&accepts, buttons.interval),
+            );
+        }
+    }
+
+    Ok(component)
+}
+
+// buttons are already formatted as {content_type, content} at this point
+fn accepts_from_buttons(buttons: &Literal, flow_name: &str) -> Vec<Literal> {
+    let buttons = match Literal::get_value::<Vec<Literal>>(
+        &buttons.primitive,
+        flow_name,
+        buttons.interval,
+        ERROR_UNREACHABLE.to_owned(),
+    ) {
+        Ok(buttons) => buttons,
+        Err(..) => return vec![],
+    };
+
+    buttons.iter().fold(vec![], |vec, button| {
+        let button = match Literal::get_value::<HashMap<String, Literal>>(
+            &button.primitive,
+            flow_name,
+            button.interval,
+            ERROR_UNREACHABLE.to_owned(),
+        ) {
+            Ok(button) => button,
+            Err(..) => return vec,
+        };
+
+        match button.get("content") {
+            Some(content) => match Literal::get_value::<HashMap<String, Literal>>(
+                &content.primitive,
+                flow_name,
+                content.interval,
+                ERROR_UNREACHABLE.to_owned(),
+            ) {
+                Ok(content) => accept_to_array(content, vec, flow_name),
+                Err(..) => vec,
+            },
+            None => accept_to_array(button, vec, flow_name),
+        }
+    })
+}
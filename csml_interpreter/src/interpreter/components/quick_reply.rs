@@ -0,0 +1,165 @@
+use crate::data::{
+    position::Position,
+    primitive::{PrimitiveArray, PrimitiveObject, PrimitiveType},
+    ArgsType, Interval, Literal,
+};
+use crate::error_format::*;
+
+use std::collections::HashMap;
+
+// Quick replies are always sent as a standalone message, they are not attached
+// to the previous Text. QuickReply and QuickReplies both emit the same JSON:
+// {"content_type": "quick_reply", "content": {"quick_replies": [{title, payload, image_url?}]}}
+
+pub fn add_quick_reply(map: &mut serde_json::Map<String, serde_json::Value>) {
+    map.insert(
+        "QuickReply".to_owned(),
+        serde_json::json!(
+            {
+                "params": [
+                    {
+                        "title": {
+                            "required": true,
+                            "type": "String"
+                        }
+                    },
+                    {
+                        "payload": {
+                            "required": false,
+                            "type": "String",
+                            "default_value": [
+                                {"$_get": "title"}
+                            ]
+                        }
+                    },
+                    {
+                        "image_url": {
+                            "required": false,
+                            "type": "String"
+                        }
+                    }
+                ]
+            }
+        ),
+    );
+}
+
+pub fn add_quick_replies(map: &mut serde_json::Map<String, serde_json::Value>) {
+    map.insert(
+        "QuickReplies".to_owned(),
+        serde_json::json!(
+            {
+                "params": [
+                    {
+                        "quick_replies": {
+                            "required": true,
+                            "type": "Array"
+                        }
+                    }
+                ]
+            }
+        ),
+    );
+}
+
+pub fn check_quick_reply(args: &ArgsType, flow_name: &str) -> Result<(), ErrorInfo> {
+    let params = [
+        args.get("title", 0),
+        args.get("payload", 1),
+        args.get("image_url", 2),
+    ];
+
+    match params
+        .iter()
+        .flatten()
+        .find(|literal| literal.primitive.get_type() != PrimitiveType::PrimitiveString)
+    {
+        Some(literal) => Err(gen_error_info(
+            Position::new(literal.interval, flow_name),
+            ERROR_QUICK_REPLY.to_owned(),
+        )),
+        None => Ok(()),
+    }
+}
+
+pub fn check_quick_replies(args: &ArgsType, flow_name: &str) -> Result<(), ErrorInfo> {
+    if let Some(quick_replies) = args.get("quick_replies", 0) {
+        let array = Literal::get_value::<Vec<Literal>>(
+            &quick_replies.primitive,
+            flow_name,
+            quick_replies.interval,
+            ERROR_QUICK_REPLIES.to_owned(),
+        )?;
+
+        if let Some(quick_reply) = array.iter().find(|item| item.content_type != "quick_reply") {
+            return Err(gen_error_info(
+                Position::new(quick_reply.interval, flow_name),
+                ERROR_QUICK_REPLIES.to_owned(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// a single QuickReply is sent as a list of one entry
+pub fn format_quick_reply(mut quick_reply: Literal, interval: Interval) -> Literal {
+    quick_reply.set_content_type("object");
+
+    gen_quick_replies(vec![quick_reply], interval)
+}
+
+/// merge the entries of every QuickReply (or nested QuickReplies) in one list
+pub fn format_quick_replies(
+    args: &ArgsType,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<Literal, ErrorInfo> {
+    let mut entries = vec![];
+
+    if let Some(quick_replies) = args.get("quick_replies", 0) {
+        let array = Literal::get_value::<Vec<Literal>>(
+            &quick_replies.primitive,
+            flow_name,
+            quick_replies.interval,
+            ERROR_QUICK_REPLIES.to_owned(),
+        )?;
+
+        for quick_reply in array.iter() {
+            let content = Literal::get_value::<HashMap<String, Literal>>(
+                &quick_reply.primitive,
+                flow_name,
+                quick_reply.interval,
+                ERROR_QUICK_REPLIES.to_owned(),
+            )?;
+
+            if let Some(list) = content.get("quick_replies") {
+                entries.extend(
+                    Literal::get_value::<Vec<Literal>>(
+                        &list.primitive,
+                        flow_name,
+                        quick_reply.interval,
+                        ERROR_QUICK_REPLIES.to_owned(),
+                    )?
+                    .iter()
+                    .cloned(),
+                );
+            }
+        }
+    }
+
+    Ok(gen_quick_replies(entries, interval))
+}
+
+fn gen_quick_replies(entries: Vec<Literal>, interval: Interval) -> Literal {
+    let mut map = HashMap::new();
+    map.insert(
+        "quick_replies".to_owned(),
+        PrimitiveArray::get_literal(&entries, interval),
+    );
+
+    let mut result = PrimitiveObject::get_literal(&map, interval);
+    result.set_content_type("quick_reply");
+
+    result
+}
@@ -1,3 +1,13 @@
+use crate::data::{
+    primitive::{PrimitiveInt, PrimitiveString},
+    ArgsType, AutoTyping, Interval, Literal,
+};
+use crate::error_format::*;
+
+use std::collections::HashMap;
+
+pub const AUTO_TYPING: &str = "auto";
+
 pub fn add_typing(map: &mut serde_json::Map<String, serde_json::Value>) {
     map.insert(
         "Typing".to_owned(),
@@ -15,3 +25,65 @@ pub fn add_typing(map: &mut serde_json::Map<String, serde_json::Value>) {
         ),
     );
 }
+
+/// Typing("auto") gets its duration from the next text message, see
+/// 'send_auto_typing'
+pub fn is_auto_typing(args: &ArgsType) -> bool {
+    match args.get("duration", 0) {
+        Some(duration) => match duration
+            .primitive
+            .as_any()
+            .downcast_ref::<PrimitiveString>()
+        {
+            Some(string) => string.value.trim().eq_ignore_ascii_case(AUTO_TYPING),
+            None => false,
+        },
+        None => false,
+    }
+}
+
+pub fn format_auto_typing(mut args: ArgsType) -> ArgsType {
+    let (ArgsType::Named(map) | ArgsType::Normal(map)) = &mut args;
+
+    for key in ["duration", "arg0"].iter() {
+        if let Some(duration) = map.get_mut(*key) {
+            *duration = PrimitiveInt::get_literal(0, duration.interval);
+        }
+    }
+
+    args
+}
+
+pub fn format_typing(
+    mut component: Literal,
+    args: &ArgsType,
+    flow_name: &str,
+    interval: Interval,
+) -> Result<Literal, ErrorInfo> {
+    if is_auto_typing(args) {
+        let content = Literal::get_mut_value::<HashMap<String, Literal>>(
+            &mut component.primitive,
+            flow_name,
+            interval,
+            ERROR_TYPING.to_owned(),
+        )?;
+        content.insert(
+            "duration".to_owned(),
+            PrimitiveString::get_literal(AUTO_TYPING, interval),
+        );
+    }
+
+    Ok(component)
+}
+
+pub fn get_auto_typing_duration(text: Option<&str>, auto_typing: &AutoTyping) -> u64 {
+    match text {
+        Some(text) => {
+            let chars = text.chars().count() as u64;
+            let duration = chars * 1000 / auto_typing.chars_per_second.max(1);
+
+            duration.max(auto_typing.min).min(auto_typing.max)
+        }
+        None => auto_typing.default,
+    }
+}
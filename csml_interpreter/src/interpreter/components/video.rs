@@ -1,3 +1,7 @@
+use crate::data::{position::Position, primitive::PrimitiveType, ArgsType};
+use crate::error_format::*;
+use crate::interpreter::components::check_media_url;
+
 pub fn add_video(map: &mut serde_json::Map<String, serde_json::Value>) {
     map.insert(
         "Video".to_owned(),
@@ -9,9 +13,42 @@ pub fn add_video(map: &mut serde_json::Map<String, serde_json::Value>) {
                             "required": true,
                             "type": "String"
                         }
+                    },
+                    {
+                        "caption": {
+                            "required": false,
+                            "type": "String"
+                        }
+                    },
+                    {
+                        "thumbnail_url": {
+                            "required": false,
+                            "type": "String"
+                        }
                     }
                 ]
             }
         ),
     );
 }
+
+pub fn check_video(args: &ArgsType, flow_name: &str) -> Result<(), ErrorInfo> {
+    if let Some(url) = args.get("url", 0) {
+        check_media_url(url, ERROR_VIDEO_URL, flow_name)?;
+    }
+
+    if let Some(caption) = args.get("caption", 1) {
+        if caption.primitive.get_type() != PrimitiveType::PrimitiveString {
+            return Err(gen_error_info(
+                Position::new(caption.interval, flow_name),
+                ERROR_VIDEO_CAPTION.to_owned(),
+            ));
+        }
+    }
+
+    if let Some(thumbnail_url) = args.get("thumbnail_url", 2) {
+        check_media_url(thumbnail_url, ERROR_VIDEO_URL, flow_name)?;
+    }
+
+    Ok(())
+}
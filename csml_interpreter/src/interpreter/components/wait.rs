@@ -1,3 +1,10 @@
+use crate::data::{
+    position::Position,
+    primitive::{PrimitiveFloat, PrimitiveInt, PrimitiveString},
+    ArgsType, Literal,
+};
+use crate::error_format::*;
+
 pub fn add_wait(map: &mut serde_json::Map<String, serde_json::Value>) {
     map.insert(
         "Wait".to_owned(),
@@ -15,3 +22,70 @@ pub fn add_wait(map: &mut serde_json::Map<String, serde_json::Value>) {
         ),
     );
 }
+
+/// durations are milliseconds, given as a positive number or as a string with
+/// a 'ms' or 's' unit like "500ms" or "2s", and can't go over the bot's
+/// max_duration
+pub fn format_duration(
+    mut args: ArgsType,
+    error: &str,
+    flow_name: &str,
+    max_duration: u64,
+) -> Result<ArgsType, ErrorInfo> {
+    let (ArgsType::Named(map) | ArgsType::Normal(map)) = &mut args;
+
+    let key = if map.contains_key("duration") {
+        "duration"
+    } else {
+        "arg0"
+    };
+
+    if let Some(duration) = map.get_mut(key) {
+        let milliseconds = match get_milliseconds(duration) {
+            Some(milliseconds) => milliseconds,
+            None => {
+                return Err(gen_error_info(
+                    Position::new(duration.interval, flow_name),
+                    error.to_owned(),
+                ))
+            }
+        };
+
+        if milliseconds > max_duration {
+            return Err(gen_error_info(
+                Position::new(duration.interval, flow_name),
+                format!("{} ({} ms)", ERROR_DURATION_MAX, max_duration),
+            ));
+        }
+
+        *duration = PrimitiveInt::get_literal(milliseconds as i64, duration.interval);
+    }
+
+    Ok(args)
+}
+
+fn get_milliseconds(duration: &Literal) -> Option<u64> {
+    let primitive = duration.primitive.as_any();
+
+    let milliseconds = if let Some(int) = primitive.downcast_ref::<PrimitiveInt>() {
+        int.value as f64
+    } else if let Some(float) = primitive.downcast_ref::<PrimitiveFloat>() {
+        float.value
+    } else if let Some(string) = primitive.downcast_ref::<PrimitiveString>() {
+        let value = string.value.trim();
+
+        if let Some(value) = value.strip_suffix("ms") {
+            value.trim().parse::<f64>().ok()?
+        } else {
+            value.strip_suffix('s')?.trim().parse::<f64>().ok()? * 1000.0
+        }
+    } else {
+        return None;
+    };
+
+    if milliseconds.is_finite() && milliseconds >= 0.0 {
+        Some(milliseconds.round() as u64)
+    } else {
+        None
+    }
+}
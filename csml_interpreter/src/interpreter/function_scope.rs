@@ -5,7 +5,9 @@ use crate::data::{
 };
 use crate::error_format::*;
 use crate::interpreter::{
-    ast_interpreter::{for_loop, match_actions, solve_if_statement, while_loop},
+    ast_interpreter::{
+        for_loop, match_actions, solve_if_statement, solve_match_statement, while_loop,
+    },
     variable_handler::{expr_to_literal, interval::interval_from_expr},
 };
 use crate::parser::ExitCondition;
@@ -48,6 +50,9 @@ fn interpret_function_scope(
             Expr::WhileExpr(expr, block, range) => {
                 message_data = while_loop(expr, block, range, message_data, data, sender)?
             }
+            Expr::MatchExpr(expr, arms, ..) => {
+                message_data = solve_match_statement(expr, arms, message_data, data, sender)?
+            }
             e => {
                 return Err(gen_error_info(
                     Position::new(interval_from_expr(e), &data.context.flow),
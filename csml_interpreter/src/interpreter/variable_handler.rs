@@ -699,10 +699,14 @@ pub fn get_var(
                 tmp_loop_index,
                 &mut tmp_step_count,
                 tmp_step_limit,
+                data.loop_limit,
                 tmp_step_vars,
                 data.previous_info.clone(),
                 data.custom_component,
                 data.native_component,
+                data.debug,
+                data.max_duration,
+                data.auto_typing,
             );
             // #####################
 
@@ -32,8 +32,11 @@ pub fn gen_literal_from_event(
     match path {
         Some(path) => {
             let path = resolve_path(path, dis_warnings, data, msg_data, sender)?;
-            let mut lit =
-                json_to_literal(&data.event.content, interval.to_owned(), &data.context.flow)?;
+            let mut lit = json_to_literal(
+                &data.event.get_content(),
+                interval.to_owned(),
+                &data.context.flow,
+            )?;
 
             lit.set_content_type("event");
 
@@ -5,7 +5,7 @@ use crate::data::{
     warnings::DisplayWarnings,
     Data, Literal, MessageData, MSG,
 };
-use crate::error_format::{gen_error_info, ErrorInfo};
+use crate::error_format::{gen_error_info, ErrorInfo, ERROR_ILLEGAL_OPERATION};
 use crate::interpreter::variable_handler::{
     expr_to_literal, interval::interval_from_expr, match_literals::match_obj,
 };
@@ -126,6 +126,24 @@ pub fn evaluate_infix(
             lhs.primitive.as_bool() & rhs.primitive.as_bool(),
             lhs.interval,
         )),
+        (Infix::Xor, Ok(lhs), Ok(rhs)) => match (
+            lhs.primitive.as_any().downcast_ref::<PrimitiveBoolean>(),
+            rhs.primitive.as_any().downcast_ref::<PrimitiveBoolean>(),
+        ) {
+            (Some(lhs_bool), Some(rhs_bool)) => Ok(PrimitiveBoolean::get_literal(
+                lhs_bool.value ^ rhs_bool.value,
+                lhs.interval,
+            )),
+            _ => Err(gen_error_info(
+                Position::new(lhs.interval, flow_name),
+                format!(
+                    "{} {:?} ^ {:?}",
+                    ERROR_ILLEGAL_OPERATION,
+                    lhs.primitive.get_type(),
+                    rhs.primitive.get_type()
+                ),
+            )),
+        },
         (Infix::Match, Ok(ref lhs), Ok(ref rhs)) => Ok(PrimitiveBoolean::get_literal(
             match_obj(lhs, rhs),
             lhs.interval,
@@ -6,7 +6,7 @@ use crate::data::{
     primitive::PrimitiveClosure,
     tokens::*,
     warnings::DisplayWarnings,
-    ArgsType, Literal, MemoryType, MessageData, Position, MSG,
+    ArgsType, Literal, MemoryType, MessageData, Position, FN_DEPTH_LIMIT, MSG,
 };
 use crate::error_format::*;
 use crate::interpreter::{
@@ -210,6 +210,41 @@ fn check_for_import<'a>(
     }
 }
 
+// flow functions must be called with exactly the arguments they declare
+fn check_fn_args(
+    name: &str,
+    fn_args: &[String],
+    args: &ArgsType,
+    interval: Interval,
+    data: &Data,
+) -> Result<(), ErrorInfo> {
+    if fn_args.len() != args.len() {
+        return Err(gen_error_info(
+            Position::new(interval, &data.context.flow),
+            format!(
+                "{}: {} expects {} argument(s) but {} were given",
+                ERROR_FN_ARGS,
+                name,
+                fn_args.len(),
+                args.len()
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_fn_depth(interval: Interval, data: &Data) -> Result<(), ErrorInfo> {
+    if data.fn_depth >= FN_DEPTH_LIMIT {
+        return Err(gen_error_info(
+            Position::new(interval, &data.context.flow),
+            format!("{} ({} nested calls)", ERROR_FN_DEPTH_LIMIT, FN_DEPTH_LIMIT),
+        ));
+    }
+
+    Ok(())
+}
+
 fn check_for_closure<'a>(
     name: &str,
     interval: Interval,
@@ -328,6 +363,8 @@ pub fn resolve_object(
         ObjType::Function { fn_args, scope } => {
             let resolved_args =
                 resolve_fn_args(args, data, msg_data, &DisplayWarnings::On, sender)?;
+            check_fn_args(name, &fn_args, &resolved_args, interval, data)?;
+
             exec_fn(
                 &scope,
                 &fn_args,
@@ -352,9 +389,8 @@ pub fn resolve_object(
             let (fn_args, expr, new_flow) =
                 check_for_import(name, interval, data).ok_or(error.clone())?;
 
-            if fn_args.len() > resolved_args.len() {
-                return Err(error);
-            }
+            check_fn_args(name, &fn_args, &resolved_args, interval, data)?;
+            check_fn_depth(interval, data)?;
 
             let mut context = init_child_context(&data);
             let mut step_count = data.step_count.clone();
@@ -419,6 +455,7 @@ pub fn exec_fn(
             ERROR_FN_ARGS.to_owned(),
         ));
     }
+    check_fn_depth(interval, data)?;
 
     let mut context = init_child_context(&data);
     let mut step_count = data.step_count.clone();
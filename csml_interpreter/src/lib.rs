@@ -19,9 +19,10 @@ use data::event::Event;
 use data::literal::create_error_info;
 use data::message_data::MessageData;
 use data::msg::MSG;
+use data::primitive::tools_random;
 use data::CsmlResult;
 use data::{csml_bot::CsmlBot, CsmlFlow};
-use data::{Context, Data, Position, STEP_LIMIT};
+use data::{Context, Data, Message, Position, LOOP_LIMIT, MAX_DURATION, STEP_LIMIT};
 use error_format::*;
 use fold_bot::fold_bot as fold;
 use linter::{linter::lint_bot, FlowToValidate};
@@ -96,6 +97,59 @@ fn execute_step(
     MessageData::error_to_message(msg_data, sender)
 }
 
+/// a held Question with accepted answers is asked again when the event is not
+/// one of them. Once the retries are spent the flow goes to the fallback step,
+/// or resumes after the hold when the question has none
+fn check_question_answer(
+    context: &mut Context,
+    event: &Event,
+    sender: &Option<mpsc::Sender<MSG>>,
+) -> Option<MessageData> {
+    let hold = context.hold.as_mut()?;
+    let question = hold.question.as_mut()?;
+
+    if question.is_accepted(event) {
+        hold.question = None;
+        return None;
+    }
+
+    if question.attempts < question.retries {
+        question.attempts += 1;
+
+        let msg = Message {
+            content_type: "question".to_owned(),
+            content: question.message.clone(),
+        };
+        MSG::send(sender, MSG::Message(msg.clone()));
+        MSG::send(sender, MSG::Hold(hold.clone()));
+
+        let mut msg_data = MessageData::default().add_message(msg);
+        msg_data.hold = Some(hold.clone());
+        msg_data.exit_condition = Some(ExitCondition::Hold);
+
+        return Some(msg_data);
+    }
+
+    match question.fallback.take() {
+        Some(step) => {
+            context.hold = None;
+            context.step = ContextStepInfo::Normal(step);
+
+            MSG::send(
+                sender,
+                MSG::Next {
+                    flow: None,
+                    step: Some(context.step.clone()),
+                    bot: None,
+                },
+            );
+        }
+        None => hold.question = None,
+    }
+
+    None
+}
+
 fn get_step_limit(event: &Event) -> usize {
     match (event.step_limit, env::var("STEP_LIMIT").ok()) {
         (Some(step_limit), _) => step_limit,
@@ -104,6 +158,14 @@ fn get_step_limit(event: &Event) -> usize {
     }
 }
 
+fn get_loop_limit(event: &Event) -> usize {
+    match (event.loop_limit, env::var("LOOP_LIMIT").ok()) {
+        (Some(loop_limit), _) => loop_limit,
+        (None, Some(loop_limit)) => loop_limit.parse::<usize>().unwrap_or(LOOP_LIMIT),
+        _ => LOOP_LIMIT,
+    }
+}
+
 fn get_flow_ast<'a, 'b>(
     flows: &'a HashMap<String, Flow>,
     flow: &'b str,
@@ -447,6 +509,12 @@ pub fn interpret(
 ) -> MessageData {
     csml_logs::init_logger();
 
+    tools_random::set_seed(bot.random_seed);
+
+    if let Some(msg_data) = check_question_answer(&mut context, &event, &sender) {
+        return msg_data;
+    }
+
     let mut msg_data = MessageData::default();
 
     let mut flow = context.flow.to_owned();
@@ -454,6 +522,7 @@ pub fn interpret(
 
     let mut step_count = 0;
     let step_limit = get_step_limit(&event);
+    let loop_limit = get_loop_limit(&event);
 
     let mut step_vars = match &context.hold {
         Some(hold) => get_hashmap_from_mem(&hold.step_vars, &flow),
@@ -511,10 +580,14 @@ pub fn interpret(
             0,
             &mut step_count,
             step_limit,
+            loop_limit,
             step_vars,
             previous_info.clone(),
             &custom,
             &native,
+            !bot.disable_debug,
+            bot.max_duration.unwrap_or(MAX_DURATION),
+            bot.auto_typing.unwrap_or_default(),
         );
 
         msg_data = match inserted_ast {
@@ -617,6 +617,56 @@ fn validate_if_scope(
     }
 }
 
+// strings are parsed as a ComplexLiteral holding a single literal
+fn get_pattern_literal(pattern: &Expr) -> Option<&Literal> {
+    match pattern {
+        Expr::LitExpr { literal, .. } => Some(literal),
+        Expr::ComplexLiteral(vec, ..) if vec.len() == 1 => get_pattern_literal(&vec[0]),
+        _ => None,
+    }
+}
+
+fn validate_match_arms(
+    arms: &[MatchArm],
+    state: &mut State,
+    linter_info: &mut LinterInfo,
+    step_breakers: &mut Option<&mut Vec<StepBreakers>>,
+) {
+    let mut matched: Vec<&Literal> = vec![];
+    let mut wildcard = false;
+
+    for arm in arms.iter() {
+        match &arm.patterns {
+            // every arm after '_' is unreachable
+            _ if wildcard => linter_info.warnings.push(Warnings::new(
+                linter_info.flow_name,
+                arm.interval.to_owned(),
+                WARNING_MATCH_UNREACHABLE,
+            )),
+            Some(patterns) => {
+                for pattern in patterns.iter() {
+                    if let Some(literal) = get_pattern_literal(pattern) {
+                        if matched.contains(&literal) {
+                            linter_info.warnings.push(Warnings::new(
+                                linter_info.flow_name,
+                                interval_from_expr(pattern),
+                                WARNING_MATCH_UNREACHABLE,
+                            ));
+                        } else {
+                            matched.push(literal);
+                        }
+                    }
+
+                    validate_expr_literals(pattern, state, linter_info);
+                }
+            }
+            None => wildcard = true,
+        }
+
+        validate_scope(&arm.block, state, linter_info, step_breakers);
+    }
+}
+
 fn validate_scope(
     scope: &Block,
     state: &mut State,
@@ -873,6 +923,10 @@ fn validate_scope(
                 validate_scope(block, state, linter_info, step_breakers);
                 state.exit_loop();
             }
+            Expr::MatchExpr(expr, arms, _range) => {
+                validate_expr_literals(expr, state, linter_info);
+                validate_match_arms(arms, state, linter_info, step_breakers);
+            }
             _ => {}
         }
     }
@@ -13,6 +13,7 @@ pub mod parse_if;
 pub mod parse_import;
 pub mod parse_insert;
 pub mod parse_literal;
+pub mod parse_match;
 pub mod parse_object;
 pub mod parse_parenthesis;
 pub mod parse_path;
@@ -1,14 +1,18 @@
-use crate::data::{ast::*, tokens::*};
+use crate::data::{ast::*, primitive::PrimitiveInt, tokens::*};
 use crate::parser::operator::tools::and_operator;
 use crate::parser::operator::tools::or_operator;
 use crate::parser::operator::tools::parse_infix_operators;
 use crate::parser::operator::tools::parse_item_operator;
 use crate::parser::operator::tools::parse_not_operator;
 use crate::parser::operator::tools::parse_term_operator;
+use crate::parser::operator::tools::subtraction_operator;
+use crate::parser::operator::tools::xor_operator;
 use crate::parser::parse_comments::comment;
 use crate::parser::parse_var_types::parse_basic_expr;
+use crate::parser::tools::get_interval;
 use nom::{
     branch::alt,
+    bytes::complete::tag,
     error::{ContextError, ParseError},
     multi::{many0, many1},
     sequence::{preceded, tuple},
@@ -34,8 +38,16 @@ where
     let (s, expr1) = alt((parse_postfix_operator, parse_item))(s)?;
     let infix: IResult<Span<'a>, Infix, E> = preceded(comment, parse_infix_operators)(s);
     match infix {
-        Ok((s, operator)) => {
-            let (s, expr2) = alt((parse_postfix_operator, parse_item))(s)?;
+        Ok((rest, operator)) => {
+            let (rest, expr2) = alt((parse_postfix_operator, parse_item))(rest)?;
+
+            // 'match value {' starts a match statement, it is not a Match operator
+            let l_brace: IResult<Span<'a>, Span<'a>, E> = preceded(comment, tag(L_BRACE))(rest);
+            if operator == Infix::Match && l_brace.is_ok() {
+                return Ok((s, expr1));
+            }
+
+            let s = rest;
             Ok((
                 s,
                 Expr::InfixExpr(operator, Box::new(expr1), Box::new(expr2)),
@@ -85,16 +97,42 @@ where
     Ok((s, value))
 }
 
+// unary minus, `-expr` is evaluated as `0 - expr` so ints keep their overflow
+// check and floats or numeric strings follow the usual subtraction rules
+fn parse_negation<'a, E>(s: Span<'a>) -> IResult<Span<'a>, Expr, E>
+where
+    E: ParseError<Span<'a>> + ContextError<Span<'a>>,
+{
+    let (s, _) = comment(s)?;
+    let (s, interval) = get_interval(s)?;
+    let (s, _) = subtraction_operator(s)?;
+    let (s, expr) = parse_unary(s)?;
+
+    let zero = Expr::LitExpr {
+        literal: PrimitiveInt::get_literal(0, interval),
+        in_in_substring: false,
+    };
+
+    Ok((
+        s,
+        Expr::InfixExpr(Infix::Subtraction, Box::new(zero), Box::new(expr)),
+    ))
+}
+
+fn parse_unary<'a, E>(s: Span<'a>) -> IResult<Span<'a>, Expr, E>
+where
+    E: ParseError<Span<'a>> + ContextError<Span<'a>>,
+{
+    alt((parse_basic_expr, parse_negation))(s)
+}
+
 fn parse_term<'a, E>(s: Span<'a>) -> IResult<Span<'a>, Expr, E>
 where
     E: ParseError<Span<'a>> + ContextError<Span<'a>>,
 {
-    let (s, value) = parse_basic_expr(s)?;
+    let (s, value) = parse_unary(s)?;
 
-    let (s, mut v) = many0(tuple((
-        preceded(comment, parse_term_operator),
-        parse_basic_expr,
-    )))(s)?;
+    let (s, mut v) = many0(tuple((preceded(comment, parse_term_operator), parse_unary)))(s)?;
 
     let value = v.drain(0..).fold(value, |acc, (infix, expr)| {
         Expr::InfixExpr(infix, Box::new(acc), Box::new(expr))
@@ -103,12 +141,36 @@ where
     Ok((s, value))
 }
 
+// `^` binds tighter than `||` and looser than `&&`
+fn parse_xor<'a, E>(s: Span<'a>) -> IResult<Span<'a>, Expr, E>
+where
+    E: ParseError<Span<'a>> + ContextError<Span<'a>>,
+{
+    let (s, _) = preceded(comment, xor_operator)(s)?;
+    parse_and_condition(s)
+}
+
+fn parse_xor_condition<'a, E>(s: Span<'a>) -> IResult<Span<'a>, Expr, E>
+where
+    E: ParseError<Span<'a>> + ContextError<Span<'a>>,
+{
+    let (s, value) = parse_and_condition(s)?;
+
+    let (s, mut v) = many0(parse_xor)(s)?;
+
+    let value = v.drain(0..).fold(value, |acc, expr| {
+        Expr::InfixExpr(Infix::Xor, Box::new(acc), Box::new(expr))
+    });
+
+    Ok((s, value))
+}
+
 fn parse_or<'a, E>(s: Span<'a>) -> IResult<Span<'a>, Expr, E>
 where
     E: ParseError<Span<'a>> + ContextError<Span<'a>>,
 {
     let (s, _) = preceded(comment, or_operator)(s)?;
-    parse_and_condition(s)
+    parse_xor_condition(s)
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -119,7 +181,7 @@ pub fn parse_operator<'a, E>(s: Span<'a>) -> IResult<Span<'a>, Expr, E>
 where
     E: ParseError<Span<'a>> + ContextError<Span<'a>>,
 {
-    let (s, value) = parse_and_condition(s)?;
+    let (s, value) = parse_xor_condition(s)?;
 
     let (s, mut v) = many0(parse_or)(s)?;
 
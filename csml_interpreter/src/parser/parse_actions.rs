@@ -9,6 +9,7 @@ use crate::parser::{
     parse_goto::parse_goto,
     parse_idents::{parse_idents_assignation, parse_idents_usage},
     parse_if::parse_if,
+    parse_match::parse_match,
     parse_path::parse_path,
     parse_previous::parse_previous,
     parse_var_types::parse_r_bracket,
@@ -429,6 +430,7 @@ where
         parse_if,
         parse_foreach,
         parse_while,
+        parse_match,
         // only accessible inside foreach or if scopes
         parse_break,
         parse_continue,
@@ -1,4 +1,5 @@
 use crate::data::{ast::*, tokens::*};
+use crate::error_format::{gen_nom_error, ERROR_MINUS_AS_IDENT};
 // use crate::linter::Linter;
 use crate::parser::tools::get_string;
 use crate::parser::{
@@ -15,9 +16,14 @@ where
     E: ParseError<Span<'a>> + ContextError<Span<'a>>,
 {
     let (s, interval) = get_interval(s)?;
-    let (s, name) = get_string(s)?;
+    let (rest, name) = get_string(s)?;
 
-    let (s, expr) = preceded(comment, parse_expr_list)(s)?;
+    // leave `-fn()` to the unary minus operator
+    if name.starts_with('-') {
+        return Err(gen_nom_error(s, ERROR_MINUS_AS_IDENT));
+    }
+
+    let (s, expr) = preceded(comment, parse_expr_list)(rest)?;
 
     let func = Function {
         name,
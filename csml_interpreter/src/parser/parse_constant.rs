@@ -32,6 +32,7 @@ fn interval_from_expr(expr: &Expr) -> Interval {
         Expr::PathExpr { literal, .. } => interval_from_expr(literal),
         Expr::ForEachExpr(_, _, _, _, range_interval) => *range_interval,
         Expr::WhileExpr(_, _, range_interval) => *range_interval,
+        Expr::MatchExpr(_, _, range_interval) => *range_interval,
         Expr::IdentExpr(ident) => ident.interval.to_owned(),
         Expr::LitExpr { literal, .. } => literal.interval.to_owned(),
         Expr::IfExpr(ifstmt) => interval_from_if_stmt(ifstmt),
@@ -179,6 +180,24 @@ fn evaluate_infix(
             lhs.primitive.as_bool() & rhs.primitive.as_bool(),
             lhs.interval,
         )),
+        (Infix::Xor, Ok(lhs), Ok(rhs)) => match (
+            lhs.primitive.as_any().downcast_ref::<PrimitiveBoolean>(),
+            rhs.primitive.as_any().downcast_ref::<PrimitiveBoolean>(),
+        ) {
+            (Some(lhs_bool), Some(rhs_bool)) => Ok(PrimitiveBoolean::get_literal(
+                lhs_bool.value ^ rhs_bool.value,
+                lhs.interval,
+            )),
+            _ => Err(gen_error_info(
+                Position::new(lhs.interval, flow_name),
+                format!(
+                    "{} {:?} ^ {:?}",
+                    ERROR_ILLEGAL_OPERATION,
+                    lhs.primitive.get_type(),
+                    rhs.primitive.get_type()
+                ),
+            )),
+        },
 
         (Infix::Match, Ok(lhs), Ok(_)) | (Infix::NotMatch, Ok(lhs), Ok(_)) => Err(gen_error_info(
             Position::new(lhs.interval, "flow"),
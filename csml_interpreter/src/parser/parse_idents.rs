@@ -1,6 +1,7 @@
 use crate::data::{ast::*, tokens::*};
 use crate::error_format::{
-    gen_nom_error, gen_nom_failure, ERROR_NUMBER_AS_IDENT, ERROR_RESERVED, ERROR_SIZE_IDENT,
+    gen_nom_error, gen_nom_failure, ERROR_MINUS_AS_IDENT, ERROR_NUMBER_AS_IDENT, ERROR_RESERVED,
+    ERROR_SIZE_IDENT,
 };
 use crate::parser::{
     parse_comments::comment,
@@ -68,6 +69,11 @@ where
     let (span, var) = get_string(s)?;
     let (_, ..) = validate_string(s, UTILISATION_RESERVED, &var)?;
 
+    // leave `-var` to the unary minus operator
+    if var.starts_with('-') {
+        return Err(gen_nom_error(s, ERROR_MINUS_AS_IDENT));
+    }
+
     Ok((span, var))
 }
 
@@ -0,0 +1,158 @@
+use crate::data::{
+    ast::{Expr, MatchArm},
+    tokens::{Span, ARROW, COMMA, L_BRACE, MATCH, PIPE, R_BRACE, WILDCARD},
+};
+use crate::error_format::{gen_nom_failure, ERROR_MATCH_ARM};
+use crate::parser::operator::parse_operator;
+use crate::parser::{
+    parse_comments::comment,
+    parse_scope::{parse_implicit_scope, parse_scope},
+    tools::{get_interval, get_string, get_tag},
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{cut, opt},
+    error::{ContextError, ParseError},
+    multi::{many0, separated_list1},
+    sequence::preceded,
+    *,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// PRIVATE FUNCTION
+////////////////////////////////////////////////////////////////////////////////
+
+fn parse_wildcard<'a, E>(s: Span<'a>) -> IResult<Span<'a>, Option<Vec<Expr>>, E>
+where
+    E: ParseError<Span<'a>> + ContextError<Span<'a>>,
+{
+    let (s, name) = preceded(comment, get_string)(s)?;
+    let (s, ..) = get_tag(name, WILDCARD)(s)?;
+
+    Ok((s, None))
+}
+
+fn parse_patterns<'a, E>(s: Span<'a>) -> IResult<Span<'a>, Option<Vec<Expr>>, E>
+where
+    E: ParseError<Span<'a>> + ContextError<Span<'a>>,
+{
+    let (s, patterns) = separated_list1(preceded(comment, tag(PIPE)), parse_operator)(s)?;
+
+    Ok((s, Some(patterns)))
+}
+
+fn parse_arm<'a, E>(s: Span<'a>) -> IResult<Span<'a>, MatchArm, E>
+where
+    E: ParseError<Span<'a>> + ContextError<Span<'a>>,
+{
+    let (s, _) = comment(s)?;
+    let (s, mut interval) = get_interval(s)?;
+    let (s, patterns) = alt((parse_wildcard, parse_patterns))(s)?;
+
+    let (s, _) = match preceded(comment, tag(ARROW))(s) {
+        Ok(value) => value,
+        Err(Err::Error(..)) => return Err(gen_nom_failure(s, ERROR_MATCH_ARM)),
+        Err(e) => return Err(e),
+    };
+
+    let (s, block) = cut(alt((parse_scope, parse_implicit_scope)))(s)?;
+    let (s, end) = get_interval(s)?;
+    interval.add_end(end);
+
+    let (s, _) = opt(preceded(comment, tag(COMMA)))(s)?;
+
+    Ok((
+        s,
+        MatchArm {
+            patterns,
+            block,
+            last_action_index: 0, // this wil be update in parse_root
+            interval,
+        },
+    ))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// PUBLIC FUNCTION
+////////////////////////////////////////////////////////////////////////////////
+
+pub fn parse_match<'a, E>(s: Span<'a>) -> IResult<Span<'a>, Expr, E>
+where
+    E: ParseError<Span<'a>> + ContextError<Span<'a>>,
+{
+    let (s, name) = preceded(comment, get_string)(s)?;
+    let (s, ..) = get_tag(name, MATCH)(s)?;
+    let (s, mut interval) = get_interval(s)?;
+
+    let (s, value) = cut(parse_operator)(s)?;
+
+    let (s, _) = cut(preceded(comment, tag(L_BRACE)))(s)?;
+    let (s, arms) = many0(parse_arm)(s)?;
+    let (s, _) = match preceded(comment, tag(R_BRACE))(s) {
+        Ok(value) => value,
+        Err(Err::Error(..)) => return Err(gen_nom_failure(s, ERROR_MATCH_ARM)),
+        Err(e) => return Err(e),
+    };
+
+    let (s, end) = get_interval(s)?;
+    interval.add_end(end);
+
+    Ok((s, Expr::MatchExpr(Box::new(value), arms, interval)))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// TEST FUNCTIONS
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn test_match(s: Span) -> IResult<Span, Expr> {
+        preceded(comment, parse_match)(s)
+    }
+
+    #[test]
+    fn ok_match() {
+        let string = Span::new(
+            "match event { \"a\" => { say \"a\" }, \"b\" | 2 => say \"b\" _ => { say \"c\" } }",
+        );
+        match test_match(string) {
+            Ok((_, Expr::MatchExpr(_, arms, _))) => {
+                assert_eq!(arms.len(), 3);
+                assert_eq!(arms[1].patterns.as_ref().unwrap().len(), 2);
+                assert!(arms[2].patterns.is_none());
+            }
+            Ok(..) => panic!("need to be a match"),
+            Err(e) => panic!("{:?}", e),
+        }
+    }
+
+    #[test]
+    fn ok_empty_match() {
+        let string = Span::new("match event { }");
+        match test_match(string) {
+            Ok(..) => {}
+            Err(e) => panic!("{:?}", e),
+        }
+    }
+
+    #[test]
+    fn err_match_arrow() {
+        let string = Span::new("match event { \"a\" { say \"a\" } }");
+        match test_match(string) {
+            Ok(..) => panic!("need to fail"),
+            Err(..) => {}
+        }
+    }
+
+    #[test]
+    fn err_match_brace() {
+        let string = Span::new("match event \"a\" => { say \"a\" }");
+        match test_match(string) {
+            Ok(..) => panic!("need to fail"),
+            Err(..) => {}
+        }
+    }
+}
@@ -40,6 +40,15 @@ fn count_if_commands(if_statement: &mut IfStatement, index: &mut usize) {
     }
 }
 
+fn count_match_commands(arms: &mut [MatchArm], index: &mut usize) {
+    for arm in arms.iter_mut() {
+        count_scope_commands(&mut arm.block, index);
+        if *index >= 1 {
+            arm.last_action_index = *index - 1;
+        }
+    }
+}
+
 fn count_scope_commands(scope: &mut Block, index: &mut usize) {
     for (command, info) in scope.commands.iter_mut() {
         count_commands(command, index, info);
@@ -67,6 +76,10 @@ pub fn count_commands(command: &mut Expr, index: &mut usize, info: &mut Instruct
             info.index = *index;
             count_scope_commands(block, index)
         }
+        Expr::MatchExpr(_expr, arms, _range) => {
+            info.index = *index;
+            count_match_commands(arms, index)
+        }
         _ => {}
     }
 
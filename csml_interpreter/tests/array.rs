@@ -2,6 +2,8 @@ mod support;
 
 use csml_interpreter::data::context::Context;
 use csml_interpreter::data::event::Event;
+use csml_interpreter::data::Interval;
+use csml_interpreter::interpreter::json_to_literal;
 use std::collections::HashMap;
 
 use crate::support::tools::format_message;
@@ -68,7 +70,7 @@ fn array_step_1() {
 fn array_step_2() {
     let data = r#"
         {
-            "memories":[{"key":"vec", "value": [42]}, {"key":"vec", "value": []}],
+            "memories":[{"key":"vec", "value": [42]}],
             "messages":[{"content":{"text": "false"},"content_type":"text"}, {"content":{"text": "true"}, "content_type":"text"}]
         }"#;
     let msg = format_message(
@@ -560,3 +562,1713 @@ fn array_reduce_index() {
 
     assert_eq!(v1, v2)
 }
+
+#[test]
+fn array_push_mixed() {
+    let data = r#"
+    {
+        "memories":[
+            {"key":"answers", "value": []},
+            {"key":"answers", "value": [42]},
+            {"key":"answers", "value": [42, "yes"]},
+            {"key":"answers", "value": [42, "yes", 1.5]},
+            {"key":"answers", "value": [42, "yes", 1.5, true]},
+            {"key":"answers", "value": [42, "yes", 1.5, true, {"_content": {"id": 1}, "_content_type": "object"}]},
+            {"key":"answers", "value": [42, "yes", 1.5, true, {"_content": {"id": 1}, "_content_type": "object"}, [null]]}
+        ],
+        "messages":[
+            {"content":{"text": "6"}, "content_type":"text"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_push_mixed",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_pop_until_empty() {
+    let data = r#"
+    {
+        "memories":[
+            {"key":"stack", "value": [1, "two", 3]},
+            {"key":"stack", "value": [1, "two"]},
+            {"key":"stack", "value": [1]},
+            {"key":"stack", "value": []}
+        ],
+        "messages":[
+            {"content":{"text": "3"}, "content_type":"text"},
+            {"content":{"text": "two"}, "content_type":"text"},
+            {"content":{"text": "1"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_pop_until_empty",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_pop_empty() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_pop_empty",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_shift_unshift() {
+    let data = r#"
+    {
+        "memories":[
+            {"key":"queue", "value": ["b"]},
+            {"key":"queue", "value": ["a", "b"]},
+            {"key":"queue", "value": [{"_content": {"question": "name?"}, "_content_type": "object"}, "a", "b"]},
+            {"key":"queue", "value": ["a", "b"]}
+        ],
+        "messages":[
+            {"content":{"question": "name?"}, "content_type":"object"},
+            {"content":["a", "b"], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_shift_unshift",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_shift_empty() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_shift_empty",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_drain_queue() {
+    let mut questions = serde_json::json!(["first", "second"]);
+    let mut said = vec![];
+
+    for _ in 0..3 {
+        let mut current = HashMap::new();
+        current.insert(
+            "questions".to_owned(),
+            json_to_literal(&questions, Interval::default(), "flow").unwrap(),
+        );
+
+        let msg = format_message(
+            Event::new("payload", "", serde_json::json!({})),
+            Context::new(
+                current,
+                HashMap::new(),
+                None,
+                None,
+                "array_drain_queue",
+                "flow",
+                None,
+            ),
+            "CSML/basic_test/stdlib/array.csml",
+        );
+
+        let value: Value = message_to_json_value(msg);
+
+        said.push(value["messages"][0]["content"]["text"].clone());
+        if let Some(memory) = value["memories"].as_array().and_then(|m| m.last()) {
+            questions = memory["value"].clone();
+        }
+    }
+
+    assert_eq!(said, vec!["first", "second", "done"]);
+    assert_eq!(questions, serde_json::json!([]));
+}
+
+#[test]
+fn array_insert_remove_negative() {
+    let data = r#"
+    {
+        "memories":[
+            {"key":"cart", "value": ["apple", "pear"]},
+            {"key":"cart", "value": ["apple", "kiwi", "pear"]},
+            {"key":"cart", "value": ["apple", "kiwi", "pear", "plum"]},
+            {"key":"cart", "value": ["apple", "kiwi", "pear"]},
+            {"key":"cart", "value": ["kiwi", "pear"]}
+        ],
+        "messages":[
+            {"content":{"text": "plum"}, "content_type":"text"},
+            {"content":{"text": "apple"}, "content_type":"text"},
+            {"content":["kiwi", "pear"], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_insert_remove_negative",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_remove_at_end() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_remove_at_end",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_remove_at_empty() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_remove_at_empty",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_insert_at_out_of_range() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_insert_at_out_of_range",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_index_of_types() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":{"text": "0"}, "content_type":"text"},
+            {"content":{"text": "-1"}, "content_type":"text"},
+            {"content":{"text": "-1"}, "content_type":"text"},
+            {"content":{"text": "1"}, "content_type":"text"},
+            {"content":{"text": "-1"}, "content_type":"text"},
+            {"content":{"text": "1"}, "content_type":"text"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_index_of_types",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_find_by_key() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":{"id": 2, "name": "Bob"}, "content_type":"object"},
+            {"content":{"text": null}, "content_type":"text"},
+            {"content":{"text": null}, "content_type":"text"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_find_by_key",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_contains() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "false"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "false"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "false"}, "content_type":"text"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_contains",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_join_nested() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":{"text": "pizza | 2 | 1.5 | true | [1,\"a\"] | {\"size\":\"xl\"}"}, "content_type":"text"},
+            {"content":{"text": ""}, "content_type":"text"},
+            {"content":{"text": "ab"}, "content_type":"text"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_join_nested",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_slice() {
+    let data = r#"
+    {
+        "memories":[{"key":"results", "value": [1, 2, 3, 4, 5, 6, 7]}],
+        "messages":[
+            {"content":[1, 2, 3, 4, 5], "content_type":"array"},
+            {"content":[6, 7], "content_type":"array"},
+            {"content":[6, 7], "content_type":"array"},
+            {"content":[5, 6], "content_type":"array"},
+            {"content":[6, 7], "content_type":"array"},
+            {"content":[1, 2], "content_type":"array"},
+            {"content":[], "content_type":"array"},
+            {"content":[], "content_type":"array"},
+            {"content":[], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_slice",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_slice_copy() {
+    let data = r#"
+    {
+        "memories":[{"key":"results", "value": [{"_content": {"id": 1}, "_content_type": "object"}, {"_content": {"id": 2}, "_content_type": "object"}]}],
+        "messages":[
+            {"content":[{"id": 42}, 3], "content_type":"array"},
+            {"content":[{"id": 1}, {"id": 2}], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_slice_copy",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_concat() {
+    let data = r#"
+    {
+        "memories":[{"key":"options", "value": ["orders"]}],
+        "messages":[
+            {"content":["help", "menu", "orders"], "content_type":"array"},
+            {"content":["help", "menu"], "content_type":"array"},
+            {"content":["help", "menu", "exit"], "content_type":"array"},
+            {"content":["help", "menu", {"label": "faq"}], "content_type":"array"},
+            {"content":["help", "menu", ["nested"]], "content_type":"array"},
+            {"content":["help", "menu"], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_concat",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_concat_closure() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_concat_closure",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_reverse() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":[{"accepts": ["four", "four"], "payload": "four", "title": "four"}, {"three": 3}, "two", 1], "content_type":"array"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":[1, "two", {"three": 3}, {"accepts": ["four", "four"], "payload": "four", "title": "four"}], "content_type":"array"},
+            {"content":{"accepts": ["four", "four"], "payload": "four", "title": "four"}, "content_type":"button"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_reverse",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_reverse_in_place() {
+    let data = r#"
+    {
+        "memories":[
+            {"key":"history", "value": [1, 2, 3]},
+            {"key":"history", "value": [3, 2, 1]}
+        ],
+        "messages":[
+            {"content":[3, 2, 1], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_reverse_in_place",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_sort() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":[-2, 0, 1.5, 3, 10], "content_type":"array"},
+            {"content":[10, 3, 1.5, 0, -2], "content_type":"array"},
+            {"content":["", "Apple", "apple", "banana", "pear"], "content_type":"array"},
+            {"content":["pear", "banana", "apple", "Apple", ""], "content_type":"array"},
+            {"content":[], "content_type":"array"},
+            {"content":[{"a": 1}], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_sort",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_sort_stable() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":[0, 1.0, 1, 2], "content_type":"array"},
+            {"content":[0, 1, 1.0, 2], "content_type":"array"},
+            {"content":[2, 1.0, 1, 0], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_sort_stable",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_sort_nan() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":[1, 2, 3, null, null], "content_type":"array"},
+            {"content":[3, 2, 1, null, null], "content_type":"array"},
+            {"content":{"text": "true"}, "content_type":"text"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_sort_nan",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_sort_mixed() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_sort_mixed",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_sort_unsortable() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_sort_unsortable",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_sort_by() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":[
+                {"name": "b", "price": 9.5},
+                {"name": "a", "price": 10},
+                {"name": "c", "price": 10},
+                {"name": "e", "price": 10},
+                {"name": "d", "price": 20}
+            ], "content_type":"array"},
+            {"content":[
+                {"name": "d", "price": 20},
+                {"name": "a", "price": 10},
+                {"name": "c", "price": 10},
+                {"name": "e", "price": 10},
+                {"name": "b", "price": 9.5}
+            ], "content_type":"array"},
+            {"content":[
+                {"name": "a", "price": 10},
+                {"name": "b", "price": 9.5},
+                {"name": "c", "price": 10},
+                {"name": "d", "price": 20},
+                {"name": "e", "price": 10}
+            ], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_sort_by",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_sort_by_missing_key() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_sort_by_missing_key",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_sort_by_incomparable() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_sort_by_incomparable",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_sort_by_not_object() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_sort_by_not_object",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_unique() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":[1, "1", true, "true", 1.5, "a", null], "content_type":"array"},
+            {"content":[{"id": 1, "tags": ["x"]}, {"id": 2}, [1]], "content_type":"array"},
+            {"content":[], "content_type":"array"},
+            {"content":[1, "1", 1, true, "true", true, 1.5, 1.5, "a", "a", null, null], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_unique",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_flatten() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":[1, 2, 3, [4], 5, [[6]]], "content_type":"array"},
+            {"content":[1, 2, 3, [4], 5, [[6]]], "content_type":"array"},
+            {"content":[1, 2, 3, 4, 5, [6]], "content_type":"array"},
+            {"content":[[1, 2], [3], [[4]], 5, [[[6]]]], "content_type":"array"},
+            {"content":[1, 2, 3, 4, 5, 6], "content_type":"array"},
+            {"content":[1, 2, 3, 4, 5, 6], "content_type":"array"},
+            {"content":[], "content_type":"array"},
+            {"content":[], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_flatten",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_flatten_wrong_depth() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_flatten_wrong_depth",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_aggregates_int() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":{"text": "40"}, "content_type":"text"},
+            {"content":{"text": "5"}, "content_type":"text"},
+            {"content":{"text": "25"}, "content_type":"text"},
+            {"content":{"text": "13.333333333333334"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_aggregates_int",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_aggregates_float() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":{"text": "11.5"}, "content_type":"text"},
+            {"content":{"text": "-1"}, "content_type":"text"},
+            {"content":{"text": "10"}, "content_type":"text"},
+            {"content":{"text": "3.8333333333333335"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_aggregates_float",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_aggregates_empty() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":{"text": "0"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_aggregates_empty",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_min_empty() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_min_empty",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_max_empty() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_max_empty",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_mean_empty() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_mean_empty",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_sum_not_numeric() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_sum_not_numeric",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_chunk() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":[[1, 2, 3], [4, 5, 6], [7]], "content_type":"array"},
+            {"content":{"text": "page 1 of 3"}, "content_type":"text"},
+            {"content":[[1, 2, 3, 4, 5, 6, 7]], "content_type":"array"},
+            {"content":[[1, 2, 3, 4, 5, 6, 7]], "content_type":"array"},
+            {"content":[], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_chunk",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_chunk_zero() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_chunk_zero",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_chunk_negative() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_chunk_negative",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_filter_by_key() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":[{"city": "Paris", "size": 120}, {"city": "Paris", "size": 40.5}], "content_type":"array"},
+            {"content":[{"city": "Lyon", "size": 80}, "not an object", {"name": "no city"}], "content_type":"array"},
+            {"content":[{"city": "Paris", "size": 120}, {"city": "Lyon", "size": 80}], "content_type":"array"},
+            {"content":[{"city": "Lyon", "size": 80}, {"city": "Paris", "size": 40.5}], "content_type":"array"},
+            {"content":["not an object", {"name": "no city"}, {"city": "Paris", "size": 40.5}], "content_type":"array"},
+            {"content":[{"city": "Lyon", "size": 80}], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_filter_by_key",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_filter_bad_operator() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_filter_bad_operator",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_pluck() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":["shoes", "hat", null, null, null], "content_type":"array"},
+            {"content":["shoes", "hat", null, null, null], "content_type":"array"},
+            {"content":[50, null, null, null, null], "content_type":"array"},
+            {"content":[{"amount": 50, "currency": "EUR"}, 12, null, null, null], "content_type":"array"},
+            {"content":[null, null, null, null, null], "content_type":"array"},
+            {"content":[], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_pluck",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_zip() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":[["Yes", "yes_payload"], ["No", "no_payload"]], "content_type":"array"},
+            {"content":[["yes_payload", "Yes"], ["no_payload", "No"]], "content_type":"array"},
+            {"content":[], "content_type":"array"},
+            {"content":[[1, {"a": 1}]], "content_type":"array"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_zip",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_zip_not_array() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_zip_not_array",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn array_is_empty_length() {
+    let data = r#"
+    {
+        "memories":[],
+        "messages":[
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "browse"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "false"}, "content_type":"text"},
+            {"content":{"text": "1"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":{"text": "checkout"}, "content_type":"text"},
+            {"content":{"text": "true"}, "content_type":"text"}
+        ]
+    }
+    "#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_is_empty_length",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_from_entries() {
+    let data = r#"{"memories":[],"messages":[{"content":{"a":3,"b":2},"content_type":"object"},{"content":{"lang":"en","theme":"dark"},"content_type":"object"},{"content":{"text":"true"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_from_entries",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn array_from_entries_error() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "array_from_entries_error",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/array.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
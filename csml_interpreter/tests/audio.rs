@@ -11,8 +11,7 @@ use serde_json::Value;
 
 #[test]
 fn ok_audio() {
-    let data =
-        r#"{"messages":[ {"content":{ "url": "test" },"content_type":"audio"} ],"memories":[]}"#;
+    let data = r#"{"messages":[ {"content":{ "url": "https://example.com/note.mp3" },"content_type":"audio"} ],"memories":[]}"#;
     let msg = format_message(
         Event::new("payload", "", serde_json::json!({})),
         Context::new(
@@ -35,7 +34,7 @@ fn ok_audio() {
 
 #[test]
 fn ok_audio_step2() {
-    let data = r#"{"messages":[ {"content":{"url": "test", "service": "youtube" },"content_type":"audio"} ],"memories":[]}"#;
+    let data = r#"{"messages":[ {"content":{"url": "https://example.com/note.mp3", "service": "youtube" },"content_type":"audio"} ],"memories":[]}"#;
     let msg = format_message(
         Event::new("payload", "", serde_json::json!({})),
         Context::new(
@@ -58,7 +57,7 @@ fn ok_audio_step2() {
 
 #[test]
 fn ok_audio_step3() {
-    let data = r#"{"messages":[ {"content":{ "url": "test", "service": "youtube" },"content_type":"audio"} ],"memories":[]}"#;
+    let data = r#"{"messages":[ {"content":{ "url": "https://example.com/note.mp3", "service": "youtube" },"content_type":"audio"} ],"memories":[]}"#;
     let msg = format_message(
         Event::new("payload", "", serde_json::json!({})),
         Context::new(
@@ -78,3 +77,84 @@ fn ok_audio_step3() {
 
     assert_eq!(v1, v2)
 }
+
+#[test]
+fn ok_audio_duration_caption() {
+    let data = r#"{
+        "messages":[
+            {
+                "content":{
+                    "url": "https://example.com/note.mp3",
+                    "duration": 12,
+                    "caption": "Voice note"
+                },
+                "content_type":"audio"
+            }
+        ],
+        "memories":[]
+    }"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "audio_full",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/built-in/audio.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn audio_invalid_arguments() {
+    for (step, info) in [
+        (
+            "audio_invalid_url",
+            "Audio component expects url to be a valid http or https URL",
+        ),
+        (
+            "audio_negative_duration",
+            "Audio component expects duration to be a non-negative int of seconds. Example: Audio(\"https://example.com/note.mp3\", duration = 12) at line 20, column 58",
+        ),
+        (
+            "audio_float_duration",
+            "Audio component expects duration to be a non-negative int of seconds",
+        ),
+        (
+            "audio_invalid_caption",
+            "argument caption in Audio component must be of type String",
+        ),
+    ]
+    .iter()
+    {
+        let msg = format_message(
+            Event::new("payload", "", serde_json::json!({})),
+            Context::new(
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                step,
+                "flow",
+                None,
+            ),
+            "CSML/basic_test/built-in/audio.csml",
+        );
+
+        let v: Value = message_to_json_value(msg);
+
+        assert_eq!(v["messages"][0]["content_type"], "error");
+        assert!(v["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
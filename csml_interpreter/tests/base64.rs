@@ -31,3 +31,97 @@ fn base64_0() {
 
     assert_eq!(v1, v2)
 }
+
+fn run_step(step: &str) -> Value {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/built-in/base64.csml",
+    );
+
+    message_to_json_value(msg)
+}
+
+#[test]
+fn base64_round_trip_object() {
+    let data = r#"{"memories":[], "messages":[
+        {"content":{"name": "Zoë", "city": "東京", "emoji": "🎉", "tags": ["crème", "brûlée"], "age": 42},"content_type":"object"},
+        {"content":{"text": "true"},"content_type":"text"}
+    ]}"#;
+
+    let v2: Value = serde_json::from_str(data).unwrap();
+    assert_eq!(run_step("round_trip_object"), v2)
+}
+
+#[test]
+fn base64_round_trip_string() {
+    let data = r#"{"memories":[], "messages":[
+        {"content":{"text": "w4dhIHZhPyDwn5GL"},"content_type":"text"},
+        {"content":{"text": "Ça va? 👋"},"content_type":"text"}
+    ]}"#;
+
+    let v2: Value = serde_json::from_str(data).unwrap();
+    assert_eq!(run_step("round_trip_string"), v2)
+}
+
+#[test]
+fn base64_decode_json_array() {
+    let data = r#"{"memories":[], "messages":[
+        {"content":[1, "two", {"three": 3}],"content_type":"array"}
+    ]}"#;
+
+    let v2: Value = serde_json::from_str(data).unwrap();
+    assert_eq!(run_step("decode_json_array"), v2)
+}
+
+#[test]
+fn base64_invalid_values() {
+    let steps = [
+        (
+            "invalid_base64",
+            "the string is not valid base64 (at: 'SGVsbG8gV29y!')",
+        ),
+        (
+            "invalid_length",
+            "the string is not valid base64 (at: 'SGVsbG8gV')",
+        ),
+        (
+            "invalid_long",
+            "the string is not valid base64 (at: '...3Igc2l0IGFtZXQsIGNvbnNlY3RldHVy!')",
+        ),
+        (
+            "invalid_utf8",
+            "the decoded value is not a valid UTF-8 string (byte: 0)",
+        ),
+        (
+            "invalid_json",
+            r#"the decoded value is not valid JSON (at: '{"name": "Zoe", }')"#,
+        ),
+        (
+            "invalid_type",
+            "Base64 builtin expects one argument of type String or Object",
+        ),
+    ];
+
+    for (step, info) in steps.iter() {
+        let value = run_step(step);
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(
+            value["messages"][0]["content"]["error"]
+                .as_str()
+                .unwrap()
+                .contains(info),
+            "{}",
+            value
+        );
+    }
+}
@@ -0,0 +1,159 @@
+mod support;
+
+use csml_interpreter::data::context::Context;
+use csml_interpreter::data::event::Event;
+use std::collections::HashMap;
+
+use crate::support::tools::format_message;
+use crate::support::tools::message_to_json_value;
+
+use serde_json::Value;
+
+#[test]
+fn boolean_conversions() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"1"},"content_type":"text"},{"content":{"text":"0"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "boolean_conversions",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/boolean.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn boolean_round_trip() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "boolean_round_trip",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/boolean.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_to_boolean_invalid() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "string_to_boolean_invalid",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/boolean.csml",
+    );
+
+    let value: Value = message_to_json_value(msg);
+
+    assert_eq!(value["messages"][0]["content_type"], "error");
+    assert!(value["messages"][0]["content"]["error"]
+        .as_str()
+        .unwrap()
+        .contains("(value: 'yes')"));
+}
+
+#[test]
+fn boolean_xor() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"exactly one"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "boolean_xor",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/boolean.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn boolean_xor_mixed_types() {
+    for (step, info) in [
+        ("boolean_xor_mixed", "PrimitiveBoolean ^ PrimitiveInt"),
+        ("boolean_xor_method_mixed", "usage: xor(other: boolean)"),
+    ] {
+        let msg = format_message(
+            Event::new("payload", "", serde_json::json!({})),
+            Context::new(
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                step,
+                "flow",
+                None,
+            ),
+            "CSML/basic_test/stdlib/boolean.csml",
+        );
+
+        let value: Value = message_to_json_value(msg);
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(value["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
+
+// int: only 0 is falsy | float: 0.0 and NaN are falsy | string: always truthy
+// array / object: falsy when empty | null: falsy
+#[test]
+fn truthiness() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"falsy"},"content_type":"text"},{"content":{"text":"truthy"},"content_type":"text"},{"content":{"text":"truthy"},"content_type":"text"},{"content":{"text":"falsy"},"content_type":"text"},{"content":{"text":"truthy"},"content_type":"text"},{"content":{"text":"falsy"},"content_type":"text"},{"content":{"text":"truthy"},"content_type":"text"},{"content":{"text":"truthy"},"content_type":"text"},{"content":{"text":"falsy"},"content_type":"text"},{"content":{"text":"truthy"},"content_type":"text"},{"content":{"text":"falsy"},"content_type":"text"},{"content":{"text":"truthy"},"content_type":"text"},{"content":{"text":"falsy"},"content_type":"text"},{"content":{"text":"truthy"},"content_type":"text"},{"content":{"text":"falsy"},"content_type":"text"},{"content":{"text":"delta is set"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "truthiness",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/boolean.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
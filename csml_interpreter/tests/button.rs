@@ -0,0 +1,90 @@
+mod support;
+
+use csml_interpreter::data::context::Context;
+use csml_interpreter::data::event::Event;
+use std::collections::HashMap;
+
+use crate::support::tools::format_message;
+use crate::support::tools::message_to_json_value;
+
+use serde_json::Value;
+
+fn run_step(step: &str) -> Value {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/built-in/button.csml",
+    );
+
+    message_to_json_value(msg)
+}
+
+#[test]
+fn button_title() {
+    let data = r#"{"messages":[ {"content":{"title": "Yes", "payload": "Yes", "accepts": ["Yes", "Yes"]},"content_type":"button"} ],"memories":[]}"#;
+
+    let v1: Value = run_step("start");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn button_payload() {
+    let data = r#"{"messages":[ {"content":{"title": "Yes", "payload": "YES_PAYLOAD", "accepts": ["Yes", "YES_PAYLOAD"], "disabled": true},"content_type":"button"} ],"memories":[]}"#;
+
+    let v1: Value = run_step("payload");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn button_url() {
+    let data = r#"{"messages":[ {"content":{"title": "Documentation", "url": "https://docs.csml.dev", "disabled": false},"content_type":"button"} ],"memories":[]}"#;
+
+    let v1: Value = run_step("url");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn button_invalid_arguments() {
+    let steps = [
+        (
+            "url_payload_error",
+            "Button component can't have both a url and a payload",
+        ),
+        (
+            "url_error",
+            "argument 'url' in Button component must be an http or https url",
+        ),
+        (
+            "disabled_error",
+            "argument 'disabled' in Button component must be of type Boolean",
+        ),
+    ];
+
+    for (step, info) in steps.iter() {
+        let value = run_step(step);
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(
+            value["messages"][0]["content"]["error"]
+                .as_str()
+                .unwrap()
+                .contains(info),
+            "{}",
+            value
+        );
+    }
+}
@@ -153,3 +153,103 @@ fn ok_carousel_step1() {
 
     assert_eq!(v1, v2)
 }
+
+#[test]
+fn ok_card_full() {
+    let data = r#"{
+        "messages":[
+            {
+                "content": {
+                    "title": "Sneakers",
+                    "subtitle": "Limited edition",
+                    "image_url": "https://example.com/sneakers.png",
+                    "buttons": [
+                        {
+                            "content": {
+                                "accepts": ["Buy", "buy_sku_42"],
+                                "payload": "buy_sku_42",
+                                "title": "Buy"
+                            },
+                            "content_type": "button"
+                        },
+                        {
+                            "content": {
+                                "accepts": ["Details", "Details"],
+                                "payload": "Details",
+                                "title": "Details"
+                            },
+                            "content_type": "button"
+                        }
+                    ]
+                },
+                "content_type": "card"
+            }
+        ],
+        "memories": []
+    }"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "card_full",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/built-in/carousel.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn card_invalid_arguments() {
+    for (step, info) in [
+        ("card_missing_title", "title is a required parameter"),
+        (
+            "card_title_error",
+            "argument title in Card component must be of type String at line 39, column 14",
+        ),
+        (
+            "card_subtitle_error",
+            "argument subtitle in Card component must be of type String at line 42, column 37",
+        ),
+        (
+            "card_button_error",
+            "must be of type Array<Button>. Example: [ Button(\"b1\"), Button(\"b2\") ] at line 45, column 53",
+        ),
+        (
+            "card_buttons_not_array",
+            "must be of type Array<Button>. Example: [ Button(\"b1\"), Button(\"b2\") ] at line 48, column 36",
+        ),
+    ]
+    .iter()
+    {
+        let msg = format_message(
+            Event::new("payload", "", serde_json::json!({})),
+            Context::new(
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                step,
+                "flow",
+                None,
+            ),
+            "CSML/basic_test/built-in/carousel.csml",
+        );
+
+        let v: Value = message_to_json_value(msg);
+
+        assert_eq!(v["messages"][0]["content_type"], "error");
+        assert!(v["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
@@ -0,0 +1,157 @@
+mod support;
+
+use csml_interpreter::data::context::Context;
+use csml_interpreter::data::csml_bot::CsmlBot;
+use csml_interpreter::data::csml_flow::CsmlFlow;
+use csml_interpreter::data::event::Event;
+use csml_interpreter::{interpret, load_components};
+use std::collections::HashMap;
+
+use crate::support::tools::message_to_json_value;
+use crate::support::tools::read_file;
+
+use serde_json::Value;
+
+fn run_step(step: &str) -> Value {
+    let content = read_file("CSML/basic_test/built-in/crypto.csml".to_owned()).unwrap();
+    let flow = CsmlFlow::new("id", "flow", &content, Vec::default());
+
+    let bot = CsmlBot::new(
+        "id",
+        "bot",
+        None,
+        vec![flow],
+        Some(load_components().unwrap()),
+        None,
+        "flow",
+        None,
+        None,
+        Some(serde_json::json!({"HMAC_SECRET": "Jefe"})),
+        None,
+        None,
+    );
+
+    let msg = interpret(
+        bot,
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        Event::new("payload", "", serde_json::json!({})),
+        None,
+    );
+
+    message_to_json_value(msg)
+}
+
+fn texts(value: &Value) -> Vec<Value> {
+    value["messages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|message| message["content"]["text"].clone())
+        .collect()
+}
+
+#[test]
+fn ok_hmac_sha256() {
+    // RFC 4231 test case 2 and the HMAC wikipedia example
+    assert_eq!(
+        texts(&run_step("hmac_sha256")),
+        vec![
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8",
+        ]
+    );
+}
+
+#[test]
+fn ok_hashes() {
+    // FIPS 180-2 and RFC 1321 test vectors
+    assert_eq!(
+        texts(&run_step("hashes")),
+        vec![
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            "900150983cd24fb0d6963f7d28e17f72",
+            "d41d8cd98f00b204e9800998ecf8427e",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ]
+    );
+}
+
+#[test]
+fn ok_signed_button() {
+    let value = run_step("signed_button");
+
+    assert_eq!(value["messages"][0]["content_type"], "button");
+    assert_eq!(
+        value["messages"][0]["content"]["payload"],
+        "order:42.5c6e3d833a5b271c3836cba78dce6cf9d36b7abdcef6b35d7854f7c4036a8995"
+    );
+}
+
+#[test]
+fn ok_verify_hmac() {
+    assert_eq!(
+        texts(&run_step("verify_hmac")),
+        vec!["true", "true", "false", "false", "false", "false"]
+    );
+}
+
+#[test]
+fn ok_hash_algos() {
+    let value = run_step("hash_algos");
+    let messages = value["messages"].as_array().unwrap();
+
+    // every algorithm listed in the error message is accepted
+    assert_eq!(messages.len(), 13);
+    assert!(messages
+        .iter()
+        .all(|message| message["content_type"] == "text"));
+}
+
+#[test]
+fn crypto_invalid_arguments() {
+    let steps = [
+        (
+            "crypto_int_error",
+            "Crypto builtin expects one argument of type String",
+        ),
+        (
+            "hmac_secret_error",
+            "usage: hmac_sha256(secret: string) => string at line 36, column 35",
+        ),
+        (
+            "hmac_missing_secret",
+            "usage: hmac_sha256(secret: string) => string",
+        ),
+        (
+            "verify_missing_secret",
+            "usage: verify_hmac(signature: string, secret: string) => boolean",
+        ),
+        ("sha256_args_error", "usage: sha256() => string"),
+        (
+            "hash_algo_error",
+            "'sha42' Invalid Algorithm, supported Algorithms are md5 sha1 sha256 sha384 sha512 sha3_224 sha3_256 sha3_384 sha3_512 shake_128 shake_256 ripemd160 sm3",
+        ),
+    ];
+
+    for (step, info) in steps.iter() {
+        let value = run_step(step);
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(
+            value["messages"][0]["content"]["error"]
+                .as_str()
+                .unwrap()
+                .contains(info),
+            "{}",
+            value
+        );
+    }
+}
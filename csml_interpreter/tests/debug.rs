@@ -1,11 +1,15 @@
 mod support;
 
 use csml_interpreter::data::context::Context;
+use csml_interpreter::data::csml_bot::CsmlBot;
+use csml_interpreter::data::csml_flow::CsmlFlow;
 use csml_interpreter::data::event::Event;
+use csml_interpreter::{interpret, load_components};
 use std::collections::HashMap;
 
 use crate::support::tools::format_message;
 use crate::support::tools::message_to_json_value;
+use crate::support::tools::read_file;
 
 use serde_json::Value;
 
@@ -55,3 +59,105 @@ fn ok_debug_step1() {
 
     assert_eq!(v1, v2)
 }
+
+fn run_step(step: &str, disable_debug: bool) -> Value {
+    let content = read_file("CSML/basic_test/syntax/debug.csml".to_owned()).unwrap();
+    let flow = CsmlFlow::new("id", "flow", &content, Vec::default());
+
+    let mut bot = CsmlBot::new(
+        "id",
+        "bot",
+        None,
+        vec![flow],
+        Some(load_components().unwrap()),
+        None,
+        "flow",
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    bot.disable_debug = disable_debug;
+
+    let msg = interpret(
+        bot,
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        Event::new("payload", "", serde_json::json!({})),
+        None,
+    );
+
+    message_to_json_value(msg)
+}
+
+#[test]
+fn ok_debug_label() {
+    let data = r#"{"messages":[ {"content":{ "value": 1, "type": "int", "label": "counter", "interval": {"start_line": 8, "start_column": 15} },"content_type":"debug"} ],"memories":[]}"#;
+
+    let v1 = run_step("debug_label", false);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn ok_debug_types() {
+    let data = r#"{"messages":[
+        {"content":{"value":42,"type":"int","interval":{"start_line":11,"start_column":15}},"content_type":"debug"},
+        {"content":{"value":4.2,"type":"float","interval":{"start_line":12,"start_column":15}},"content_type":"debug"},
+        {"content":{"value":"hello","type":"string","interval":{"start_line":13,"start_column":16,"end_line":13,"end_column":16}},"content_type":"debug"},
+        {"content":{"value":true,"type":"boolean","interval":{"start_line":14,"start_column":15}},"content_type":"debug"},
+        {"content":{"value":null,"type":"null","interval":{"start_line":15,"start_column":15}},"content_type":"debug"},
+        {"content":{"value":[1,"two"],"type":"array","interval":{"start_line":16,"start_column":15,"end_line":16,"end_column":25}},"content_type":"debug"},
+        {"content":{"value":{"key":"value"},"type":"object","interval":{"start_line":17,"start_column":15,"end_line":17,"end_column":31}},"content_type":"debug"}
+    ],"memories":[]}"#;
+
+    let v1 = run_step("debug_types", false);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn debug_invalid_arguments() {
+    let steps = [
+        ("debug_invalid_label", "at line 20, column 34"),
+        ("debug_too_many_args", "at line 23, column 9"),
+    ];
+
+    for (step, info) in steps.iter() {
+        let value = run_step(step, false);
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(value["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
+
+#[test]
+fn ok_debug_disabled() {
+    let data =
+        r#"{"messages":[ {"content":{"text":"done"},"content_type":"text"} ],"memories":[]}"#;
+
+    let v1 = run_step("debug_disabled", true);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2);
+    assert_eq!(
+        run_step("debug_disabled", false)["messages"]
+            .as_array()
+            .unwrap()
+            .len(),
+        3
+    );
+}
@@ -0,0 +1,91 @@
+mod support;
+
+use csml_interpreter::data::context::Context;
+use csml_interpreter::data::{event::Event, primitive::PrimitiveString, Hold, Interval};
+use std::collections::HashMap;
+
+use crate::support::tools::format_message;
+use crate::support::tools::message_to_json_value;
+
+use serde_json::Value;
+
+fn run_step(step: &str, name: Option<&str>, hold: Option<Hold>) -> (Value, Option<Hold>) {
+    let mut current = HashMap::new();
+    if let Some(name) = name {
+        current.insert(
+            "name".to_owned(),
+            PrimitiveString::get_literal(name, Interval::default()),
+        );
+    }
+
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(current, HashMap::new(), None, hold, step, "flow", None),
+        "CSML/basic_test/do_statement.csml",
+    );
+    let hold = msg.hold.clone();
+
+    (message_to_json_value(msg), hold)
+}
+
+fn get_texts(value: &Value) -> Vec<Value> {
+    value["messages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|message| message["content"]["text"].clone())
+        .collect()
+}
+
+#[test]
+fn do_shadows_memory() {
+    let (value, _) = run_step("start", Some("memory"), None);
+
+    assert_eq!(
+        get_texts(&value),
+        vec!["shadowed in condition", "hello step"]
+    );
+    // the step variable is never saved as a memory
+    assert_eq!(value["memories"], serde_json::json!([]));
+}
+
+#[test]
+fn do_is_gone_next_turn() {
+    let (value, _) = run_step("start", None, None);
+    assert_eq!(value["memories"], serde_json::json!([]));
+
+    let (value, _) = run_step("read_name", Some("memory"), None);
+    assert_eq!(get_texts(&value), vec!["memory"]);
+
+    let (value, _) = run_step("read_name", None, None);
+    let error = value["messages"][0]["content"]["error"].as_str().unwrap();
+    assert!(error.contains("< name > is used before it was saved in memory"));
+}
+
+#[test]
+fn do_kept_across_hold() {
+    let (value, hold) = run_step("with_hold", Some("memory"), None);
+    assert_eq!(get_texts(&value), Vec::<Value>::new());
+
+    let (value, _) = run_step("with_hold", Some("memory"), hold);
+    assert_eq!(get_texts(&value), vec!["step"]);
+}
+
+#[test]
+fn do_exec() {
+    let (value, _) = run_step("exec", None, None);
+
+    assert_eq!(get_texts(&value), vec!["3"]);
+    assert_eq!(value["memories"], serde_json::json!([]));
+}
+
+#[test]
+fn do_update_memory() {
+    let (value, _) = run_step("update_memory", None, None);
+
+    assert_eq!(get_texts(&value), vec!["10"]);
+    assert_eq!(
+        value["memories"],
+        serde_json::json!([{"key": "count", "value": 1}, {"key": "count", "value": 2}])
+    );
+}
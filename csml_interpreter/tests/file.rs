@@ -0,0 +1,102 @@
+mod support;
+
+use csml_interpreter::data::context::Context;
+use csml_interpreter::data::event::Event;
+use std::collections::HashMap;
+
+use crate::support::tools::format_message;
+use crate::support::tools::message_to_json_value;
+
+use serde_json::Value;
+
+fn run_step(step: &str) -> Value {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/built-in/file.csml",
+    );
+
+    message_to_json_value(msg)
+}
+
+#[test]
+fn ok_file_query_string() {
+    let data = r#"{"messages":[ {"content":{ "url": "https://example.com/invoices/2021-04.pdf?token=abc&download=1", "filename": "2021-04.pdf" },"content_type":"file"} ],"memories":[]}"#;
+
+    let v1: Value = run_step("start");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn ok_file_filename_mime_type() {
+    let data = r#"{"messages":[ {"content":{ "url": "https://example.com/download?id=42", "filename": "invoice.pdf", "mime_type": "application/pdf" },"content_type":"file"} ],"memories":[]}"#;
+
+    let v1: Value = run_step("file_full");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn ok_file_encoded_filename() {
+    let data = r#"{"messages":[ {"content":{ "url": "https://example.com/docs/My%20Invoice.pdf#page=2", "filename": "My Invoice.pdf" },"content_type":"file"} ],"memories":[]}"#;
+
+    let v1: Value = run_step("file_encoded_name");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn ok_file_without_path() {
+    let data = r#"{"messages":[ {"content":{ "url": "https://example.com/" },"content_type":"file"} ],"memories":[]}"#;
+
+    let v1: Value = run_step("file_without_path");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn ok_file_unknown_mime_type() {
+    let data = r#"{"messages":[ {"content":{ "url": "https://example.com/report.xyz", "filename": "report.xyz", "mime_type": "application/x-custom" },"content_type":"file"} ],"memories":[]}"#;
+
+    let v1: Value = run_step("file_unknown_mime_type");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn file_invalid_arguments() {
+    for (step, info) in [
+        (
+            "file_invalid_url",
+            "File component expects url to be a valid http or https URL",
+        ),
+        (
+            "file_invalid_mime_type",
+            "argument mime_type in File component must be of type String",
+        ),
+    ]
+    .iter()
+    {
+        let v: Value = run_step(step);
+
+        assert_eq!(v["messages"][0]["content_type"], "error");
+        assert!(v["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
@@ -2,10 +2,12 @@ mod support;
 
 use csml_interpreter::data::context::Context;
 use csml_interpreter::data::event::Event;
+use csml_interpreter::parser::parse_flow;
 use std::collections::HashMap;
 
 use crate::support::tools::format_message;
 use crate::support::tools::message_to_json_value;
+use crate::support::tools::read_file;
 
 use serde_json::Value;
 
@@ -56,3 +58,110 @@ fn ok_find_step1() {
 
     assert_eq!(v1, v2)
 }
+
+fn run_step(event: Event, step: &str) -> Value {
+    let msg = format_message(
+        event,
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/built-in/find.csml",
+    );
+
+    message_to_json_value(msg)
+}
+
+fn texts(value: &Value) -> Vec<&str> {
+    value["messages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|message| message["content"]["text"].as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn find_in_string() {
+    let value = run_step(
+        Event::new("payload", "", serde_json::json!({})),
+        "find_string",
+    );
+
+    assert_eq!(texts(&value), vec!["true", "false", "true", "false"]);
+}
+
+#[test]
+fn find_in_event() {
+    for (text, expected) in [("Yes please", "confirmed"), ("nope", "cancelled")].iter() {
+        let value = run_step(
+            Event::new("text", text, serde_json::json!({ "text": text })),
+            "find_event",
+        );
+
+        assert_eq!(texts(&value), vec![*expected]);
+    }
+}
+
+#[test]
+fn find_in_array() {
+    let value = run_step(
+        Event::new("payload", "", serde_json::json!({})),
+        "find_array",
+    );
+
+    assert_eq!(
+        texts(&value),
+        vec!["true", "false", "true", "true", "true", "false", "false"]
+    );
+}
+
+#[test]
+fn find_invalid_arguments() {
+    for (step, line) in [
+        ("find_missing_in", "at line 33, column 9"),
+        ("find_invalid_haystack", "at line 36, column 26"),
+        ("find_invalid_case_sensitive", "at line 39, column 51"),
+        ("find_invalid_value", "at line 42, column 14"),
+    ]
+    .iter()
+    {
+        let value = run_step(Event::new("payload", "", serde_json::json!({})), step);
+        let error = value["messages"][0]["content"]["error"].as_str().unwrap();
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(error
+            .contains("Find builtin expects a value and an 'in' param of type String or Array"));
+        assert!(error.contains(line));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+/// FIND NAMED ARGUMENTS SYNTAX
+////////////////////////////////////////////////////////////////////////////////
+
+fn parse_file(filepath: &str) -> bool {
+    let text = read_file(filepath.to_owned()).unwrap();
+
+    parse_flow(&text, "Test").is_ok()
+}
+
+#[test]
+fn find_syntax_named_arguments() {
+    assert!(parse_file("CSML/basic_test/syntax/find/find_0.csml"));
+}
+
+#[test]
+fn find_syntax_missing_in_value() {
+    assert!(!parse_file("CSML/basic_test/syntax/find/find_1.csml"));
+}
+
+#[test]
+fn find_syntax_missing_equal() {
+    assert!(!parse_file("CSML/basic_test/syntax/find/find_2.csml"));
+}
@@ -0,0 +1,113 @@
+mod support;
+
+use csml_interpreter::data::context::Context;
+use csml_interpreter::data::event::Event;
+use std::collections::HashMap;
+
+use crate::support::tools::format_message;
+use crate::support::tools::message_to_json_value;
+
+use serde_json::Value;
+
+fn run_step(step: &str, loop_limit: Option<usize>) -> Vec<Value> {
+    let mut event = Event::new("payload", "", serde_json::json!({}));
+    event.loop_limit = loop_limit;
+
+    let msg = format_message(
+        event,
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/foreach.csml",
+    );
+
+    message_to_json_value(msg)["messages"]
+        .as_array()
+        .unwrap()
+        .to_owned()
+}
+
+fn get_texts(messages: Vec<Value>) -> Vec<Value> {
+    messages
+        .iter()
+        .map(|message| message["content"]["text"].clone())
+        .collect()
+}
+
+#[test]
+fn foreach_array() {
+    assert_eq!(
+        get_texts(run_step("start", None)),
+        vec!["0: a", "1: b", "2: c", "after"]
+    );
+}
+
+#[test]
+fn foreach_object() {
+    assert_eq!(
+        get_texts(run_step("object", None)),
+        vec!["0: book 12", "1: cup 5", "2: pen 2"]
+    );
+}
+
+#[test]
+fn foreach_empty() {
+    assert_eq!(get_texts(run_step("empty", None)), vec!["empty"]);
+}
+
+#[test]
+fn foreach_cards() {
+    let messages = run_step("cards", None);
+
+    assert_eq!(
+        serde_json::json!(messages),
+        serde_json::json!([
+            {"content": {"title": "pen"}, "content_type": "card"},
+            {"content": {"title": "book"}, "content_type": "card"}
+        ])
+    );
+}
+
+#[test]
+fn foreach_goto_in_body() {
+    assert_eq!(
+        get_texts(run_step("goto_in_body", None)),
+        vec!["1", "2", "next"]
+    );
+}
+
+#[test]
+fn foreach_scoped_vars() {
+    let texts = get_texts(run_step("scoped", None));
+
+    assert_eq!(texts.first().unwrap(), "1");
+    assert_eq!(texts.last().unwrap(), "Null Null");
+}
+
+#[test]
+fn foreach_loop_limit() {
+    assert_eq!(
+        get_texts(run_step("start", Some(3))),
+        vec!["0: a", "1: b", "2: c", "after"]
+    );
+
+    let vec = [
+        ("start", "line 2, column 12"),
+        ("object", "line 10, column 12"),
+    ];
+
+    for (step, info) in vec.iter() {
+        let messages = run_step(step, Some(2));
+        let error = messages[0]["content"]["error"].as_str().unwrap();
+
+        assert_eq!(messages.len(), 1);
+        assert!(error.contains("Loop limit reached (2 iterations)"));
+        assert!(error.contains(info));
+    }
+}
@@ -1,11 +1,21 @@
 mod support;
 
 use csml_interpreter::data::ast::Flow;
+use csml_interpreter::data::context::Context;
+use csml_interpreter::data::csml_bot::CsmlBot;
+use csml_interpreter::data::csml_flow::CsmlFlow;
+use csml_interpreter::data::event::Event;
 use csml_interpreter::error_format::ErrorInfo;
 use csml_interpreter::parser::parse_flow;
+use csml_interpreter::validate_bot;
+use std::collections::HashMap;
 
+use support::tools::format_message as format_message_exec;
+use support::tools::message_to_json_value;
 use support::tools::read_file;
 
+use serde_json::Value;
+
 fn format_message(filepath: String) -> Result<Flow, ErrorInfo> {
     let text = read_file(filepath).unwrap();
 
@@ -21,3 +31,117 @@ fn functions_syntax() {
 
     assert!(result);
 }
+
+fn run_step(step: &str) -> Vec<Value> {
+    let msg = format_message_exec(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/functions.csml",
+    );
+
+    message_to_json_value(msg)["messages"]
+        .as_array()
+        .unwrap()
+        .to_owned()
+}
+
+fn get_texts(messages: Vec<Value>) -> Vec<Value> {
+    messages
+        .iter()
+        .map(|message| message["content"]["text"].clone())
+        .collect()
+}
+
+#[test]
+fn functions_call() {
+    assert_eq!(get_texts(run_step("start")), vec!["12.5 EUR", "0.05 USD"]);
+    assert_eq!(get_texts(run_step("normalize")), vec!["hello john"]);
+}
+
+#[test]
+fn functions_local_args() {
+    assert_eq!(
+        get_texts(run_step("local_args")),
+        vec!["0.99 EUR", "step value"]
+    );
+}
+
+#[test]
+fn functions_recursion() {
+    assert_eq!(get_texts(run_step("recursion")), vec!["120"]);
+}
+
+#[test]
+fn functions_errors() {
+    let vec = [
+        (
+            "infinite_recursion",
+            "Function call depth limit reached (50 nested calls)",
+        ),
+        (
+            "too_few_args",
+            "format_price expects 2 argument(s) but 1 were given",
+        ),
+        (
+            "too_many_args",
+            "format_price expects 2 argument(s) but 3 were given",
+        ),
+    ];
+
+    for (step, info) in vec.iter() {
+        let messages = run_step(step);
+        let error = messages[0]["content"]["error"].as_str().unwrap();
+
+        assert_eq!(messages.len(), 1);
+        assert!(error.contains(info));
+    }
+}
+
+#[test]
+fn functions_reject_say() {
+    let content = r#"
+start:
+    say greet("john")
+    goto end
+
+fn greet(name) {
+    say "hello"
+    remember last_name = name
+    return name
+}
+"#;
+    let flow = CsmlFlow::new("id", "flow", content, Vec::default());
+    let bot = CsmlBot::new(
+        "id",
+        "bot",
+        None,
+        vec![flow],
+        None,
+        None,
+        "flow",
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+
+    let errors: Vec<String> = validate_bot(&bot)
+        .errors
+        .unwrap()
+        .iter()
+        .map(|error| error.message.to_owned())
+        .collect();
+
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].contains("'say' action is not allowed in function scope"));
+    assert!(errors[1].contains("remember"));
+}
@@ -1,12 +1,17 @@
 mod support;
 
+use csml_interpreter::data::csml_bot::CsmlBot;
+use csml_interpreter::data::csml_flow::CsmlFlow;
 use csml_interpreter::data::event::Event;
 use csml_interpreter::data::hold::{Hold, IndexInfo};
-use csml_interpreter::data::Context;
+use csml_interpreter::data::{Context, MessageData, MSG};
+use csml_interpreter::{interpret, load_components};
 use std::collections::HashMap;
+use std::sync::mpsc;
 
 use crate::support::tools::format_message;
 use crate::support::tools::message_to_json_value;
+use crate::support::tools::read_file;
 
 use serde_json::Value;
 
@@ -348,3 +353,96 @@ fn hold_test_step_4_ok() {
 
     assert_eq!(v1, v2)
 }
+
+fn run_with_sender(step: &str) -> (MessageData, Vec<Hold>) {
+    let content = read_file("CSML/basic_test/hold.csml".to_owned()).unwrap();
+    let flow = CsmlFlow::new("id", "flow", &content, Vec::default());
+
+    let bot = CsmlBot::new(
+        "id",
+        "bot",
+        None,
+        vec![flow],
+        Some(load_components().unwrap()),
+        None,
+        "flow",
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+
+    let (sender, receiver) = mpsc::channel::<MSG>();
+    let msg = interpret(
+        bot,
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        Event::new("payload", "", serde_json::json!({})),
+        Some(sender),
+    );
+
+    let holds = receiver
+        .try_iter()
+        .filter_map(|received| match received {
+            MSG::Hold(hold) => Some(hold),
+            _ => None,
+        })
+        .collect();
+
+    (msg, holds)
+}
+
+#[test]
+fn hold_in_scope_returned_as_sent() {
+    let steps = [
+        (
+            "hold_1_ok",
+            serde_json::json!({"command_index": 4, "loop_index": []}),
+            4,
+        ),
+        (
+            "hold_3_ok",
+            serde_json::json!({"command_index": 6, "loop_index": []}),
+            1,
+        ),
+        (
+            "hold_4_ok",
+            serde_json::json!({"command_index": 4, "loop_index": [0]}),
+            2,
+        ),
+    ];
+
+    for (step, index, messages) in steps.iter() {
+        let (msg, holds) = run_with_sender(step);
+
+        // the messages sent before the hold are the same, the hold of a nested
+        // scope is now also returned with them
+        assert_eq!(msg.messages.len(), *messages);
+        assert!(msg
+            .messages
+            .iter()
+            .all(|message| message.content["text"] == "ERROR"));
+        assert_eq!(holds.len(), 1);
+        assert_eq!(serde_json::to_value(&holds[0].index).unwrap(), *index);
+        assert_eq!(
+            serde_json::to_value(&msg.hold.unwrap().index).unwrap(),
+            *index
+        );
+    }
+}
+
+#[test]
+fn goto_without_hold_returns_no_hold() {
+    let (msg, holds) = run_with_sender("start");
+
+    assert!(holds.is_empty());
+    assert!(msg.hold.is_none());
+}
@@ -1,8 +1,15 @@
 mod support;
 
-use csml_interpreter::data::context::Context;
+use csml_interpreter::data::context::{ApiInfo, Context};
 use csml_interpreter::data::event::Event;
+use csml_interpreter::data::Client;
+use csml_interpreter::interpreter::builtins::http_builtin::{
+    set_http_client, HttpRequest, DEFAULT_HTTP_TIMEOUT,
+};
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
 
 use crate::support::tools::format_message;
 use crate::support::tools::message_to_json_value;
@@ -563,3 +570,249 @@ fn http_auth_0() {
 
     assert_eq!(v1, v2)
 }
+
+mod mock {
+    use csml_interpreter::interpreter::builtins::http_builtin::{
+        HttpClient, HttpError, HttpRequest, HttpResponse,
+    };
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    pub struct MockClient {
+        pub requests: Rc<RefCell<Vec<HttpRequest>>>,
+    }
+
+    impl HttpClient for MockClient {
+        fn call(&self, request: &HttpRequest) -> Result<HttpResponse, HttpError> {
+            self.requests.borrow_mut().push(request.clone());
+
+            let response = |status: u16, body: &str| HttpResponse {
+                status,
+                headers: vec![("content-type".to_owned(), "application/json".to_owned())],
+                body: Some(body.to_owned()),
+            };
+
+            match request.url.as_str() {
+                "https://api.example.com/stocks?symbol=ACME" | "https://api.example.com/stocks" => {
+                    Ok(response(200, r#"{"symbol": "ACME", "price": 42.5}"#))
+                }
+                "https://api.example.com/crm" => Ok(response(
+                    201,
+                    &serde_json::json!({ "received": request.body }).to_string(),
+                )),
+                "https://apps.example.com/functions" => Ok(response(
+                    200,
+                    &serde_json::json!({ "data": request.body.as_ref().unwrap()["data"] })
+                        .to_string(),
+                )),
+                "https://api.example.com/missing" => {
+                    Err(HttpError::Status(response(404, "not found")))
+                }
+                _ => Err(HttpError::Transport("connection refused".to_owned())),
+            }
+        }
+    }
+}
+
+fn run_with_mock(step: &str) -> (Value, Vec<HttpRequest>) {
+    run_with_mock_api(step, None)
+}
+
+fn run_with_mock_api(step: &str, api_info: Option<ApiInfo>) -> (Value, Vec<HttpRequest>) {
+    let requests = Rc::new(RefCell::new(vec![]));
+    set_http_client(Some(Box::new(mock::MockClient {
+        requests: requests.clone(),
+    })));
+
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            api_info,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/http.csml",
+    );
+    set_http_client(None);
+
+    let requests = requests.borrow().clone();
+    (message_to_json_value(msg), requests)
+}
+
+#[test]
+fn http_timeout_0() {
+    let data = r#"{
+        "memories":[
+            {
+                "key":"http",
+                "value":{
+                    "_content":{
+                        "header":{
+                            "Accept":"application/json,text/*",
+                            "Content-Type":"application/json",
+                            "User-Agent": "csml/v1"
+                        },
+                        "method":"get",
+                        "timeout":2.5,
+                        "url":"https://clevy.io"
+                    },
+                    "_content_type":"http"
+                }
+            }
+        ],
+        "messages":[
+            {
+                "content":{
+                    "header":{
+                        "Accept":"application/json,text/*",
+                        "Content-Type":"application/json",
+                        "User-Agent": "csml/v1"
+                    },
+                    "method":"get",
+                    "timeout":2.5,
+                    "url":"https://clevy.io"
+                },
+                "content_type":"http"
+            }
+        ]}"#;
+
+    let (v1, requests) = run_with_mock("timeout_0");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2);
+    assert!(requests.is_empty());
+}
+
+#[test]
+fn http_timeout_error() {
+    let (value, _) = run_with_mock("timeout_error");
+
+    assert_eq!(value["messages"][0]["content_type"], "error");
+    assert!(value["messages"][0]["content"]["error"]
+        .as_str()
+        .unwrap()
+        .contains("[timeout] takes one positive number of seconds"));
+}
+
+#[test]
+fn http_timeout_overflow() {
+    let (value, requests) = run_with_mock("timeout_overflow");
+
+    assert_eq!(value["messages"][0]["content_type"], "error");
+    assert!(value["messages"][0]["content"]["error"]
+        .as_str()
+        .unwrap()
+        .contains("[timeout] takes one positive number of seconds"));
+    assert!(requests.is_empty());
+}
+
+#[test]
+fn http_fetch_get() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content":{"text":"200"},"content_type":"text"},
+            {"content":{"content-type":"application/json"},"content_type":"object"},
+            {"content":{"text":"42.5"},"content_type":"text"},
+            {"content":{"text":"false"},"content_type":"text"}
+        ]}"#;
+
+    let (v1, requests) = run_with_mock("fetch_get");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2);
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "get");
+    assert_eq!(requests[0].timeout, Some(Duration::from_secs(2)));
+    assert!(requests[0]
+        .headers
+        .contains(&("X-Api-Key".to_owned(), "secret".to_owned())));
+    assert_eq!(requests[0].body, None);
+}
+
+#[test]
+fn http_fetch_post() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content":{"text":"201"},"content_type":"text"},
+            {"content":{"received":{"name":"Jane"}},"content_type":"object"}
+        ]}"#;
+
+    let (v1, requests) = run_with_mock("fetch_post");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2);
+    assert_eq!(requests[0].method, "post");
+    assert_eq!(requests[0].timeout, Some(DEFAULT_HTTP_TIMEOUT));
+}
+
+#[test]
+fn http_fetch_not_found() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content":{"text":"404"},"content_type":"text"},
+            {"content":{"text":"not found"},"content_type":"text"},
+            {"content":{"text":"https://api.example.com/missing: status code 404"},"content_type":"text"},
+            {"content":{"text":"still running"},"content_type":"text"}
+        ]}"#;
+
+    let (v1, _) = run_with_mock("fetch_not_found");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2);
+}
+
+#[test]
+fn http_fetch_network_error() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content":{"text":"connection refused"},"content_type":"text"},
+            {"content":{"text":"still running"},"content_type":"text"}
+        ]}"#;
+
+    let (v1, _) = run_with_mock("fetch_network_error");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2);
+}
+
+#[test]
+fn http_send_mock() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content":{"symbol":"ACME","price":42.5},"content_type":"object"}
+        ]}"#;
+
+    let (v1, requests) = run_with_mock("send_mock");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2);
+    assert_eq!(requests[0].timeout, Some(DEFAULT_HTTP_TIMEOUT));
+}
+
+#[test]
+fn http_app_call_without_timeout() {
+    let api_info = ApiInfo {
+        client: Client::new("bot".to_owned(), "channel".to_owned(), "user".to_owned()),
+        apps_endpoint: "https://apps.example.com/functions".to_owned(),
+    };
+
+    let (value, requests) = run_with_mock_api("app_call", Some(api_info));
+
+    assert_eq!(
+        value["messages"][0]["content"],
+        serde_json::json!({"name": "Jane"})
+    );
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "post");
+    // App() calls are not cut off at the default timeout of HTTP()
+    assert_eq!(requests[0].timeout, None);
+}
@@ -0,0 +1,61 @@
+mod support;
+
+use csml_interpreter::data::context::Context;
+use csml_interpreter::data::event::Event;
+use std::collections::HashMap;
+
+use crate::support::tools::format_message;
+use crate::support::tools::message_to_json_value;
+
+use serde_json::Value;
+
+fn run_step(step: &str, event: &str) -> Vec<Value> {
+    let msg = format_message(
+        Event::new("text", event, serde_json::json!({ "text": event })),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/if_else.csml",
+    );
+
+    message_to_json_value(msg)["messages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|message| message["content"]["text"].clone())
+        .collect()
+}
+
+#[test]
+fn else_if_chain() {
+    assert_eq!(run_step("start", "a"), vec!["first", "after"]);
+    assert_eq!(run_step("start", "b"), vec!["second", "after"]);
+    assert_eq!(run_step("start", "c"), vec!["third", "after"]);
+}
+
+#[test]
+fn dangling_else() {
+    assert_eq!(run_step("dangling_else", "a"), vec!["inner if", "after"]);
+    assert_eq!(run_step("dangling_else", "b"), vec!["inner else", "after"]);
+    assert_eq!(run_step("dangling_else", "c"), vec!["after"]);
+}
+
+#[test]
+fn nested_if_else() {
+    assert_eq!(run_step("nested", "a"), vec!["a nested else"]);
+    assert_eq!(run_step("nested", "b"), vec!["b nested if"]);
+    assert_eq!(run_step("nested", "c"), vec!["c nested else if"]);
+    assert_eq!(run_step("nested", "d"), Vec::<Value>::new());
+}
+
+#[test]
+fn goto_in_else() {
+    assert_eq!(run_step("goto_in_else", "a"), vec!["a", "after"]);
+    assert_eq!(run_step("goto_in_else", "b"), vec!["else", "next"]);
+}
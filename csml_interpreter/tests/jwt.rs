@@ -0,0 +1,150 @@
+mod support;
+
+use csml_interpreter::data::context::Context;
+use csml_interpreter::data::event::Event;
+use csml_interpreter::data::primitive::{tools_time, PrimitiveString};
+use csml_interpreter::data::{Interval, Literal};
+use std::collections::HashMap;
+
+use crate::support::tools::format_message;
+use crate::support::tools::message_to_json_value;
+
+use serde_json::Value;
+
+// 2020-09-13T12:26:40Z
+const NOW: i64 = 1_600_000_000_000;
+
+fn run_step(step: &str, now: i64, metadata: HashMap<String, Literal>) -> Value {
+    tools_time::set_mock_now(Some(now));
+
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(HashMap::new(), metadata, None, None, step, "flow", None),
+        "CSML/basic_test/built-in/jwt.csml",
+    );
+
+    tools_time::set_mock_now(None);
+
+    message_to_json_value(msg)
+}
+
+fn contents(value: &Value) -> Vec<Value> {
+    value["messages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|message| message["content"].clone())
+        .collect()
+}
+
+fn verify_at(token: &str, now: i64) -> Value {
+    let mut metadata = HashMap::new();
+    metadata.insert(
+        "token".to_owned(),
+        PrimitiveString::get_literal(token, Interval::default()),
+    );
+
+    run_step("verify_token", now, metadata)
+}
+
+#[test]
+fn ok_jwt_round_trip() {
+    let value = run_step("round_trip", NOW, HashMap::new());
+
+    assert_eq!(
+        contents(&value),
+        vec![
+            serde_json::json!({"user": "jane", "iat": 1600000000, "exp": 1600003600}),
+            serde_json::json!({"text": "false"}),
+        ]
+    );
+}
+
+#[test]
+fn ok_jwt_algorithms() {
+    let value = run_step("algorithms", NOW, HashMap::new());
+
+    assert_eq!(
+        contents(&value),
+        vec![serde_json::json!({"user": "jane"}); 3]
+    );
+}
+
+#[test]
+fn ok_jwt_legacy_sign() {
+    let value = run_step("legacy_sign", NOW, HashMap::new());
+
+    assert_eq!(contents(&value), vec![serde_json::json!({"user": "jane"})]);
+}
+
+#[test]
+fn jwt_expired_token() {
+    let value = run_step("sign_token", NOW, HashMap::new());
+    let token = value["messages"][0]["content"]["text"].as_str().unwrap();
+
+    let valid = verify_at(token, NOW + 59_000);
+    assert_eq!(
+        contents(&valid),
+        vec![
+            serde_json::json!({"user": "jane", "exp": 1600000060}),
+            serde_json::json!({"text": "false"}),
+        ]
+    );
+
+    let expired = verify_at(token, NOW + 60_000);
+    assert_eq!(expired["messages"][0]["content"]["reason"], "expired");
+    assert_eq!(expired["messages"][1]["content"]["text"], "true");
+}
+
+#[test]
+fn jwt_rejected_tokens() {
+    let steps = [
+        ("wrong_secret", "bad_signature"),
+        ("tampered", "bad_signature"),
+        ("malformed", "malformed"),
+        ("not_before", "not_yet_valid"),
+    ];
+
+    for (step, reason) in steps.iter() {
+        let value = run_step(step, NOW, HashMap::new());
+
+        assert_eq!(value["messages"][0]["content_type"], "object");
+        assert_eq!(
+            value["messages"][0]["content"]["reason"], *reason,
+            "{}",
+            step
+        );
+    }
+}
+
+#[test]
+fn jwt_invalid_arguments() {
+    let steps = [
+        (
+            "sign_secret_error",
+            "JWT(claims).sign(secret, Optional<algo>, Optional<Header>) expect argument 'secret' of type String",
+        ),
+        (
+            "sign_algo_error",
+            "Invalid Algorithm, supported Algorithms are HS256, HS384, HS512",
+        ),
+        (
+            "verify_secret_error",
+            "JWT(jwt).verify(secret) expect argument 'secret' of type String",
+        ),
+    ];
+
+    for (step, info) in steps.iter() {
+        let value = run_step(step, NOW, HashMap::new());
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(
+            value["messages"][0]["content"]["error"]
+                .as_str()
+                .unwrap()
+                .contains(info),
+            "{}",
+            value
+        );
+    }
+}
@@ -75,3 +75,60 @@ fn ok_length_2() {
 
     assert_eq!(msg.messages[0].content_type, "error")
 }
+
+fn run_step(step: &str) -> csml_interpreter::data::MessageData {
+    format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/built-in/length.csml",
+    )
+}
+
+#[test]
+fn ok_length_same_as_method() {
+    let data = r#"{"messages":[ {"content":[[5,5],[0,0],[1,1],[8,8],[0,0],[3,3],[0,0],[2,2]],"content_type":"array"} ],"memories":[]}"#;
+
+    let v1: Value = message_to_json_value(run_step("compare_paths"));
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn ok_length_object() {
+    let data =
+        r#"{"messages":[ {"content":{ "text": "2"  },"content_type":"text"} ],"memories":[]}"#;
+
+    let v1: Value = message_to_json_value(run_step("length_object"));
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn length_invalid_types() {
+    let steps = [
+        ("step_1", "(type: int) at line 10, column 13"),
+        ("length_float", "(type: float) at line 26, column 13"),
+        ("length_bool", "(type: boolean) at line 30, column 13"),
+        ("length_null", "(type: null) at line 34, column 13"),
+    ];
+
+    for (step, info) in steps.iter() {
+        let msg = run_step(step);
+
+        assert_eq!(msg.messages[0].content_type, "error");
+        assert!(msg.messages[0].content["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
@@ -0,0 +1,128 @@
+mod support;
+
+use csml_interpreter::data::context::Context;
+use csml_interpreter::data::event::Event;
+use std::collections::HashMap;
+
+use crate::support::tools::format_message;
+use crate::support::tools::message_to_json_value;
+
+use serde_json::Value;
+
+fn run_step(event: Event, step: &str) -> Value {
+    let msg = format_message(
+        event,
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/built-in/location.csml",
+    );
+
+    message_to_json_value(msg)
+}
+
+#[test]
+fn ok_location() {
+    let data = r#"{"messages":[ {"content":{ "lat": 48.8584, "long": 2.2945 },"content_type":"location"} ],"memories":[]}"#;
+
+    let v1: Value = run_step(Event::new("payload", "", serde_json::json!({})), "start");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn ok_location_title_address() {
+    let data = r#"{
+        "messages":[
+            {
+                "content":{
+                    "lat": 40.6892,
+                    "long": -74.0445,
+                    "title": "Statue of Liberty",
+                    "address": "Liberty Island, New York"
+                },
+                "content_type":"location"
+            }
+        ],
+        "memories":[]
+    }"#;
+
+    let v1: Value = run_step(
+        Event::new("payload", "", serde_json::json!({})),
+        "location_full",
+    );
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn location_invalid_arguments() {
+    for (step, info) in [
+        (
+            "location_invalid_lat",
+            "Location component expects lat to be a number between -90 and 90 (value: 91) at line 9, column 18",
+        ),
+        (
+            "location_invalid_long",
+            "Location component expects long to be a number between -180 and 180 (value: -180.5)",
+        ),
+        (
+            "location_invalid_type",
+            "Location component expects lat to be a number between -90 and 90",
+        ),
+        (
+            "location_invalid_title",
+            "Location component expects title and address to be of type String",
+        ),
+    ]
+    .iter()
+    {
+        let v: Value = run_step(
+            Event::new("payload", "", serde_json::json!({})),
+            step,
+        );
+
+        assert_eq!(v["messages"][0]["content_type"], "error");
+        assert!(v["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
+
+#[test]
+fn ok_location_event() {
+    let data = r#"{
+        "messages":[
+            {"content":{"text": "51.5074"}, "content_type":"text"},
+            {"content":{"text": "-0.1278"}, "content_type":"text"},
+            {"content":{"text": "float"}, "content_type":"text"},
+            {"content":{"text": "341"}, "content_type":"text"}
+        ],
+        "memories":[]
+    }"#;
+
+    for content in [
+        serde_json::json!({"lat": 51.5074, "long": -0.1278}),
+        serde_json::json!({"latitude": "51.5074", "lng": -0.1278}),
+        serde_json::json!({"latitude": 51.5074, "longitude": "-0.1278"}),
+    ]
+    .iter()
+    {
+        let v1: Value = run_step(
+            Event::new("location", "51.5074,-0.1278", content.to_owned()),
+            "location_event",
+        );
+        let v2: Value = serde_json::from_str(data).unwrap();
+
+        assert_eq!(v1, v2)
+    }
+}
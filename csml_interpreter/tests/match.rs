@@ -0,0 +1,126 @@
+mod support;
+
+use csml_interpreter::data::context::Context;
+use csml_interpreter::data::csml_bot::CsmlBot;
+use csml_interpreter::data::csml_flow::CsmlFlow;
+use csml_interpreter::data::event::Event;
+use csml_interpreter::data::Hold;
+use csml_interpreter::validate_bot;
+use std::collections::HashMap;
+
+use crate::support::tools::format_message;
+use crate::support::tools::message_to_json_value;
+use crate::support::tools::read_file;
+
+use serde_json::Value;
+
+fn run_step(step: &str, event: &str, hold: Option<Hold>) -> (Vec<Value>, Option<Hold>) {
+    let msg = format_message(
+        Event::new("text", event, serde_json::json!({ "text": event })),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            hold,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/match.csml",
+    );
+    let hold = msg.hold.clone();
+
+    let texts = message_to_json_value(msg)["messages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|message| message["content"]["text"].clone())
+        .collect();
+
+    (texts, hold)
+}
+
+fn get_texts(step: &str, event: &str) -> Vec<Value> {
+    run_step(step, event, None).0
+}
+
+#[test]
+fn match_string_arms() {
+    assert_eq!(get_texts("start", "a"), vec!["a", "after"]);
+    assert_eq!(get_texts("start", "b"), vec!["b or c", "after"]);
+    assert_eq!(get_texts("start", "c"), vec!["b or c", "after"]);
+    assert_eq!(get_texts("start", "d"), vec!["other", "after"]);
+}
+
+#[test]
+fn match_mixed_arms() {
+    assert_eq!(get_texts("mixed", "a"), vec!["string a"]);
+    assert_eq!(get_texts("mixed", "ab"), vec!["ab or two"]);
+    assert_eq!(get_texts("mixed", "abc"), vec!["three or four"]);
+    assert_eq!(get_texts("mixed", "abcd"), vec!["three or four"]);
+    assert_eq!(get_texts("mixed", "abcde"), vec!["other"]);
+}
+
+#[test]
+fn match_first_arm_only() {
+    assert_eq!(get_texts("first_arm", "a"), vec!["first"]);
+    assert_eq!(get_texts("first_arm", "b"), vec!["second"]);
+    assert_eq!(get_texts("first_arm", "c"), vec!["wildcard"]);
+}
+
+#[test]
+fn match_no_wildcard() {
+    assert_eq!(get_texts("no_wildcard", "a"), vec!["a", "after"]);
+    assert_eq!(get_texts("no_wildcard", "b"), vec!["after"]);
+}
+
+#[test]
+fn match_goto_in_arm() {
+    assert_eq!(get_texts("goto_in_arm", "a"), vec!["a", "next"]);
+    assert_eq!(get_texts("goto_in_arm", "b"), vec!["after"]);
+}
+
+#[test]
+fn match_hold_in_arm() {
+    let (texts, hold) = run_step("hold_in_arm", "b", None);
+    assert_eq!(texts, vec!["question"]);
+
+    // the answer resumes in the held arm whatever the new event is
+    let (texts, _) = run_step("hold_in_arm", "a", hold);
+    assert_eq!(texts, vec!["answer a", "after"]);
+}
+
+#[test]
+fn match_unreachable_arms_warning() {
+    let content = read_file("CSML/basic_test/match.csml".to_owned()).unwrap();
+    let flow = CsmlFlow::new("id", "flow", &content, Vec::default());
+    let bot = CsmlBot::new(
+        "id",
+        "bot",
+        None,
+        vec![flow],
+        None,
+        None,
+        "flow",
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+
+    let warnings: Vec<(u32, u32)> = validate_bot(&bot)
+        .warnings
+        .unwrap()
+        .iter()
+        .filter(|warning| warning.message.contains("unreachable match arm"))
+        .map(|warning| {
+            (
+                warning.position.interval.start_line,
+                warning.position.interval.start_column,
+            )
+        })
+        .collect();
+
+    assert_eq!(warnings, vec![(25, 10), (27, 9)]);
+}
@@ -239,3 +239,626 @@ fn string_step_1() {
 
     assert_eq!(v1, v2)
 }
+
+#[test]
+fn number_min_max_clamp() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"10"},"content_type":"text"},{"content":{"text":"20"},"content_type":"text"},{"content":{"text":"10.5"},"content_type":"text"},{"content":{"text":"3"},"content_type":"text"},{"content":{"text":"10"},"content_type":"text"},{"content":{"text":"9.5"},"content_type":"text"},{"content":{"text":"0"},"content_type":"text"},{"content":{"text":"4.2"},"content_type":"text"},{"content":{"text":"10"},"content_type":"text"},{"content":{"text":"int"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_min_max_clamp",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn number_clamp_error() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_clamp_error",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn number_log_exp() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"3"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"3"},"content_type":"text"},{"content":{"text":"10"},"content_type":"text"},{"content":{"text":"4"},"content_type":"text"},{"content":{"text":"0"},"content_type":"text"},{"content":{"text":"1"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"1"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_log_exp",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn number_log_out_of_domain() {
+    for (step, info) in [
+        ("number_log_zero", "(value: 0)"),
+        ("number_log_negative", "(value: -2.5)"),
+        ("number_log_base_one", "(base: 1)"),
+    ] {
+        let msg = format_message(
+            Event::new("payload", "", serde_json::json!({})),
+            Context::new(
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                step,
+                "flow",
+                None,
+            ),
+            "CSML/basic_test/stdlib/number.csml",
+        );
+
+        let value: Value = message_to_json_value(msg);
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(value["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
+
+#[test]
+fn number_radix() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"ff"},"content_type":"text"},{"content":{"text":"-ff"},"content_type":"text"},{"content":{"text":"101"},"content_type":"text"},{"content":{"text":"-101"},"content_type":"text"},{"content":{"text":"0"},"content_type":"text"},{"content":{"text":"z"},"content_type":"text"},{"content":{"text":"255"},"content_type":"text"},{"content":{"text":"255"},"content_type":"text"},{"content":{"text":"-5"},"content_type":"text"},{"content":{"text":"35"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"1234"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_radix",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn number_radix_errors() {
+    for (step, info) in [
+        ("number_radix_invalid_digit", "(character: 'g', radix: 16)"),
+        ("number_radix_invalid_base", "to_base"),
+    ] {
+        let msg = format_message(
+            Event::new("payload", "", serde_json::json!({})),
+            Context::new(
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                step,
+                "flow",
+                None,
+            ),
+            "CSML/basic_test/stdlib/number.csml",
+        );
+
+        let value: Value = message_to_json_value(msg);
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(value["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
+
+#[test]
+fn number_parity() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"left"},"content_type":"text"},{"content":{"text":"right"},"content_type":"text"},{"content":{"text":"left"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_parity",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn number_parity_float() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_parity_float",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let value: Value = message_to_json_value(msg);
+
+    assert_eq!(value["messages"][0]["content_type"], "error");
+    assert!(value["messages"][0]["content"]["error"]
+        .as_str()
+        .unwrap()
+        .contains("round() or floor()"));
+}
+
+#[test]
+fn number_to_fixed() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"12.50"},"content_type":"text"},{"content":{"text":"0.30"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"1.01"},"content_type":"text"},{"content":{"text":"100.00"},"content_type":"text"},{"content":{"text":"3"},"content_type":"text"},{"content":{"text":"-3"},"content_type":"text"},{"content":{"text":"0.00"},"content_type":"text"},{"content":{"text":"3"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"},{"content":{"text":"3.142"},"content_type":"text"},{"content":{"text":"9007199254740992.00"},"content_type":"text"},{"content":{"text":"304"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_to_fixed",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn number_to_fixed_invalid_decimals() {
+    for (step, info) in [
+        ("number_to_fixed_negative", "[to_fixed]"),
+        ("number_round_to_float", "[round_to]"),
+    ] {
+        let msg = format_message(
+            Event::new("payload", "", serde_json::json!({})),
+            Context::new(
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                step,
+                "flow",
+                None,
+            ),
+            "CSML/basic_test/stdlib/number.csml",
+        );
+
+        let value: Value = message_to_json_value(msg);
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(value["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
+
+#[test]
+fn number_checked_pow() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"4611686018427387904"},"content_type":"text"},{"content":{"text":"4052555153018976267"},"content_type":"text"},{"content":{"text":"-9223372036854775808"},"content_type":"text"},{"content":{"text":"-1"},"content_type":"text"},{"content":{"text":"0.5"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_checked_pow",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn number_int_overflow() {
+    for (step, info) in [
+        (
+            "number_overflow_add",
+            "overflowing operation: 9223372036854775807 + 1",
+        ),
+        (
+            "number_overflow_sub",
+            "overflowing operation: -9223372036854775808 - 1",
+        ),
+        (
+            "number_overflow_mul",
+            "overflowing operation: 4611686018427387904 * 2",
+        ),
+        (
+            "number_overflow_div",
+            "overflowing operation: -9223372036854775808 / -1",
+        ),
+        ("number_overflow_pow", "overflowing operation: 2.pow(63)"),
+        (
+            "number_overflow_pow_min",
+            "overflowing operation: -9223372036854775808.pow(3)",
+        ),
+    ] {
+        let msg = format_message(
+            Event::new("payload", "", serde_json::json!({})),
+            Context::new(
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                step,
+                "flow",
+                None,
+            ),
+            "CSML/basic_test/stdlib/number.csml",
+        );
+
+        let value: Value = message_to_json_value(msg);
+
+        assert_eq!(value["messages"][0]["content_type"], "error", "{}", step);
+        assert!(
+            value["messages"][0]["content"]["error"]
+                .as_str()
+                .unwrap()
+                .contains(info),
+            "{:#?}",
+            value
+        );
+    }
+}
+
+#[test]
+fn number_bitwise() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"5"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"0"},"content_type":"text"},{"content":{"text":"6"},"content_type":"text"},{"content":{"text":"-1"},"content_type":"text"},{"content":{"text":"-6"},"content_type":"text"},{"content":{"text":"4611686018427387904"},"content_type":"text"},{"content":{"text":"-9223372036854775808"},"content_type":"text"},{"content":{"text":"16"},"content_type":"text"},{"content":{"text":"-4"},"content_type":"text"},{"content":{"text":"-1"},"content_type":"text"},{"content":{"text":"-4"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_bitwise",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn number_bitwise_errors() {
+    for (step, info) in [
+        (
+            "number_shift_too_large",
+            "[shl] takes one parameter of type int between 0 and 63",
+        ),
+        ("number_shift_negative", "(bits: -1)"),
+        ("number_bitwise_float", "[bitxor]"),
+    ] {
+        let msg = format_message(
+            Event::new("payload", "", serde_json::json!({})),
+            Context::new(
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                step,
+                "flow",
+                None,
+            ),
+            "CSML/basic_test/stdlib/number.csml",
+        );
+
+        let value: Value = message_to_json_value(msg);
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(value["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
+
+#[test]
+fn number_int_to_float_math() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"},{"content":{"text":"2"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"},{"content":{"text":"9007199254740993"},"content_type":"text"},{"content":{"text":"9007199254740993"},"content_type":"text"},{"content":{"text":"9007199254740993"},"content_type":"text"},{"content":{"text":"9007199254740993"},"content_type":"text"},{"content":{"text":"9007199254740993"},"content_type":"text"},{"content":{"text":"int"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_int_to_float_math",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn number_abs_overflow() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_abs_overflow",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let value: Value = message_to_json_value(msg);
+
+    assert_eq!(value["messages"][0]["content_type"], "error");
+    assert!(value["messages"][0]["content"]["error"]
+        .as_str()
+        .unwrap()
+        .contains("overflowing operation: -9223372036854775808.abs()"));
+}
+
+#[test]
+fn number_haversine() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"344"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"180"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"},{"content":{"text":"90"},"content_type":"text"},{"content":{"text":"60"},"content_type":"text"},{"content":{"text":"45"},"content_type":"text"},{"content":{"text":"-90"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_haversine",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn number_inverse_trig_domain() {
+    for (step, info) in [
+        (
+            "number_asin_domain",
+            "[asin] is only defined for values between -1 and 1 (value: 2)",
+        ),
+        (
+            "number_acos_domain",
+            "[acos] is only defined for values between -1 and 1 (value: -1.5)",
+        ),
+    ] {
+        let msg = format_message(
+            Event::new("payload", "", serde_json::json!({})),
+            Context::new(
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                step,
+                "flow",
+                None,
+            ),
+            "CSML/basic_test/stdlib/number.csml",
+        );
+
+        let value: Value = message_to_json_value(msg);
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(value["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
+
+#[test]
+fn number_pow_exponents() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"},{"content":{"text":"4611686018427387904"},"content_type":"text"},{"content":{"text":"int"},"content_type":"text"},{"content":{"text":"1024"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"},{"content":{"text":"float"},"content_type":"text"},{"content":{"text":"8"},"content_type":"text"},{"content":{"text":"6.25"},"content_type":"text"},{"content":{"text":"0.25"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_pow_exponents",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn number_pow_overflow() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_pow_overflow",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let value: Value = message_to_json_value(msg);
+
+    assert_eq!(value["messages"][0]["content_type"], "error");
+    assert!(value["messages"][0]["content"]["error"]
+        .as_str()
+        .unwrap()
+        .contains("overflowing operation: 2.pow(64)"));
+}
+
+#[test]
+fn number_int_rounding_bounds() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"9223372036854775807"},"content_type":"text"},{"content":{"text":"9223372036854775807"},"content_type":"text"},{"content":{"text":"9223372036854775807"},"content_type":"text"},{"content":{"text":"18014398509481985"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_int_rounding_bounds",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn number_sign_negation() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"-12.5"},"content_type":"text"},{"content":{"text":"-3"},"content_type":"text"},{"content":{"text":"13"},"content_type":"text"},{"content":{"text":"13"},"content_type":"text"},{"content":{"text":"-6"},"content_type":"text"},{"content":{"text":"-6"},"content_type":"text"},{"content":{"text":"-4"},"content_type":"text"},{"content":{"text":"3"},"content_type":"text"},{"content":{"text":"-3"},"content_type":"text"},{"content":{"text":"-4"},"content_type":"text"},{"content":{"text":"-1"},"content_type":"text"},{"content":{"text":"0"},"content_type":"text"},{"content":{"text":"1"},"content_type":"text"},{"content":{"text":"-1"},"content_type":"text"},{"content":{"text":"0"},"content_type":"text"},{"content":{"text":"int"},"content_type":"text"},{"content":{"text":"negative"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_sign_negation",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn number_negation_overflow() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "number_negation_overflow",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/number.csml",
+    );
+
+    let value: Value = message_to_json_value(msg);
+
+    assert_eq!(value["messages"][0]["content_type"], "error");
+    assert!(value["messages"][0]["content"]["error"]
+        .as_str()
+        .unwrap()
+        .contains("overflowing operation: 0 - -9223372036854775808"));
+}
@@ -180,3 +180,94 @@ fn ok_step_7_assign() {
 
     assert_eq!(v1, v2)
 }
+
+fn run_step(step: &str) -> Value {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/object.csml",
+    );
+
+    message_to_json_value(msg)
+}
+
+#[test]
+fn ok_object_array_constructors() {
+    let data = r#"{"messages":[
+        {"content":{"name": "Jane", "address": {"city": "Paris", "zip": "75001"}, "tags": ["admin", "beta"]},"content_type":"object"},
+        {"content":[1, "two", {"three": 3}, [4]],"content_type":"array"}
+    ],"memories":[]}"#;
+
+    let v2: Value = serde_json::from_str(data).unwrap();
+    assert_eq!(run_step("constructors"), v2)
+}
+
+#[test]
+fn ok_empty_constructors() {
+    let data = r#"{"messages":[
+        {"content":{},"content_type":"object"},
+        {"content":[],"content_type":"array"},
+        {"content":{"text":"0"},"content_type":"text"}
+    ],"memories":[]}"#;
+
+    let v2: Value = serde_json::from_str(data).unwrap();
+    assert_eq!(run_step("empty_constructors"), v2)
+}
+
+#[test]
+fn ok_object_duplicate_keys() {
+    let data = r#"{"messages":[
+        {"content":{"name": "last", "age": 42},"content_type":"object"}
+    ],"memories":[]}"#;
+
+    let v2: Value = serde_json::from_str(data).unwrap();
+    assert_eq!(run_step("duplicate_keys"), v2)
+}
+
+#[test]
+fn ok_remember_constructors() {
+    let data = r#"{"messages":[
+        {"content":{"text":"3"},"content_type":"text"}
+    ],"memories":[
+        {"key":"order","value":{"_content":{"items":[{"qty":2},{"qty":3}],"sku":"A1"},"_content_type":"object"}}
+    ]}"#;
+
+    let v2: Value = serde_json::from_str(data).unwrap();
+    assert_eq!(run_step("remember_constructors"), v2)
+}
+
+#[test]
+fn constructors_invalid_arguments() {
+    let steps = [
+        (
+            "object_positional_error",
+            "Object builtin expects named arguments",
+        ),
+        (
+            "array_named_error",
+            "Array builtin expects positional arguments",
+        ),
+    ];
+
+    for (step, info) in steps.iter() {
+        let value = run_step(step);
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(
+            value["messages"][0]["content"]["error"]
+                .as_str()
+                .unwrap()
+                .contains(info),
+            "{}",
+            value
+        );
+    }
+}
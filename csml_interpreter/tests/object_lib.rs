@@ -3,6 +3,8 @@ mod support;
 use ::std::collections::HashMap;
 use csml_interpreter::data::context::Context;
 use csml_interpreter::data::event::Event;
+use csml_interpreter::data::Interval;
+use csml_interpreter::interpreter::memory_to_literal;
 
 use crate::support::tools::format_message;
 use crate::support::tools::message_to_json_value;
@@ -96,8 +98,7 @@ fn object_step_3() {
     let data = r#"{
         "memories":[
             {"key":"obj", "value": {"_content":{}, "_content_type":"object"}},
-            {"key":"obj", "value": {"_content":{"42": "Hello"}, "_content_type":"object"}},
-            {"key":"obj", "value": {"_content":{}, "_content_type":"object"}}
+            {"key":"obj", "value": {"_content":{"42": "Hello"}, "_content_type":"object"}}
         ],
         "messages":[
             {"content":{"text": "true"}, "content_type":"text"},
@@ -294,3 +295,449 @@ fn object_step_8() {
 
     assert_eq!(v1, v2)
 }
+
+#[test]
+fn object_entries() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content":["a", "b", "c"], "content_type":"array"},
+            {"content":[{"nested": [1, 2]}, 2, null], "content_type":"array"},
+            {"content":[
+                {"key": "a", "value": {"nested": [1, 2]}},
+                {"key": "b", "value": 2},
+                {"key": "c", "value": null}
+            ], "content_type":"array"},
+            {"content":[1, 2], "content_type":"array"},
+            {"content":{"text": "true"}, "content_type":"text"},
+            {"content":[], "content_type":"array"}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "object_entries",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/object.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn object_has_key() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "object_has_key",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/object.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+fn run_with_memory(name: &str, memory: &Value, step: &str) -> Value {
+    let mut current = HashMap::new();
+    current.insert(
+        name.to_owned(),
+        memory_to_literal(memory, Interval::default(), "flow").unwrap(),
+    );
+
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(current, HashMap::new(), None, None, step, "flow", None),
+        "CSML/basic_test/stdlib/object.csml",
+    );
+
+    message_to_json_value(msg)
+}
+
+#[test]
+fn object_remove_persisted() {
+    let consent = serde_json::json!({
+        "email": "jane@doe.com",
+        "marketing": {"sms": true, "mail": false}
+    });
+
+    let value = run_with_memory("consent", &consent, "object_remove");
+    let messages: Vec<Value> = value["messages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|message| message["content"].clone())
+        .collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            serde_json::json!({"text": "jane@doe.com"}),
+            serde_json::json!({"text": "true"}),
+            serde_json::json!({"text": null}),
+            serde_json::json!({"text": null}),
+        ]
+    );
+
+    let consent = value["memories"]
+        .as_array()
+        .and_then(|memories| memories.last())
+        .map(|memory| memory["value"].clone())
+        .unwrap();
+
+    let value = run_with_memory("consent", &consent, "object_remove_next_turn");
+    let messages: Vec<Value> = value["messages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|message| message["content"].clone())
+        .collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            serde_json::json!({"text": "false"}),
+            serde_json::json!({"text": "false"}),
+            serde_json::json!({"marketing": {"mail": false}}),
+        ]
+    );
+}
+
+#[test]
+fn object_merge() {
+    let data = r#"{"memories":[],"messages":[{"content":{"lang":"en","notify":{"sms":false},"tags":["c"],"theme":"light"},"content_type":"object"},{"content":{"lang":"en","notify":{"mail":true,"sms":false},"tags":["c"],"theme":"light"},"content_type":"object"},{"content":{"theme":{"dark":true}},"content_type":"object"},{"content":{"text":"2"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "object_merge",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/object.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn object_merge_error() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "object_merge_error",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/object.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn object_length() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"3"},"content_type":"text"},{"content":{"text":"3"},"content_type":"text"},{"content":{"text":"false"},"content_type":"text"},{"content":{"text":"complete"},"content_type":"text"},{"content":{"text":"truthy"},"content_type":"text"},{"content":{"text":"falsy"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "object_length",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/object.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn object_get_or() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"Paris"},"content_type":"text"},{"content":{"text":null},"content_type":"text"},{"content":{"text":"Paris"},"content_type":"text"},{"content":{"text":"unknown"},"content_type":"text"},{"content":{"text":"unknown"},"content_type":"text"},{"content":{"text":"unknown"},"content_type":"text"},{"content":{"text":null},"content_type":"text"},{"content":{"text":"19"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "object_get_or",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/object.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn object_to_array() {
+    let data = r#"{"memories":[],"messages":[{"content":[["a",[1]],["b",2],["c",null]],"content_type":"array"},{"content":{"text":"true"},"content_type":"text"},{"content":{"text":"true"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "object_to_array",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/object.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn object_clone_does_not_leak() {
+    let value = run_with_memory("profile", &Value::Null, "object_clone");
+    let profile = serde_json::json!({
+        "_content": {"name": "Jane", "email": "jane@doe.com", "tags": ["vip"]},
+        "_content_type": "object"
+    });
+
+    assert_eq!(
+        value["memories"],
+        serde_json::json!([{"key": "profile", "value": profile}])
+    );
+    assert_eq!(
+        value["messages"][0]["content"],
+        serde_json::json!({"name": "Jane", "tags": ["vip"], "age": 42})
+    );
+    assert_eq!(value["messages"][0]["content_type"], "object");
+    assert_eq!(
+        value["messages"][1]["content"],
+        serde_json::json!({"email": "jane@doe.com", "tags": ["vip"]})
+    );
+    assert_eq!(
+        value["messages"][2]["content"],
+        serde_json::json!(["vip", "new"])
+    );
+
+    let value = run_with_memory("profile", &profile, "object_clone_next_turn");
+
+    assert_eq!(
+        value["messages"][0]["content"],
+        serde_json::json!({"name": "Jane", "email": "jane@doe.com", "tags": ["vip"]})
+    );
+}
+
+#[test]
+fn object_flatten_keys() {
+    let data = r#"{"memories":[],"messages":[{"content":{"address.city":"Paris","address.geo.lat":48.8,"empty":{},"items.0":"a","items.1":"b","name":"Jane"},"content_type":"object"},{"content":{"address.city":"Paris","address.geo.lat":48.8,"empty":{},"items":["a","b"],"name":"Jane"},"content_type":"object"},{"content":{"text":"true"},"content_type":"text"},{"content":{"address":{"city":"Paris","zip":"75001"},"name":"Jane"},"content_type":"object"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "object_flatten_keys",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/object.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn object_flatten_keys_collision() {
+    for (step, path) in [
+        ("object_flatten_keys_collision", "(path: a.b)"),
+        ("object_unflatten_collision", "(path: user)"),
+    ] {
+        let msg = format_message(
+            Event::new("payload", "", serde_json::json!({})),
+            Context::new(
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                step,
+                "flow",
+                None,
+            ),
+            "CSML/basic_test/stdlib/object.csml",
+        );
+
+        let value: Value = message_to_json_value(msg);
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(value["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(path));
+    }
+}
+
+#[test]
+fn object_query() {
+    let data = r#"{"memories":[],"messages":[{"content":{"text":"Paris"},"content_type":"text"},{"content":{"text":"Mug"},"content_type":"text"},{"content":[19.9,9.5,15],"content_type":"array"},{"content":["SUMMER"],"content_type":"array"},{"content":[4.99,"express"],"content_type":"array"},{"content":{"text":null},"content_type":"text"},{"content":{"text":null},"content_type":"text"},{"content":{"customer":{"address":{"city":"Paris","zip":"75001"},"name":"Jane"},"id":"ord_42","items":[{"name":"T-shirt","price":19.9,"quantity":2,"sku":"tee-01"},{"name":"Mug","price":9.5,"quantity":1,"sku":"mug-07"},{"discount":{"code":"SUMMER"},"name":"Cap","price":15,"quantity":1,"sku":"cap-03"}],"shipping":{"cost":4.99,"method":"express"}},"content_type":"object"},{"content":{"text":"44.4"},"content_type":"text"}]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "object_query",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/object.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn object_query_error() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "object_query_error",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/object.csml",
+    );
+
+    let value: Value = message_to_json_value(msg);
+
+    assert_eq!(value["messages"][0]["content_type"], "error");
+    assert!(value["messages"][0]["content"]["error"]
+        .as_str()
+        .unwrap()
+        .contains("(position: 8 in '$.items[?(@.price > 1)]')"));
+}
+
+#[test]
+fn object_set_path_persisted() {
+    let user = serde_json::json!({
+        "_content": {"name": "Jane", "address": {"city": "Paris", "zip": "75001"}},
+        "_content_type": "object"
+    });
+
+    let value = run_with_memory("user", &user, "object_set_path");
+    let user = value["memories"]
+        .as_array()
+        .and_then(|memories| memories.last())
+        .map(|memory| memory["value"].clone())
+        .unwrap();
+
+    assert_eq!(
+        user,
+        serde_json::json!({
+            "_content": {
+                "name": "Jane",
+                "address": {"city": "Paris", "zip": "75002"},
+                "preferences": {"notifications": {"sms": false}}
+            },
+            "_content_type": "object"
+        })
+    );
+
+    let value = run_with_memory("user", &user, "object_set_path_next_turn");
+
+    assert_eq!(
+        value["messages"],
+        serde_json::json!([
+            {"content": {"city": "Paris", "zip": "75002"}, "content_type": "object"},
+            {"content": {"text": "false"}, "content_type": "text"}
+        ])
+    );
+}
+
+#[test]
+fn object_set_path_error() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "object_set_path_error",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/object.csml",
+    );
+
+    let value: Value = message_to_json_value(msg);
+
+    assert_eq!(value["messages"][0]["content_type"], "error");
+    assert!(value["messages"][0]["content"]["error"]
+        .as_str()
+        .unwrap()
+        .contains("(path: address)"));
+}
@@ -0,0 +1,181 @@
+mod support;
+
+use csml_interpreter::data::context::Context;
+use csml_interpreter::data::event::Event;
+use csml_interpreter::data::{Hold, MessageData};
+use std::collections::HashMap;
+
+use crate::support::tools::format_message;
+use crate::support::tools::message_to_json_value;
+
+use serde_json::Value;
+
+const FLOW: &str = "CSML/basic_test/built-in/question_retries.csml";
+
+fn text_event(text: &str) -> Event {
+    Event::new("text", text, serde_json::json!({ "text": text }))
+}
+
+/// run one turn of the conversation, the hold goes through JSON like it
+/// does when the engine saves it between two events
+fn run_turn(step: &str, hold: Option<Hold>, event: Event) -> MessageData {
+    let hold = hold.map(|mut hold| {
+        let question = serde_json::to_value(&hold.question).unwrap();
+        hold.question = serde_json::from_value(question).unwrap();
+        hold
+    });
+
+    format_message(
+        event,
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            hold,
+            step,
+            "flow",
+            None,
+        ),
+        FLOW,
+    )
+}
+
+fn content_types(msg_data: &MessageData) -> Vec<String> {
+    msg_data
+        .messages
+        .iter()
+        .map(|message| message.content_type.to_owned())
+        .collect()
+}
+
+#[test]
+fn question_retries_then_right_answer() {
+    let first = run_turn("start", None, Event::default());
+    assert_eq!(content_types(&first), vec!["question"]);
+
+    let question = first.messages[0].content.clone();
+    assert_eq!(question["title"], "Pick a color");
+    assert_eq!(question["retries"], 2);
+    assert_eq!(
+        question["accepts"],
+        serde_json::json!(["Red", "red", "Blue", "blue"])
+    );
+
+    let hold = first.hold.unwrap();
+    assert_eq!(hold.question.as_ref().unwrap().attempts, 0);
+
+    // a wrong answer asks the same question again
+    let second = run_turn("start", Some(hold), text_event("green"));
+    assert_eq!(content_types(&second), vec!["question"]);
+    assert_eq!(second.messages[0].content, question);
+
+    let hold = second.hold.unwrap();
+    assert_eq!(hold.question.as_ref().unwrap().attempts, 1);
+
+    // answers are matched without case
+    let third = run_turn("start", Some(hold), text_event(" BLUE "));
+    let v1: Value = message_to_json_value(third);
+    let v2: Value = serde_json::json!({
+        "memories": [],
+        "messages": [{"content": {"text": "You picked  BLUE "}, "content_type": "text"}]
+    });
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn question_retries_then_fallback() {
+    let mut msg_data = run_turn("start", None, Event::default());
+
+    for _ in 0..2 {
+        msg_data = run_turn("start", msg_data.hold, text_event("green"));
+        assert_eq!(content_types(&msg_data), vec!["question"]);
+    }
+
+    let last = run_turn("start", msg_data.hold, text_event("green"));
+    assert!(last.hold.is_none());
+
+    let v1: Value = message_to_json_value(last);
+    let v2: Value = serde_json::json!({
+        "memories": [],
+        "messages": [{"content": {"text": "Let's try something else"}, "content_type": "text"}]
+    });
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn question_fallback_without_retries() {
+    let first = run_turn("fallback_only", None, Event::default());
+    assert_eq!(
+        first.messages[0].content["accepts"],
+        serde_json::json!(["Red", "red", "Blue", "blue"])
+    );
+
+    // without retries the first wrong answer goes to the fallback step
+    let second = run_turn("fallback_only", first.hold, text_event("green"));
+    assert_eq!(
+        second.messages[0].content["text"],
+        "Let's try something else"
+    );
+}
+
+#[test]
+fn question_explicit_accepts() {
+    let first = run_turn("accepts", None, Event::default());
+    assert_eq!(
+        first.messages[0].content["accepts"],
+        serde_json::json!(["yes", "y", "no", "n"])
+    );
+
+    let second = run_turn("accepts", first.hold, text_event("maybe"));
+    assert_eq!(content_types(&second), vec!["question"]);
+
+    let third = run_turn("accepts", second.hold, text_event("y"));
+    assert_eq!(third.messages[0].content["text"], "answer: y");
+}
+
+#[test]
+fn question_without_fallback_resumes() {
+    let mut msg_data = run_turn("accepts", None, Event::default());
+    msg_data = run_turn("accepts", msg_data.hold, text_event("maybe"));
+
+    let last = run_turn("accepts", msg_data.hold, text_event("maybe"));
+    assert_eq!(last.messages[0].content["text"], "answer: maybe");
+}
+
+#[test]
+fn question_invalid_arguments() {
+    let steps = [
+        (
+            "retries_error",
+            "argument 'retries' in Question component must be a non-negative int",
+        ),
+        (
+            "accepts_error",
+            "argument 'accepts' in Question component must be of type Array<String>",
+        ),
+        (
+            "fallback_error",
+            "argument 'fallback' in Question component must be the name of a step",
+        ),
+        (
+            "no_answers_error",
+            "arguments 'retries' and 'fallback' in Question component need 'buttons' or 'accepts'",
+        ),
+    ];
+
+    for (step, info) in steps.iter() {
+        let value = message_to_json_value(run_turn(step, None, Event::default()));
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(
+            value["messages"][0]["content"]["error"]
+                .as_str()
+                .unwrap()
+                .contains(info),
+            "{}",
+            value
+        );
+    }
+}
@@ -0,0 +1,121 @@
+mod support;
+
+use csml_interpreter::data::context::Context;
+use csml_interpreter::data::event::Event;
+use std::collections::HashMap;
+
+use crate::support::tools::format_message;
+use crate::support::tools::message_to_json_value;
+
+use serde_json::Value;
+
+fn run_step(step: &str) -> Value {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/built-in/quick_reply.csml",
+    );
+
+    message_to_json_value(msg)
+}
+
+#[test]
+fn ok_quick_reply() {
+    let data = r#"{
+        "messages":[
+            {"content":{"text":"Do you want to continue?"},"content_type":"text"},
+            {
+                "content":{"quick_replies":[{"payload":"Yes","title":"Yes"}]},
+                "content_type":"quick_reply"
+            }
+        ],
+        "memories":[]
+    }"#;
+
+    let v1: Value = run_step("start");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn ok_quick_replies() {
+    let data = r#"{
+        "messages":[
+            {"content":{"text":"Do you want to continue?"},"content_type":"text"},
+            {
+                "content":{
+                    "quick_replies":[
+                        {"payload":"YES","title":"Yes"},
+                        {"payload":"NO","title":"No"},
+                        {"image_url":"https://example.com/maybe.png","payload":"MAYBE","title":"Maybe"}
+                    ]
+                },
+                "content_type":"quick_reply"
+            }
+        ],
+        "memories":[]
+    }"#;
+
+    let v1: Value = run_step("quick_replies");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn ok_quick_replies_nested() {
+    let data = r#"{
+        "messages":[
+            {
+                "content":{
+                    "quick_replies":[
+                        {"payload":"Yes","title":"Yes"},
+                        {"payload":"No","title":"No"},
+                        {"payload":"Later","title":"Later"}
+                    ]
+                },
+                "content_type":"quick_reply"
+            }
+        ],
+        "memories":[]
+    }"#;
+
+    let v1: Value = run_step("quick_replies_nested");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn quick_reply_invalid_arguments() {
+    for (step, info) in [
+        ("quick_reply_missing_title", "title is a required parameter"),
+        (
+            "quick_reply_title_error",
+            "QuickReply component expects title, payload and image_url arguments of type String",
+        ),
+        (
+            "quick_replies_error",
+            "in QuickReplies component must be of type Array<QuickReply>",
+        ),
+    ]
+    .iter()
+    {
+        let v: Value = run_step(step);
+
+        assert_eq!(v["messages"][0]["content_type"], "error");
+        assert!(v["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
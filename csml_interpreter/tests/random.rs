@@ -1,11 +1,17 @@
 mod support;
 
 use csml_interpreter::data::context::Context;
+use csml_interpreter::data::csml_bot::CsmlBot;
+use csml_interpreter::data::csml_flow::CsmlFlow;
 use csml_interpreter::data::event::Event;
+use csml_interpreter::data::MSG;
+use csml_interpreter::{interpret, load_components};
 use std::collections::HashMap;
+use std::sync::mpsc;
 
 use crate::support::tools::format_message;
 use crate::support::tools::message_to_json_value;
+use crate::support::tools::read_file;
 
 use serde_json::Value;
 
@@ -37,3 +43,316 @@ fn ok_random() {
         panic!("Random fail {}", float);
     }
 }
+
+fn run_seeded(seed: u64, step: &str) -> Value {
+    run_with_bot_seed(Some(seed), step)
+}
+
+#[test]
+fn rand_between() {
+    let value = run_seeded(42, "rand_between");
+
+    for dice in value["messages"][0]["content"].as_array().unwrap() {
+        let dice = dice.as_i64().unwrap();
+
+        if !(1..=6).contains(&dice) {
+            panic!("rand_between fail {}", dice);
+        }
+    }
+
+    let float = value["messages"][1]["content"]["text"]
+        .as_str()
+        .unwrap()
+        .parse::<f64>()
+        .unwrap();
+
+    if !(-1.0..1.5).contains(&float) {
+        panic!("rand_between fail {}", float);
+    }
+
+    assert_eq!(value["messages"][3]["content"]["text"], "3");
+
+    // the same seed draws the same values
+    assert_eq!(value, run_seeded(42, "rand_between"));
+    assert_ne!(value, run_seeded(7, "rand_between"));
+}
+
+#[test]
+fn rand_between_min_greater_than_max() {
+    let value = run_seeded(42, "rand_between_error");
+
+    assert_eq!(value["messages"][0]["content_type"], "error");
+}
+
+#[test]
+fn rand_between_infinite_bound() {
+    let value = run_seeded(42, "rand_between_infinite_error");
+
+    assert_eq!(value["messages"][0]["content_type"], "error");
+    assert!(value["messages"][0]["content"]["error"]
+        .as_str()
+        .unwrap()
+        .contains("[rand_between] min and max must be finite numbers (min: 0, max: inf)"));
+}
+
+fn run_with_bot_seed(seed: Option<u64>, step: &str) -> Value {
+    let content = read_file("CSML/basic_test/built-in/random.csml".to_owned()).unwrap();
+    let flow = CsmlFlow::new("id", "flow", &content, Vec::default());
+
+    let mut bot = CsmlBot::new(
+        "id",
+        "bot",
+        None,
+        vec![flow],
+        Some(load_components().unwrap()),
+        None,
+        "flow",
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    bot.random_seed = seed;
+
+    let msg = interpret(
+        bot,
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        Event::new("payload", "", serde_json::json!({})),
+        None,
+    );
+
+    message_to_json_value(msg)
+}
+
+#[test]
+fn random_int_bot_seed() {
+    let data = r#"{
+        "messages":[
+            {"content":[4, 4, 3, 1, 3, 5, 6, 1], "content_type":"array"},
+            {"content":{"text": "0.932145132176102"}, "content_type":"text"},
+            {"content":{"text": "-3"}, "content_type":"text"}
+        ],
+        "memories":[]
+    }"#;
+
+    let v1: Value = run_with_bot_seed(Some(42), "random_int");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2);
+    // the seed is applied on each run, not only on the first one
+    assert_eq!(v1, run_with_bot_seed(Some(42), "random_int"));
+    assert_ne!(v1, run_with_bot_seed(Some(7), "random_int"));
+}
+
+#[test]
+fn random_int_seed_does_not_leak() {
+    let seeded = run_with_bot_seed(Some(42), "random_int");
+    // same thread, no seed: the generator must not continue the seeded sequence
+    let first = run_with_bot_seed(None, "random_int");
+    let again = run_with_bot_seed(Some(42), "random_int");
+    let second = run_with_bot_seed(None, "random_int");
+
+    assert_eq!(seeded, again);
+    assert_ne!(first, second);
+}
+
+#[test]
+fn random_int_invalid_arguments() {
+    for (step, info) in [
+        (
+            "random_int_range_error",
+            "RandomInt builtin expects min to be lower than or equal to max (min: 6, max: 1)",
+        ),
+        (
+            "random_int_type_error",
+            "RandomInt builtin expects two arguments of type int. Example: RandomInt(1, 6) at line 33, column 22",
+        ),
+        (
+            "random_int_missing_arg",
+            "RandomInt builtin expects two arguments of type int",
+        ),
+    ]
+    .iter()
+    {
+        let v: Value = run_with_bot_seed(None, step);
+
+        assert_eq!(v["messages"][0]["content_type"], "error");
+        assert!(v["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
+
+#[test]
+fn shuffle_seeded() {
+    let data = r#"{
+        "messages":[
+            {"content":["Bordeaux", "Lyon", "Paris", "Marseille"], "content_type":"array"},
+            {"content":["Paris", "Lyon", "Marseille", "Bordeaux"], "content_type":"array"}
+        ],
+        "memories":[]
+    }"#;
+
+    let v1: Value = run_with_bot_seed(Some(42), "shuffle");
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn shuffle_question_buttons() {
+    let value = run_with_bot_seed(Some(42), "shuffle_question");
+    let titles: Vec<&str> = value["messages"][0]["content"]["buttons"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|button| {
+            assert_eq!(button["content_type"], "button");
+            button["content"]["title"].as_str().unwrap()
+        })
+        .collect();
+
+    assert_eq!(titles, vec!["Bordeaux", "Lyon", "Paris", "Marseille"]);
+}
+
+#[test]
+fn shuffle_invalid_arguments() {
+    for step in ["shuffle_string_error", "shuffle_int_error"].iter() {
+        let v: Value = run_with_bot_seed(None, step);
+
+        assert_eq!(v["messages"][0]["content_type"], "error");
+        assert!(v["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains("Shuffle builtin expects one value of type Array"));
+    }
+}
+
+#[test]
+fn one_of_weights_seeded() {
+    let value = run_with_bot_seed(Some(42), "one_of_weights");
+    let picks = value["messages"][0]["content"].as_array().unwrap();
+
+    let count = |pick: &str| picks.iter().filter(|value| *value == pick).count();
+
+    // weights [1, 1, 3] expect 200 'a', 200 'b' and 600 'c'
+    assert_eq!(picks.len(), 1000);
+    assert!((150..250).contains(&count("a")), "{}", count("a"));
+    assert!((150..250).contains(&count("b")), "{}", count("b"));
+    assert!((550..650).contains(&count("c")), "{}", count("c"));
+}
+
+#[test]
+fn one_of_no_repeat_in_step() {
+    let value = run_with_bot_seed(Some(42), "one_of_no_repeat");
+    let picks = value["messages"][0]["content"].as_array().unwrap();
+
+    assert_eq!(picks.len(), 12);
+    for pair in picks.windows(2) {
+        assert_ne!(pair[0], pair[1]);
+    }
+}
+
+/// run the step once per seed, the last picks go back in the context like the
+/// engine saves them between two events
+fn run_turns(seeds: std::ops::Range<u64>, step: &str) -> Vec<Value> {
+    let content = read_file("CSML/basic_test/built-in/random.csml".to_owned()).unwrap();
+    let mut one_of = HashMap::new();
+    let mut outputs = vec![];
+
+    for seed in seeds {
+        let flow = CsmlFlow::new("id", "flow", &content, Vec::default());
+        let mut bot = CsmlBot::new(
+            "id",
+            "bot",
+            None,
+            vec![flow],
+            Some(load_components().unwrap()),
+            None,
+            "flow",
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        bot.random_seed = Some(seed);
+
+        let mut context = Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        );
+        context.one_of = one_of.clone();
+
+        let (sender, receiver) = mpsc::channel::<MSG>();
+        let msg = interpret(bot, context, Event::default(), Some(sender));
+
+        for received in receiver.try_iter() {
+            if let MSG::OneOf { key, index } = received {
+                one_of.insert(key, index);
+            }
+        }
+
+        outputs.push(message_to_json_value(msg)["messages"][0]["content"]["text"].clone());
+    }
+
+    outputs
+}
+
+#[test]
+fn one_of_no_repeat_across_turns() {
+    let outputs = run_turns(0..20, "one_of_no_repeat_turn");
+
+    // 'c' weights 50 times more but never comes twice in a row
+    assert!(outputs.iter().filter(|output| *output == "c").count() >= 9);
+    for pair in outputs.windows(2) {
+        assert_ne!(pair[0], pair[1], "{:?}", outputs);
+    }
+}
+
+#[test]
+fn one_of_invalid_arguments() {
+    for (step, info) in [
+        (
+            "one_of_weights_length_error",
+            "argument 'weights' in OneOf builtin must be an Array of positive numbers with one weight per value",
+        ),
+        (
+            "one_of_weights_value_error",
+            "argument 'weights' in OneOf builtin must be an Array of positive numbers with one weight per value. Example: OneOf( [\"a\", \"b\"], weights = [1, 3] ) at line 80, column 41",
+        ),
+        (
+            "one_of_no_repeat_error",
+            "argument 'no_repeat' in OneOf builtin must be of type Boolean",
+        ),
+    ]
+    .iter()
+    {
+        let v: Value = run_with_bot_seed(None, step);
+
+        assert_eq!(v["messages"][0]["content_type"], "error");
+        assert!(
+            v["messages"][0]["content"]["error"]
+                .as_str()
+                .unwrap()
+                .contains(info),
+            "{}",
+            v
+        );
+    }
+}
@@ -207,3 +207,165 @@ fn ok_regex_8() {
 
     assert_eq!(v1, v2)
 }
+
+#[test]
+fn ok_regex_9() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "true"}},
+            {"content_type":"text", "content":{"text": "false"}},
+            {"content_type":"array", "content":["ORD-1234", "ORD", "1234"]},
+            {"content_type":"array", "content":["shipped"]},
+            {"content_type":"array", "content":["ORD-", "ORD", null]},
+            {"content_type":"text", "content":{"text": null}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "regex_9",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/regex.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn regex_10_invalid_pattern() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "regex_10",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/regex.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn regex_11_invalid_pattern() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "regex_11",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/regex.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn ok_regex_12() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"array", "content":["a", "b", "c", "d"]},
+            {"content_type":"array", "content":["a", " b", "", "c  d"]},
+            {"content_type":"array", "content":["a", "b", "c  d"]},
+            {"content_type":"array", "content":["a, b;;c  d"]},
+            {"content_type":"array", "content":["a, b;;c  d"]}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "regex_12",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/regex.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn regex_13_invalid_pattern() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "regex_13",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/regex.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
@@ -13,8 +13,7 @@ use serde_json::Value;
 fn string_step_0() {
     let data = r#"{
         "memories":[
-            {"key":"s", "value":"Hello "},
-            {"key":"s", "value":"Hello World"}
+            {"key":"s", "value":"Hello "}
         ],
         "messages":[
             {"content":{"text": "Hello World"}, "content_type":"text"},
@@ -558,3 +557,1585 @@ fn string_step_18_html_escape() {
 
     assert_eq!(v1, v2)
 }
+
+#[test]
+fn string_step_19_split() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "b"}},
+            {"content_type":"text", "content":{"text": "3"}},
+            {"content_type":"text", "content":{"text": "[\"😃\",\"é\",\"ы\"]"}},
+            {"content_type":"text", "content":{"text": "[\"a\",\"b\",\"\"]"}},
+            {"content_type":"text", "content":{"text": "[\"a\",\"😃\",\"b\"]"}},
+            {"content_type":"text", "content":{"text": "[\"a😃b\"]"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_19_split",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_20_replace() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "ba"}},
+            {"content_type":"text", "content":{"text": "bb"}},
+            {"content_type":"text", "content":{"text": "_b_"}},
+            {"content_type":"text", "content":{"text": "Hello CSML"}},
+            {"content_type":"text", "content":{"text": "Hello World"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_20_replace",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_21_replace_empty() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_21_replace_empty",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_22_replace_all_empty() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_22_replace_all_empty",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_23_contains() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "false"}},
+            {"content_type":"text", "content":{"text": "true"}},
+            {"content_type":"text", "content":{"text": "true"}},
+            {"content_type":"text", "content":{"text": "false"}},
+            {"content_type":"text", "content":{"text": "true"}},
+            {"content_type":"text", "content":{"text": "false"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_23_contains",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_24_contains_error() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_24_contains_error",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_25_starts_ends_with() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "true"}},
+            {"content_type":"text", "content":{"text": "false"}},
+            {"content_type":"text", "content":{"text": "true"}},
+            {"content_type":"text", "content":{"text": "true"}},
+            {"content_type":"text", "content":{"text": "false"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_25_starts_ends_with",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_26_starts_with_error() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_26_starts_with_error",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_27_trim() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "[hello  world]"}},
+            {"content_type":"text", "content":{"text": "[hello  world\n ]"}},
+            {"content_type":"text", "content":{"text": "[ 　 \t hello  world]"}},
+            {"content_type":"text", "content":{"text": "[padded  ]"}},
+            {"content_type":"text", "content":{"text": "[  padded]"}},
+            {"content_type":"text", "content":{"text": "title"}},
+            {"content_type":"text", "content":{"text": "--==title==--"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_27_trim",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_28_case() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "STRASSE ÉLODIE ΩΜΈΓΑ ПРИВЕТ"}},
+            {"content_type":"text", "content":{"text": "straße élodie ωμέγα привет"}},
+            {"content_type":"text", "content":{"text": "Straße Élodie Ωμέγα Привет"}},
+            {"content_type":"text", "content":{"text": "déjà vu"}},
+            {"content_type":"text", "content":{"text": "ÇA VA"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_28_case",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_29_capitalize() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "Toto"}},
+            {"content_type":"text", "content":{"text": "Élodie"}},
+            {"content_type":"text", "content":{"text": "Élodie"}},
+            {"content_type":"text", "content":{"text": "😃hello"}},
+            {"content_type":"text", "content":{"text": ""}},
+            {"content_type":"text", "content":{"text": "Jean-pierre De La  Fontaine"}},
+            {"content_type":"text", "content":{"text": "ÉLODIE McDONALD"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_29_capitalize",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_30_slice_negative() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "7890"}},
+            {"content_type":"text", "content":{"text": "lo"}},
+            {"content_type":"text", "content":{"text": "😃H"}},
+            {"content_type":"text", "content":{"text": "He"}},
+            {"content_type":"text", "content":{"text": "llo"}},
+            {"content_type":"text", "content":{"text": "[]"}},
+            {"content_type":"text", "content":{"text": "[]"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_30_slice_negative",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_31_index_of() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "4"}},
+            {"content_type":"text", "content":{"text": "10"}},
+            {"content_type":"text", "content":{"text": "-1"}},
+            {"content_type":"text", "content":{"text": "10"}},
+            {"content_type":"text", "content":{"text": "4"}},
+            {"content_type":"text", "content":{"text": "0"}},
+            {"content_type":"text", "content":{"text": "😃key"}},
+            {"content_type":"text", "content":{"text": "-1"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_31_index_of",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_32_pad() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "000042"}},
+            {"content_type":"text", "content":{"text": "[42  ]"}},
+            {"content_type":"text", "content":{"text": "ababa42"}},
+            {"content_type":"text", "content":{"text": "42"}},
+            {"content_type":"text", "content":{"text": "--😃"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_32_pad",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_33_pad_negative() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_33_pad_negative",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_34_pad_empty() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_34_pad_empty",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_35_repeat() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "--------------------"}},
+            {"content_type":"text", "content":{"text": "ab😃ab😃"}},
+            {"content_type":"text", "content":{"text": "[]"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_35_repeat",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_36_repeat_negative() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_36_repeat_negative",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_37_repeat_too_large() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_37_repeat_too_large",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_38_reverse() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "lëon"}},
+            {"content_type":"text", "content":{"text": "!🇩🇪🇫🇷"}},
+            {"content_type":"text", "content":{"text": "cba 👨‍👩‍👧‍👦"}},
+            {"content_type":"text", "content":{"text": "true"}},
+            {"content_type":"text", "content":{"text": "LËON"}},
+            {"content_type":"text", "content":{"text": "true"}},
+            {"content_type":"text", "content":{"text": "true"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_38_reverse",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_39_is_email() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "toto@clevy.io: true"}},
+            {"content_type":"text", "content":{"text": "first.last+tag@mail.example.co.uk: true"}},
+            {"content_type":"text", "content":{"text": "a@b.c: true"}},
+            {"content_type":"text", "content":{"text": ": false"}},
+            {"content_type":"text", "content":{"text": "toto: false"}},
+            {"content_type":"text", "content":{"text": "toto@: false"}},
+            {"content_type":"text", "content":{"text": "@clevy.io: false"}},
+            {"content_type":"text", "content":{"text": "toto@clevy: false"}},
+            {"content_type":"text", "content":{"text": "to to@clevy.io: false"}},
+            {"content_type":"text", "content":{"text": "a@@b.co: false"}},
+            {"content_type":"text", "content":{"text": "a@b..co: false"}},
+            {"content_type":"text", "content":{"text": "a@.co: false"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_39_is_email",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_40_is_url() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "https://www.csml.dev: true"}},
+            {"content_type":"text", "content":{"text": "http://localhost:8080/path?q=1#frag: true"}},
+            {"content_type":"text", "content":{"text": "ftp://csml.dev: false"}},
+            {"content_type":"text", "content":{"text": "www.csml.dev: false"}},
+            {"content_type":"text", "content":{"text": "https://: false"}},
+            {"content_type":"text", "content":{"text": "mailto:toto@clevy.io: false"}},
+            {"content_type":"text", "content":{"text": "not a url: false"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_40_is_url",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_41_is_phone() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "+33612345678: true"}},
+            {"content_type":"text", "content":{"text": "+1 (555) 123-4567: true"}},
+            {"content_type":"text", "content":{"text": "06.12.34.56.78: true"}},
+            {"content_type":"text", "content":{"text": "0612345678: true"}},
+            {"content_type":"text", "content":{"text": "+44 20 7946 0958: true"}},
+            {"content_type":"text", "content":{"text": "12345: false"}},
+            {"content_type":"text", "content":{"text": "+1234567890123456: false"}},
+            {"content_type":"text", "content":{"text": "+33 6 12 AB 56 78: false"}},
+            {"content_type":"text", "content":{"text": "++33612345678: false"}},
+            {"content_type":"text", "content":{"text": "06-12-: false"}},
+            {"content_type":"text", "content":{"text": "-0612345678: false"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_41_is_phone",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_42_chars() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"array", "content":["A", "é", "1", "😃"]},
+            {"content_type":"text", "content":{"text": "A"}},
+            {"content_type":"text", "content":{"text": "é"}},
+            {"content_type":"text", "content":{"text": "😃"}},
+            {"content_type":"text", "content":{"text": "[[]]"}},
+            {"content_type":"text", "content":{"text": "false"}},
+            {"content_type":"text", "content":{"text": "false"}},
+            {"content_type":"text", "content":{"text": "true"}},
+            {"content_type":"text", "content":{"text": "false"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_42_chars",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_43_char_at_out_of_range() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_43_char_at_out_of_range",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_44_char_at_negative_out_of_range() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_44_char_at_negative_out_of_range",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_45_base64() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "SGVsbG8gV29ybGQ="}},
+            {"content_type":"text", "content":{"text": "Hello World"}},
+            {"content_type":"text", "content":{"text": "CQ0KXCDDqfCfmIMgfn5+Pz8/"}},
+            {"content_type":"text", "content":{"text": "CQ0KXCDDqfCfmIMgfn5-Pz8_"}},
+            {"content_type":"text", "content":{"text": "true"}},
+            {"content_type":"text", "content":{"text": "true"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_45_base64",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_46_base64_truncated() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_46_base64_truncated",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_47_base64_invalid_utf8() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_47_base64_invalid_utf8",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_48_base64_wrong_alphabet() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_48_base64_wrong_alphabet",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_49_url_encode() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "fish%20%26%20chips%2F%C3%A9t%C3%A9~ok"}},
+            {"content_type":"text", "content":{"text": "https://x/?q=fish%20%26%20chips%2F%C3%A9t%C3%A9~ok"}},
+            {"content_type":"text", "content":{"text": "fish & chips/été~ok"}},
+            {"content_type":"text", "content":{"text": "a+b c"}},
+            {"content_type":"text", "content":{"text": "a b c"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_49_url_encode",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_50_url_decode_invalid() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_50_url_decode_invalid",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_51_url_decode_invalid_hex() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_51_url_decode_invalid_hex",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_52_hash() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"}},
+            {"content_type":"text", "content":{"text": "a9993e364706816aba3e25717850c26c9cd0d89d"}},
+            {"content_type":"text", "content":{"text": "900150983cd24fb0d6963f7d28e17f72"}},
+            {"content_type":"text", "content":{"text": "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"}},
+            {"content_type":"text", "content":{"text": "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"}},
+            {"content_type":"text", "content":{"text": "true"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_52_hash",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_53_hash_unknown_algorithm() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_53_hash_unknown_algorithm",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_54_levenshtein() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "3"}},
+            {"content_type":"text", "content":{"text": "3"}},
+            {"content_type":"text", "content":{"text": "3"}},
+            {"content_type":"text", "content":{"text": "0"}},
+            {"content_type":"text", "content":{"text": "0"}},
+            {"content_type":"text", "content":{"text": "1"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_54_levenshtein",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_55_similarity() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "0.75"}},
+            {"content_type":"text", "content":{"text": "1"}},
+            {"content_type":"text", "content":{"text": "1"}},
+            {"content_type":"text", "content":{"text": "0"}},
+            {"content_type":"text", "content":{"text": "true"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_55_similarity",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_56_levenshtein_too_long() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_56_levenshtein_too_long",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_57_levenshtein_wrong_type() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_57_levenshtein_wrong_type",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_58_format() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "Hello Alice, you have 3 messages"}},
+            {"content_type":"text", "content":{"text": "b-a-b"}},
+            {"content_type":"text", "content":{"text": "Hello Bob, you have 2 messages"}},
+            {"content_type":"text", "content":{"text": "{0} is literal"}},
+            {"content_type":"text", "content":{"text": "no placeholders"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_58_format",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_59_format_missing_placeholder() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_59_format_missing_placeholder",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_60_format_missing_name() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_60_format_missing_name",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_61_format_unclosed() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_61_format_unclosed",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg.to_owned());
+
+    if let Some(value) = value.get("messages") {
+        if let Some(value) = value.get(0) {
+            if let Some(value) = value.get("content_type") {
+                if value == "error" {
+                    return assert!(true);
+                }
+            }
+        }
+    }
+
+    println!("{:#?}", value);
+
+    assert!(false)
+}
+
+#[test]
+fn string_step_62_html_escape() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"text", "content":{"text": "&lt;a href=&#x27;x&#x27; title=&quot;y&quot;&gt;Tom &amp; Jerry&lt;/a&gt;"}},
+            {"content_type":"text", "content":{"text": "true"}},
+            {"content_type":"text", "content":{"text": "& <b> ' ' \" é"}},
+            {"content_type":"text", "content":{"text": "1 &lt; 2 &amp;amp; 3"}},
+            {"content_type":"text", "content":{"text": "&bogus; &#xZZ; & &#;"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_62_html_escape",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_63_length_graphemes() {
+    let data = r#"{
+    "memories": [],
+    "messages": [
+        {
+            "content_type": "text",
+            "content": {
+                "text": "1"
+            }
+        },
+        {
+            "content_type": "text",
+            "content": {
+                "text": "25"
+            }
+        },
+        {
+            "content_type": "text",
+            "content": {
+                "text": "1"
+            }
+        },
+        {
+            "content_type": "text",
+            "content": {
+                "text": "10"
+            }
+        },
+        {
+            "content_type": "text",
+            "content": {
+                "text": "14"
+            }
+        },
+        {
+            "content_type": "text",
+            "content": {
+                "text": "é"
+            }
+        },
+        {
+            "content_type": "text",
+            "content": {
+                "text": "naïve"
+            }
+        },
+        {
+            "content_type": "text",
+            "content": {
+                "text": "true"
+            }
+        },
+        {
+            "content_type": "text",
+            "content": {
+                "text": "2"
+            }
+        },
+        {
+            "content_type": "text",
+            "content": {
+                "text": "3"
+            }
+        }
+    ]
+}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_63_length_graphemes",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_64_lines() {
+    let data = r#"{
+        "memories":[],
+        "messages":[
+            {"content_type":"array", "content":["first", "second", "third"]},
+            {"content_type":"array", "content":["trailing"]},
+            {"content_type":"array", "content":["a", "", "b"]},
+            {"content_type":"array", "content":[]},
+            {"content_type":"text", "content":{"text": "x, y"}}
+        ]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_64_lines",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn string_step_65_pad_too_large() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "step_65_pad_too_large",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/stdlib/string.csml",
+    );
+
+    let value: Value = message_to_json_value(msg);
+
+    assert_eq!(value["messages"][0]["content_type"], "error");
+    assert!(value["messages"][0]["content"]["error"]
+        .as_str()
+        .unwrap()
+        .contains("[pad_start/pad_end] the width can not be larger than 1048576 characters"));
+}
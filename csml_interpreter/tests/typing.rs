@@ -1,11 +1,16 @@
 mod support;
 
 use csml_interpreter::data::context::Context;
+use csml_interpreter::data::csml_bot::CsmlBot;
+use csml_interpreter::data::csml_flow::CsmlFlow;
 use csml_interpreter::data::event::Event;
+use csml_interpreter::data::AutoTyping;
+use csml_interpreter::{interpret, load_components};
 use std::collections::HashMap;
 
 use crate::support::tools::format_message;
 use crate::support::tools::message_to_json_value;
+use crate::support::tools::read_file;
 
 use serde_json::Value;
 
@@ -56,3 +61,156 @@ fn typing_1() {
 
     assert_eq!(v1, v2)
 }
+
+#[test]
+fn typing_units() {
+    let data =
+        r#"{"messages":[ {"content":{"duration": 1500},"content_type":"typing"} ],"memories":[]}"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "typing_units",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/built-in/typing.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn typing_max() {
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "typing_max",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/built-in/typing.csml",
+    );
+
+    let value = message_to_json_value(msg);
+    let error = value["messages"][0]["content"]["error"].as_str().unwrap();
+
+    assert!(
+        error.contains("duration is longer than the max_duration of the bot (30000 ms)"),
+        "{}",
+        value
+    );
+}
+
+fn run_step(step: &str, auto_typing: Option<AutoTyping>) -> Value {
+    let content = read_file("CSML/basic_test/built-in/typing.csml".to_owned()).unwrap();
+    let flow = CsmlFlow::new("id", "flow", &content, Vec::default());
+
+    let mut bot = CsmlBot::new(
+        "id",
+        "bot",
+        None,
+        vec![flow],
+        Some(load_components().unwrap()),
+        None,
+        "flow",
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    bot.auto_typing = auto_typing;
+
+    let msg = interpret(
+        bot,
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        Event::new("payload", "", serde_json::json!({})),
+        None,
+    );
+
+    message_to_json_value(msg)
+}
+
+#[test]
+fn typing_auto_next_text() {
+    let v1 = run_step("typing_auto", None);
+    let v2 = serde_json::json!({
+        "memories": [],
+        "messages": [
+            {"content": {"duration": 1480}, "content_type": "typing"},
+            {"content": {"text": "Hello there, how are you doing today?"}, "content_type": "text"},
+            // short texts get the minimum duration
+            {"content": {"duration": 500}, "content_type": "typing"},
+            {"content": {"text": "Hi"}, "content_type": "text"},
+        ]
+    });
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn typing_auto_without_text() {
+    let v1 = run_step("typing_auto_no_text", None);
+    let v2 = serde_json::json!({
+        "memories": [],
+        "messages": [
+            {"content": {"duration": 1500}, "content_type": "typing"},
+            {"content": {"duration": 10}, "content_type": "wait"},
+            {"content": {"duration": 1500}, "content_type": "typing"},
+        ]
+    });
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn typing_auto_across_if() {
+    let v1 = run_step("typing_auto_if", None);
+    let v2 = serde_json::json!({
+        "memories": [],
+        "messages": [
+            {"content": {"duration": 1500}, "content_type": "typing"},
+            {"content": {"text": "Hello"}, "content_type": "text"},
+            {"content": {"duration": 1500}, "content_type": "typing"},
+            {"content": {"text": "Hello"}, "content_type": "text"},
+        ]
+    });
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn typing_auto_options() {
+    let auto_typing = AutoTyping {
+        chars_per_second: 10,
+        min: 100,
+        max: 3000,
+        default: 700,
+    };
+
+    let value = run_step("typing_auto", Some(auto_typing));
+    assert_eq!(value["messages"][0]["content"]["duration"], 3000);
+    assert_eq!(value["messages"][2]["content"]["duration"], 200);
+
+    let value = run_step("typing_auto_no_text", Some(auto_typing));
+    assert_eq!(value["messages"][0]["content"]["duration"], 700);
+}
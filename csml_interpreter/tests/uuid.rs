@@ -1,14 +1,62 @@
 mod support;
 
 use csml_interpreter::data::context::Context;
+use csml_interpreter::data::csml_bot::CsmlBot;
+use csml_interpreter::data::csml_flow::CsmlFlow;
 use csml_interpreter::data::event::Event;
+use csml_interpreter::data::primitive::tools_random;
+use csml_interpreter::{interpret, load_components};
+use regex::Regex;
 use std::collections::HashMap;
 
 use crate::support::tools::format_message;
 use crate::support::tools::message_to_json_value;
+use crate::support::tools::read_file;
 
 use serde_json::Value;
 
+const UUID_V1: &str = "^[0-9a-f]{8}-[0-9a-f]{4}-1[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$";
+const UUID_V4: &str = "^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$";
+const SHORT: &str = "^[0-9A-Za-z]{8}$";
+
+fn run_step(step: &str, random_seed: Option<u64>) -> Value {
+    let content = read_file("CSML/basic_test/built-in/uuid.csml".to_owned()).unwrap();
+    let flow = CsmlFlow::new("id", "flow", &content, Vec::default());
+
+    let mut bot = CsmlBot::new(
+        "id",
+        "bot",
+        None,
+        vec![flow],
+        Some(load_components().unwrap()),
+        None,
+        "flow",
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    bot.random_seed = random_seed;
+
+    let msg = interpret(
+        bot,
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        Event::new("payload", "", serde_json::json!({})),
+        None,
+    );
+
+    message_to_json_value(msg)
+}
+
 #[test]
 fn ok_v1() {
     let msg = format_message(
@@ -27,11 +75,12 @@ fn ok_v1() {
 
     let v: Value = message_to_json_value(msg);
 
-    let _uuid = v["messages"][0]["content"]["text"]
+    let uuid = v["messages"][0]["content"]["text"]
         .as_str()
         .unwrap()
         .parse::<String>()
         .unwrap();
+    assert!(Regex::new(UUID_V1).unwrap().is_match(&uuid), "{}", uuid);
 }
 
 #[test]
@@ -52,11 +101,12 @@ fn ok_v4() {
 
     let v: Value = message_to_json_value(msg);
 
-    let _uuid = v["messages"][0]["content"]["text"]
+    let uuid = v["messages"][0]["content"]["text"]
         .as_str()
         .unwrap()
         .parse::<String>()
         .unwrap();
+    assert!(Regex::new(UUID_V4).unwrap().is_match(&uuid), "{}", uuid);
 }
 
 #[test]
@@ -77,9 +127,71 @@ fn ok_v4_no_arg() {
 
     let v: Value = message_to_json_value(msg);
 
-    let _uuid = v["messages"][0]["content"]["text"]
+    let uuid = v["messages"][0]["content"]["text"]
         .as_str()
         .unwrap()
         .parse::<String>()
         .unwrap();
+    assert!(Regex::new(UUID_V4).unwrap().is_match(&uuid), "{}", uuid);
+}
+
+#[test]
+fn ok_short() {
+    let value = run_step("short", None);
+    let short = value["messages"][0]["content"]["text"].as_str().unwrap();
+
+    assert!(Regex::new(SHORT).unwrap().is_match(short), "{}", short);
+}
+
+#[test]
+fn ok_ids_ignore_random_seed() {
+    // the flow seed makes Random() reproducible but must not repeat identifiers
+    let first = run_step("ids", Some(42));
+    let second = run_step("ids", Some(42));
+
+    assert_ne!(
+        first["messages"][0]["content"],
+        second["messages"][0]["content"]
+    );
+}
+
+#[test]
+fn ok_ids_seeded_for_tests() {
+    tools_random::set_id_seed(Some(7));
+    let first = run_step("ids", None);
+    tools_random::set_id_seed(Some(7));
+    let second = run_step("ids", None);
+    tools_random::set_id_seed(None);
+
+    assert_eq!(first, second);
+
+    let ids = first["messages"][0]["content"].as_array().unwrap();
+    assert!(Regex::new(UUID_V4)
+        .unwrap()
+        .is_match(ids[0].as_str().unwrap()));
+    assert!(Regex::new(SHORT)
+        .unwrap()
+        .is_match(ids[1].as_str().unwrap()));
+    assert!(Regex::new(SHORT)
+        .unwrap()
+        .is_match(ids[2].as_str().unwrap()));
+    assert_ne!(ids[1], ids[2]);
+}
+
+#[test]
+fn uuid_invalid_mode() {
+    let steps = [
+        ("unknown_mode", "at line 21, column 15"),
+        ("invalid_mode_type", "at line 24, column 14"),
+    ];
+
+    for (step, info) in steps.iter() {
+        let value = run_step(step, None);
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(value["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
 }
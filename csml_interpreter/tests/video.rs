@@ -11,8 +11,7 @@ use serde_json::Value;
 
 #[test]
 fn ok_video() {
-    let data =
-        r#"{"messages":[ {"content":{ "url": "test" },"content_type":"video"} ],"memories":[]}"#;
+    let data = r#"{"messages":[ {"content":{ "url": "https://example.com/video.mp4" },"content_type":"video"} ],"memories":[]}"#;
     let msg = format_message(
         Event::new("payload", "", serde_json::json!({})),
         Context::new(
@@ -35,7 +34,7 @@ fn ok_video() {
 
 #[test]
 fn ok_video_step2() {
-    let data = r#"{"messages":[ {"content":{ "url": "test", "service": "youtube" },"content_type":"video"} ],"memories":[]}"#;
+    let data = r#"{"messages":[ {"content":{ "url": "https://example.com/video.mp4", "service": "youtube" },"content_type":"video"} ],"memories":[]}"#;
     let msg = format_message(
         Event::new("payload", "", serde_json::json!({})),
         Context::new(
@@ -58,7 +57,7 @@ fn ok_video_step2() {
 
 #[test]
 fn ok_video_step3() {
-    let data = r#"{"messages":[ {"content":{ "url": "test", "service": "youtube" },"content_type":"video"} ],"memories":[]}"#;
+    let data = r#"{"messages":[ {"content":{ "url": "https://example.com/video.mp4", "service": "youtube" },"content_type":"video"} ],"memories":[]}"#;
     let msg = format_message(
         Event::new("payload", "", serde_json::json!({})),
         Context::new(
@@ -78,3 +77,84 @@ fn ok_video_step3() {
 
     assert_eq!(v1, v2)
 }
+
+#[test]
+fn ok_video_caption_thumbnail() {
+    let data = r#"{
+        "messages":[
+            {
+                "content":{
+                    "url": "https://example.com/onboarding.mp4?lang=en",
+                    "caption": "Welcome aboard",
+                    "thumbnail_url": "https://example.com/onboarding.png"
+                },
+                "content_type":"video"
+            }
+        ],
+        "memories":[]
+    }"#;
+    let msg = format_message(
+        Event::new("payload", "", serde_json::json!({})),
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            "video_full",
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/built-in/video.csml",
+    );
+
+    let v1: Value = message_to_json_value(msg);
+    let v2: Value = serde_json::from_str(data).unwrap();
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn video_invalid_arguments() {
+    for (step, info) in [
+        (
+            "video_invalid_url",
+            "valid http or https URLs. Example: Video(\"https://example.com/video.mp4\") at line 21, column 16",
+        ),
+        (
+            "video_invalid_scheme",
+            "valid http or https URLs. Example: Video(\"https://example.com/video.mp4\") at line 24, column 16",
+        ),
+        (
+            "video_invalid_thumbnail",
+            "valid http or https URLs. Example: Video(\"https://example.com/video.mp4\") at line 27, column 70",
+        ),
+        (
+            "video_invalid_caption",
+            "argument caption in Video component must be of type String at line 30, column 63",
+        ),
+    ]
+    .iter()
+    {
+        let msg = format_message(
+            Event::new("payload", "", serde_json::json!({})),
+            Context::new(
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                step,
+                "flow",
+                None,
+            ),
+            "CSML/basic_test/built-in/video.csml",
+        );
+
+        let v: Value = message_to_json_value(msg);
+
+        assert_eq!(v["messages"][0]["content_type"], "error");
+        assert!(v["messages"][0]["content"]["error"]
+            .as_str()
+            .unwrap()
+            .contains(info));
+    }
+}
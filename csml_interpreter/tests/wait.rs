@@ -1,11 +1,15 @@
 mod support;
 
 use csml_interpreter::data::context::Context;
+use csml_interpreter::data::csml_bot::CsmlBot;
+use csml_interpreter::data::csml_flow::CsmlFlow;
 use csml_interpreter::data::event::Event;
+use csml_interpreter::{interpret, load_components};
 use std::collections::HashMap;
 
 use crate::support::tools::format_message;
 use crate::support::tools::message_to_json_value;
+use crate::support::tools::read_file;
 
 use serde_json::Value;
 
@@ -61,3 +65,108 @@ fn wait_1() {
 
     assert_eq!(v1, v2)
 }
+
+fn run_step(step: &str, max_duration: Option<u64>) -> Value {
+    let content = read_file("CSML/basic_test/built-in/wait.csml".to_owned()).unwrap();
+    let flow = CsmlFlow::new("id", "flow", &content, Vec::default());
+
+    let mut bot = CsmlBot::new(
+        "id",
+        "bot",
+        None,
+        vec![flow],
+        Some(load_components().unwrap()),
+        None,
+        "flow",
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    bot.max_duration = max_duration;
+
+    let msg = interpret(
+        bot,
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        Event::new("payload", "", serde_json::json!({})),
+        None,
+    );
+
+    message_to_json_value(msg)
+}
+
+#[test]
+fn wait_units() {
+    let v1 = run_step("wait_units", None);
+    let v2 = serde_json::json!({
+        "memories": [],
+        "messages": [
+            {"content": {"duration": 2000}, "content_type": "wait"},
+            {"content": {"duration": 500}, "content_type": "wait"},
+            {"content": {"duration": 1}, "content_type": "wait"},
+            {"content": {"duration": 250}, "content_type": "wait"},
+        ]
+    });
+
+    assert_eq!(v1, v2)
+}
+
+#[test]
+fn wait_invalid_duration() {
+    let steps = [
+        (
+            "wait_negative",
+            "Wait component expects a non-negative duration",
+            "line 20, column 11",
+        ),
+        (
+            "wait_string",
+            "Wait component expects a non-negative duration",
+            "line 24, column 12",
+        ),
+        (
+            "wait_max",
+            "duration is longer than the max_duration of the bot (30000 ms)",
+            "line 28, column 12",
+        ),
+    ];
+
+    // errors point to the argument
+    for (step, info, position) in steps.iter() {
+        let value = run_step(step, None);
+        let error = value["messages"][0]["content"]["error"].as_str().unwrap();
+
+        assert_eq!(value["messages"][0]["content_type"], "error");
+        assert!(error.contains(info), "{}", value);
+        assert!(error.contains(position), "{}", value);
+    }
+}
+
+#[test]
+fn wait_max_duration_option() {
+    let v1 = run_step("wait_max", Some(60_000));
+    let v2 = serde_json::json!({
+        "memories": [],
+        "messages": [{"content": {"duration": 31000}, "content_type": "wait"}]
+    });
+
+    assert_eq!(v1, v2);
+
+    let value = run_step("wait_units", Some(1000));
+    let error = value["messages"][0]["content"]["error"].as_str().unwrap();
+
+    assert!(
+        error.contains("max_duration of the bot (1000 ms)"),
+        "{}",
+        value
+    );
+}
@@ -41,3 +41,69 @@ fn ok_while_loop() {
 
     assert_eq!(v1, v2)
 }
+
+fn run_step(step: &str, loop_limit: Option<usize>) -> Value {
+    let mut event = Event::new("payload", "", serde_json::json!({}));
+    event.loop_limit = loop_limit;
+
+    let msg = format_message(
+        event,
+        Context::new(
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            None,
+            step,
+            "flow",
+            None,
+        ),
+        "CSML/basic_test/while_loops.csml",
+    );
+
+    message_to_json_value(msg)
+}
+
+#[test]
+fn ok_while_loop_zero_iteration() {
+    let v1 = run_step("never_true", None);
+
+    assert_eq!(
+        v1["messages"],
+        serde_json::json!([{"content":{ "text": "no iteration" },"content_type":"text"}])
+    );
+}
+
+#[test]
+fn ok_while_loop_remember() {
+    let v1 = run_step("remember_count", None);
+
+    assert_eq!(
+        v1["messages"],
+        serde_json::json!([{"content":{ "text": "3" },"content_type":"text"}])
+    );
+}
+
+#[test]
+fn ok_while_loop_limit() {
+    let v1 = run_step("start", Some(5));
+
+    assert_eq!(v1["messages"].as_array().unwrap().len(), 5);
+}
+
+#[test]
+fn ko_while_loop_limit() {
+    let vec = [
+        ("start", 4, "line 3, column 10"),
+        ("no_end", 100, "line 28, column 10"),
+    ];
+
+    for (step, loop_limit, info) in vec.iter() {
+        let v1 = run_step(step, Some(*loop_limit));
+        let messages = v1["messages"].as_array().unwrap();
+        let error = messages[0]["content"]["error"].as_str().unwrap();
+
+        assert_eq!(messages.len(), 1);
+        assert!(error.contains(&format!("Loop limit reached ({} iterations)", loop_limit)));
+        assert!(error.contains(info));
+    }
+}